@@ -198,6 +198,53 @@ fn bench_lformat(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_push_int(c: &mut Criterion) {
+    type S = cds::arraystring::ArrayString<40, cds::len::U8>;
+    let mut group = c.benchmark_group("arraystring/push_int");
+    group.bench_function("cds", |b| {
+        b.iter(|| {
+            let mut s = S::new();
+            s.push_int(black_box(-1234567890i64), 10);
+            black_box(s);
+        })
+    });
+    group.bench_function("cds_aformat", |b| {
+        b.iter(|| {
+            black_box(cds::aformat!(S, "{}", black_box(-1234567890i64)).unwrap());
+        })
+    });
+    group.bench_function("std", |b| {
+        b.iter(|| {
+            black_box(black_box(-1234567890i64).to_string());
+        })
+    });
+    group.finish();
+}
+
+fn bench_push_hex(c: &mut Criterion) {
+    type S = cds::arraystring::ArrayString<64, cds::len::U8>;
+    let bytes: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let mut group = c.benchmark_group("arraystring/push_hex");
+    group.bench_function("cds", |b| {
+        b.iter(|| {
+            let mut s = S::new();
+            s.push_hex(black_box(&bytes));
+            black_box(s);
+        })
+    });
+    group.bench_function("cds_write", |b| {
+        b.iter(|| {
+            let mut s = S::new();
+            for byte in black_box(&bytes) {
+                use core::fmt::Write;
+                write!(s, "{:02x}", byte).unwrap();
+            }
+            black_box(s);
+        })
+    });
+    group.finish();
+}
+
 bench_group!(
     bench_push,
     "arraystring/push",
@@ -268,5 +315,7 @@ criterion_group!(
     bench_remove,
     bench_truncate,
     bench_lformat,
+    bench_push_int,
+    bench_push_hex,
 );
 criterion_main!(benches);
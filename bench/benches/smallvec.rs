@@ -406,6 +406,26 @@ bench_group!(
     )
 );
 
+fn bench_sformat(c: &mut Criterion) {
+    let mut group = c.benchmark_group("smallvec/sformat");
+    group.bench_function("cds_local", |b| {
+        b.iter(|| {
+            black_box(cds::sformat!(64, "{}: {}", black_box("local"), black_box(42)).unwrap());
+        })
+    });
+    group.bench_function("cds_heap", |b| {
+        b.iter(|| {
+            black_box(cds::sformat!(4, "{}: {}", black_box("heap"), black_box(42)).unwrap());
+        })
+    });
+    group.bench_function("std", |b| {
+        b.iter(|| {
+            black_box(std::format!("{}: {}", black_box("std"), black_box(42)));
+        })
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_push_heap,
@@ -423,6 +443,7 @@ criterion_group!(
     bench_drain_heap,
     bench_drain_local,
     bench_drain_no_consume_heap,
-    bench_drain_no_consume_local
+    bench_drain_no_consume_local,
+    bench_sformat
 );
 criterion_main!(benches);
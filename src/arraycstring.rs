@@ -0,0 +1,467 @@
+//! A NUL-terminated string-like array, for passing strings to C APIs.
+
+use crate::{
+    arrayvec::ArrayVec,
+    len::{LengthType, Usize},
+    mem::{SpareMemoryPolicy, Uninitialized},
+};
+use core::{
+    ffi::{c_char, CStr},
+    ptr,
+    str::Utf8Error,
+};
+
+/// A non-growable array holding a NUL-terminated C string.
+///
+/// Written as `ArrayCString<C, L, SM>`, array-C-string has the capacity of `C` bytes,
+/// *including* the NUL terminator. Thus, it can hold a string of up to `C - 1` bytes.
+///
+/// `ArrayCString` is built on top of [`ArrayVec<u8, C, L, SM>`], which always ends with a single
+/// NUL byte. Interior NUL bytes are rejected with [`CStringError::InteriorNul`].
+///
+/// It uses type `L` as [`length type`], and `SM` as [`spare memory policy`]. The spare memory
+/// policy is applied to the bytes of the string when it is shortened, cleared or dropped,
+/// so that e.g. secrets passed to C APIs don't linger on the stack.
+///
+/// The capacity `C` must be at least `1`, to fit the NUL terminator. This is verified at compile
+/// time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::{arraycstring::ArrayCString, len::U8, mem::Zeroed};
+/// type S = ArrayCString<16, U8, Zeroed>;
+/// let mut s = S::try_from("/tmp").unwrap();
+/// s.push_str("/cds");
+/// assert_eq!(s.as_bytes_with_nul(), b"/tmp/cds\0");
+/// assert!(!s.as_ptr().is_null());
+/// ```
+///
+/// [`spare memory policy`]: SpareMemoryPolicy
+/// [`length type`]: LengthType
+pub struct ArrayCString<const C: usize, L = Usize, SM = Uninitialized>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    // the bytes of the string followed by a NUL terminator; never empty
+    v: ArrayVec<u8, C, L, SM>,
+}
+
+impl<L, SM, const C: usize> ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    /// The capacity of the array-C-string as associated constant, including the NUL terminator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::{arraycstring::ArrayCString, len::U8};
+    /// type S = ArrayCString<8, U8>;
+    /// assert_eq!(S::CAPACITY, 8);
+    /// ```
+    pub const CAPACITY: usize = C;
+
+    /// Creates a new empty `ArrayCString`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::{arraycstring::ArrayCString, len::U8};
+    /// type S = ArrayCString<8, U8>;
+    /// let s = S::new();
+    /// assert!(s.is_empty());
+    /// assert_eq!(s.as_bytes_with_nul(), b"\0");
+    /// ```
+    ///
+    /// Zero capacity fails to compile, as there is no room for the NUL terminator:
+    ///
+    /// ```compile_fail
+    /// # use cds::arraycstring::ArrayCString;
+    /// let s = ArrayCString::<0>::new();
+    /// ```
+    ///
+    /// See [`new_const`] for a `const fn` counterpart.
+    ///
+    /// [`new_const`]: ArrayCString::new_const
+    #[inline]
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NUL_CHECK;
+        let mut v = ArrayVec::new();
+        unsafe { v.push_unchecked(0) };
+        Self { v }
+    }
+
+    // Fails compilation of any `ArrayCString` type without room for the NUL terminator
+    const NUL_CHECK: () = assert!(C >= 1, "ArrayCString capacity must be at least 1");
+
+    /// Returns the capacity of the array-C-string in bytes, including the NUL terminator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::{arraycstring::ArrayCString, len::U8};
+    /// let s = ArrayCString::<8, U8>::new();
+    /// assert_eq!(s.capacity(), 8);
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        C
+    }
+
+    /// Returns the number of bytes that can be appended to the array-C-string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::{arraycstring::ArrayCString, len::U8};
+    /// let s = ArrayCString::<8, U8>::try_from("cds").unwrap();
+    /// assert_eq!(s.spare_capacity(), 4);
+    /// ```
+    #[inline]
+    pub fn spare_capacity(&self) -> usize {
+        self.v.spare_capacity()
+    }
+
+    /// Returns the length of the string in bytes, not including the NUL terminator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let s = ArrayCString::<8>::try_from("cds").unwrap();
+    /// assert_eq!(s.len(), 3);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.v.len() - 1
+    }
+
+    /// Returns `true` if the string has a length of zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let s = ArrayCString::<8>::new();
+    /// assert!(s.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the bytes of the string, not including the NUL terminator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let s = ArrayCString::<8>::try_from("cds").unwrap();
+    /// assert_eq!(s.as_bytes(), b"cds");
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.v[..self.len()]
+    }
+
+    /// Returns the bytes of the string, including the NUL terminator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let s = ArrayCString::<8>::try_from("cds").unwrap();
+    /// assert_eq!(s.as_bytes_with_nul(), b"cds\0");
+    /// ```
+    #[inline]
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        self.v.as_slice()
+    }
+
+    /// Returns the string as a C string slice.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let s = ArrayCString::<8>::try_from("cds").unwrap();
+    /// assert_eq!(s.as_c_str(), c"cds");
+    /// ```
+    #[inline]
+    pub fn as_c_str(&self) -> &CStr {
+        unsafe { CStr::from_bytes_with_nul_unchecked(self.v.as_slice()) }
+    }
+
+    /// Returns a raw pointer to the NUL-terminated string, suitable for passing to C APIs.
+    ///
+    /// The pointer is valid as long as the array-C-string is neither modified nor moved.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let s = ArrayCString::<8>::try_from("cds").unwrap();
+    /// let p = s.as_ptr();
+    /// assert_eq!(unsafe { core::ffi::CStr::from_ptr(p) }, c"cds");
+    /// ```
+    #[inline]
+    pub const fn as_ptr(&self) -> *const c_char {
+        self.v.as_ptr() as *const c_char
+    }
+
+    /// Returns the string as a string slice, if it is valid UTF-8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let s = ArrayCString::<8>::try_from("cds").unwrap();
+    /// assert_eq!(s.to_str(), Ok("cds"));
+    ///
+    /// let s = ArrayCString::<8>::try_from(&b"\xFF"[..]).unwrap();
+    /// assert!(s.to_str().is_err());
+    /// ```
+    #[inline]
+    pub fn to_str(&self) -> Result<&str, Utf8Error> {
+        core::str::from_utf8(self.as_bytes())
+    }
+
+    /// Truncates the string, removing all contents.
+    ///
+    /// Spare memory policy is applied to the bytes of the removed contents.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let mut s = ArrayCString::<8>::try_from("cds").unwrap();
+    /// s.clear();
+    /// assert!(s.is_empty());
+    /// assert_eq!(s.as_bytes_with_nul(), b"\0");
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// Shortens the string to `len` bytes.
+    ///
+    /// If `len` is greater or equal to the string's current length, this has no effect.
+    /// Spare memory policy is applied to the bytes of the removed contents.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let mut s = ArrayCString::<8>::try_from("cds").unwrap();
+    /// s.truncate(1);
+    /// assert_eq!(s.as_bytes_with_nul(), b"c\0");
+    /// s.truncate(2);
+    /// assert_eq!(s.as_bytes_with_nul(), b"c\0");
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.v[len] = 0;
+            self.v.truncate(len + 1);
+        }
+    }
+
+    /// Removes the last byte of the string and returns it, or `None` if the string is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let mut s = ArrayCString::<8>::try_from("ab").unwrap();
+    /// assert_eq!(s.pop(), Some(b'b'));
+    /// assert_eq!(s.pop(), Some(b'a'));
+    /// assert_eq!(s.pop(), None);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<u8> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let b = self.v[len - 1];
+        self.truncate(len - 1);
+        Some(b)
+    }
+
+    /// Appends a byte to the end of the string.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the byte is NUL, or if there is no spare capacity.
+    ///
+    /// See [`try_push`] for a method that returns [`CStringError`] instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let mut s = ArrayCString::<8>::new();
+    /// s.push(b'a');
+    /// assert_eq!(s.as_bytes(), b"a");
+    /// ```
+    ///
+    /// [`try_push`]: ArrayCString::try_push
+    #[inline]
+    pub fn push(&mut self, b: u8) {
+        self.try_push(b).expect("push failed")
+    }
+
+    /// Tries to append a byte to the end of the string.
+    ///
+    /// This is a non-panic version of [`push`].
+    ///
+    /// Returns [`CStringError::InteriorNul`] if the byte is NUL, or
+    /// [`CStringError::InsufficientCapacity`] if there is no spare capacity.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::{errors::CStringError, ArrayCString};
+    /// let mut s = ArrayCString::<3>::new();
+    /// assert!(s.try_push(b'a').is_ok());
+    /// assert_eq!(s.try_push(0), Err(CStringError::InteriorNul(0)));
+    /// assert!(s.try_push(b'b').is_ok());
+    /// assert_eq!(s.try_push(b'c'), Err(CStringError::InsufficientCapacity));
+    /// ```
+    ///
+    /// [`push`]: ArrayCString::push
+    #[inline]
+    pub fn try_push(&mut self, b: u8) -> Result<(), CStringError> {
+        self.try_push_bytes(&[b])
+    }
+
+    /// Appends a byte slice to the end of the string.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the slice contains a NUL byte, or if there is no enough spare
+    /// capacity to accommodate the whole slice.
+    ///
+    /// See [`try_push_bytes`] for a method that returns [`CStringError`] instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let mut s = ArrayCString::<8>::new();
+    /// s.push_bytes(b"abc");
+    /// assert_eq!(s.as_bytes(), b"abc");
+    /// ```
+    ///
+    /// [`try_push_bytes`]: ArrayCString::try_push_bytes
+    #[inline]
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        self.try_push_bytes(bytes).expect("push_bytes failed")
+    }
+
+    /// Tries to append a byte slice to the end of the string.
+    ///
+    /// This is a non-panic version of [`push_bytes`].
+    ///
+    /// Returns [`CStringError::InteriorNul`], with the position of the first NUL byte in `bytes`,
+    /// if the slice contains a NUL byte. Returns [`CStringError::InsufficientCapacity`] if there is
+    /// no enough spare capacity to accommodate the whole slice. On error, the string is not
+    /// modified.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::{errors::CStringError, ArrayCString};
+    /// let mut s = ArrayCString::<5>::new();
+    /// assert!(s.try_push_bytes(b"ab").is_ok());
+    /// assert_eq!(s.try_push_bytes(b"c\0"), Err(CStringError::InteriorNul(1)));
+    /// assert_eq!(s.try_push_bytes(b"cde"), Err(CStringError::InsufficientCapacity));
+    /// assert_eq!(s.as_bytes(), b"ab");
+    /// ```
+    ///
+    /// [`push_bytes`]: ArrayCString::push_bytes
+    #[inline]
+    pub fn try_push_bytes(&mut self, bytes: &[u8]) -> Result<(), CStringError> {
+        if let Some(pos) = bytes.iter().position(|&b| b == 0) {
+            return Err(CStringError::InteriorNul(pos));
+        }
+        if bytes.len() > self.spare_capacity() {
+            return Err(CStringError::InsufficientCapacity);
+        }
+        let len = self.len();
+        unsafe {
+            // overwrite the current NUL terminator, and terminate the string anew
+            let p = self.v.as_mut_ptr().add(len);
+            ptr::copy_nonoverlapping(bytes.as_ptr(), p, bytes.len());
+            p.add(bytes.len()).write(0);
+            self.v.set_len(len + bytes.len() + 1);
+        }
+        Ok(())
+    }
+
+    /// Appends a string slice to the end of the string.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the string slice contains a NUL character, or if there is no enough
+    /// spare capacity to accommodate the whole string slice.
+    ///
+    /// See [`try_push_str`] for a method that returns [`CStringError`] instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::ArrayCString;
+    /// let mut s = ArrayCString::<8>::new();
+    /// s.push_str("cds");
+    /// assert_eq!(s.to_str(), Ok("cds"));
+    /// ```
+    ///
+    /// [`try_push_str`]: ArrayCString::try_push_str
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        self.push_bytes(s.as_bytes())
+    }
+
+    /// Tries to append a string slice to the end of the string.
+    ///
+    /// This is a non-panic version of [`push_str`]. See [`try_push_bytes`] for the errors.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::arraycstring::{errors::CStringError, ArrayCString};
+    /// let mut s = ArrayCString::<4>::new();
+    /// assert!(s.try_push_str("cds").is_ok());
+    /// assert_eq!(s.try_push_str("!"), Err(CStringError::InsufficientCapacity));
+    /// ```
+    ///
+    /// [`push_str`]: ArrayCString::push_str
+    /// [`try_push_bytes`]: ArrayCString::try_push_bytes
+    #[inline]
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), CStringError> {
+        self.try_push_bytes(s.as_bytes())
+    }
+}
+
+impl<L, const C: usize> ArrayCString<C, L, Uninitialized>
+where
+    L: LengthType,
+{
+    /// Creates a new empty `ArrayCString` in const context.
+    ///
+    /// This is a `const fn` counterpart of [`new`], intended for building constants and statics.
+    /// It is available with the [`Uninitialized`] spare memory policy only, as other policies
+    /// initialize the spare memory at runtime.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use cds::{arraycstring::ArrayCString, len::U8};
+    /// static S: ArrayCString<8, U8> = ArrayCString::new_const();
+    /// assert_eq!(S.as_bytes_with_nul(), b"\0");
+    /// ```
+    ///
+    /// [`new`]: ArrayCString::new
+    #[inline]
+    pub const fn new_const() -> Self {
+        Self {
+            v: ArrayVec::from_array_const([0]),
+        }
+    }
+}
+
+pub mod errors;
+use errors::*;
+
+mod format;
+pub use format::*;
+
+mod traits;
+
+#[cfg(test)]
+mod test_arraycstring;
@@ -0,0 +1,55 @@
+//! `ArrayCString` error types.
+
+use core::{
+    clone::Clone,
+    cmp::{Eq, Ord, PartialEq, PartialOrd},
+    fmt::{Debug, Display, Formatter},
+    hash::Hash,
+    marker::Copy,
+};
+
+// ---------------------------------------------------------------------------
+
+/// An error returned when a C string cannot be built or extended.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CStringError {
+    /// The input contains a NUL byte at the given position.
+    InteriorNul(usize),
+
+    /// There is no enough spare capacity to accommodate the input.
+    InsufficientCapacity,
+}
+
+impl Display for CStringError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            CStringError::InteriorNul(pos) => {
+                write!(f, "arraycstring error: interior nul byte at {pos}")
+            }
+            CStringError::InsufficientCapacity => {
+                write!(f, "arraycstring error: insufficient capacity")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for CStringError {}
+
+#[cfg(all(test, feature = "std"))]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn c_string_error_display() {
+        let e = CStringError::InteriorNul(3);
+        let s = format!("{e}");
+        assert_eq!(s, "arraycstring error: interior nul byte at 3");
+
+        let e = CStringError::InsufficientCapacity;
+        let s = format!("{e}");
+        assert_eq!(s, "arraycstring error: insufficient capacity");
+    }
+}
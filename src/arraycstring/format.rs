@@ -0,0 +1,150 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::fmt::{self, Arguments, Write};
+
+struct LossyWriter<'a, const C: usize, L: LengthType, SM: SpareMemoryPolicy<u8>> {
+    s: &'a mut ArrayCString<C, L, SM>,
+    // set once the output is cut, to keep the result a prefix of the formatted string
+    done: bool,
+}
+
+impl<L, SM, const C: usize> Write for LossyWriter<'_, C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.done {
+            return Ok(());
+        }
+
+        // cut at the first NUL character, and at the last character boundary that fits
+        let mut end = s.find('\0').unwrap_or(s.len());
+        if end > self.s.spare_capacity() {
+            end = self.s.spare_capacity();
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+        }
+        if end < s.len() {
+            self.done = true;
+        }
+
+        // cannot fail, as the pushed slice has neither NUL nor excess bytes
+        self.s.try_push_str(&s[..end]).ok();
+        Ok(())
+    }
+}
+
+/// Formats an `ArrayCString` possibly truncating the result.
+///
+/// This function allows formatting a C string on stack, without memory allocation, similar to
+/// the [`arraystring::format_lossy`] function.
+///
+/// The result is the longest prefix of the formatted string that fits the capacity, truncated on
+/// character boundary, and ending before the first NUL character, if any.
+///
+/// The [`Arguments`] instance can be created with the [`format_args!`] macro.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::{arraycstring::{format_lossy, ArrayCString}, len::U8};
+/// # use core::format_args;
+/// type S = ArrayCString<8, U8>;
+/// let s: S = format_lossy(format_args!("/dev/{}", "null"));
+/// assert_eq!(s.as_c_str(), c"/dev/nu");
+///
+/// let s: S = format_lossy(format_args!("a\0b"));
+/// assert_eq!(s.as_c_str(), c"a");
+/// ```
+///
+/// [`arraystring::format_lossy`]: crate::arraystring::format_lossy
+/// [`format_args!`]: core::format_args
+#[inline]
+pub fn format_lossy<const C: usize, L, SM>(args: Arguments<'_>) -> ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    let mut s = ArrayCString::<C, L, SM>::new();
+    let mut lw = LossyWriter {
+        s: &mut s,
+        done: false,
+    };
+    lw.write_fmt(args).ok();
+    s
+}
+
+/// Formats an `ArrayCString`.
+///
+/// Unlike [`format_lossy`], this function fails if the formatted string doesn't fit the capacity,
+/// or contains a NUL character.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::{arraycstring::{format, ArrayCString}, len::U8};
+/// # use core::{fmt, format_args};
+/// type S = ArrayCString<8, U8>;
+/// let s: S = format(format_args!("fd={}", 3)).unwrap();
+/// assert_eq!(s.as_c_str(), c"fd=3");
+///
+/// let res: Result<S, fmt::Error> = format(format_args!("/dev/{}", "null"));
+/// assert!(res.is_err());
+/// ```
+///
+/// [`format_args!`]: core::format_args
+#[inline]
+pub fn format<const C: usize, L, SM>(
+    args: Arguments<'_>,
+) -> Result<ArrayCString<C, L, SM>, fmt::Error>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    let mut s = ArrayCString::<C, L, SM>::new();
+    s.write_fmt(args)?;
+    Ok(s)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::len::U8;
+
+    type S = ArrayCString<6, U8>;
+
+    #[test]
+    fn test_format_lossy() {
+        let s: S = format_lossy(format_args!("{}", 12));
+        assert_eq!(s.as_bytes_with_nul(), b"12\0");
+
+        let s: S = format_lossy(format_args!("{}", 123456));
+        assert_eq!(s.as_bytes_with_nul(), b"12345\0");
+
+        let s: S = format_lossy(format_args!("2€€"));
+        assert_eq!(s.as_bytes(), "2€".as_bytes());
+
+        // the result is always a prefix of the formatted string
+        let s: S = format_lossy(format_args!("{}{}", "2€€", 'a'));
+        assert_eq!(s.as_bytes(), "2€".as_bytes());
+        let s: S = format_lossy(format_args!("{}{}", "a\0b", "c"));
+        assert_eq!(s.as_bytes(), b"a");
+
+        let s: ArrayCString<1, U8> = format_lossy(format_args!("cds"));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_format() {
+        let s: S = format(format_args!("{}", 12345)).unwrap();
+        assert_eq!(s.as_bytes(), b"12345");
+
+        let res: Result<S, fmt::Error> = format(format_args!("{}", 123456));
+        assert!(res.is_err());
+
+        let res: Result<S, fmt::Error> = format(format_args!("a{}", '\0'));
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    arraycstring::{errors::CStringError, ArrayCString},
+    len::{LengthType, U8},
+    mem::{Pattern, SpareMemoryPolicy, Zeroed},
+};
+use core::mem;
+
+fn check_spare_memory<L, SM, const C: usize>(s: &ArrayCString<C, L, SM>, pattern: u8)
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    // the spare memory starts right after the NUL terminator
+    for i in s.len() + 1..C {
+        assert_eq!(unsafe { *s.as_ptr().add(i) } as u8, pattern);
+    }
+}
+
+type S = ArrayCString<8, U8, Pattern<0xBA>>;
+
+#[test]
+fn test_size() {
+    assert_eq!(mem::size_of::<ArrayCString<15, U8>>(), 16);
+}
+
+#[test]
+fn test_new() {
+    let s = S::new();
+    assert!(s.is_empty());
+    assert_eq!(s.len(), 0);
+    assert_eq!(s.capacity(), 8);
+    assert_eq!(s.spare_capacity(), 7);
+    assert_eq!(s.as_bytes(), b"");
+    assert_eq!(s.as_bytes_with_nul(), b"\0");
+
+    const E: ArrayCString<1, U8> = ArrayCString::new_const();
+    assert_eq!(E.spare_capacity(), 0);
+    assert_eq!(E.as_c_str(), c"");
+}
+
+#[test]
+fn test_push() {
+    let mut s = S::new();
+    s.push(b'a');
+    s.push_bytes(b"bc");
+    s.push_str("def");
+    assert_eq!(s.as_bytes_with_nul(), b"abcdef\0");
+    assert_eq!(s.to_str(), Ok("abcdef"));
+    assert_eq!(s.spare_capacity(), 1);
+    check_spare_memory(&s, 0xBA);
+
+    assert_eq!(s.try_push(0), Err(CStringError::InteriorNul(0)));
+    assert_eq!(
+        s.try_push_str("gh"),
+        Err(CStringError::InsufficientCapacity)
+    );
+    assert_eq!(s.try_push_bytes(b"\0gh"), Err(CStringError::InteriorNul(0)));
+    assert_eq!(s.as_bytes_with_nul(), b"abcdef\0");
+
+    assert!(s.try_push(b'g').is_ok());
+    assert_eq!(s.try_push(b'h'), Err(CStringError::InsufficientCapacity));
+    assert_eq!(s.as_bytes_with_nul(), b"abcdefg\0");
+    assert_eq!(s.spare_capacity(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_push_panics_on_nul() {
+    let mut s = S::new();
+    s.push(0);
+}
+
+#[test]
+#[should_panic]
+fn test_push_str_panics_on_capacity() {
+    let mut s = S::new();
+    s.push_str("abcdefgh");
+}
+
+#[test]
+fn test_truncate_wipes() {
+    let mut s = S::try_from("abcdefg").unwrap();
+    s.truncate(3);
+    assert_eq!(s.as_bytes_with_nul(), b"abc\0");
+    check_spare_memory(&s, 0xBA);
+
+    s.truncate(3);
+    assert_eq!(s.as_bytes_with_nul(), b"abc\0");
+
+    assert_eq!(s.pop(), Some(b'c'));
+    assert_eq!(s.as_bytes_with_nul(), b"ab\0");
+    check_spare_memory(&s, 0xBA);
+
+    s.clear();
+    assert_eq!(s.as_bytes_with_nul(), b"\0");
+    check_spare_memory(&s, 0xBA);
+    assert_eq!(s.pop(), None);
+}
+
+#[test]
+fn test_zeroed() {
+    let mut s = ArrayCString::<8, U8, Zeroed>::try_from("secret").unwrap();
+    s.clear();
+    check_spare_memory(&s, 0);
+}
+
+#[test]
+fn test_as_ptr() {
+    let s = S::try_from("cds").unwrap();
+    let c = unsafe { core::ffi::CStr::from_ptr(s.as_ptr()) };
+    assert_eq!(c, c"cds");
+    assert_eq!(s.as_c_str(), c"cds");
+}
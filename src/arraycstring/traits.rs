@@ -0,0 +1,15 @@
+mod as_ref;
+mod borrow;
+mod clone;
+mod debug;
+mod default;
+#[cfg(feature = "defmt")]
+mod defmt;
+mod deref;
+mod eq;
+mod fmt_write;
+mod hash;
+mod ord;
+mod try_from;
+#[cfg(feature = "ufmt")]
+mod ufmt;
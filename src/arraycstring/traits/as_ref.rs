@@ -0,0 +1,26 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::{convert::AsRef, ffi::CStr};
+
+impl<L, SM, const C: usize> AsRef<CStr> for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::arraycstring::ArrayCString;
+    use core::ffi::CStr;
+
+    #[test]
+    fn test_as_ref_c_str() {
+        let s = ArrayCString::<8>::try_from("cds").unwrap();
+        let c: &CStr = s.as_ref();
+        assert_eq!(c, c"cds");
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::{borrow::Borrow, ffi::CStr};
+
+impl<L, SM, const C: usize> Borrow<CStr> for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn borrow(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn test_borrow() {
+        let s = ArrayCString::<8>::try_from("cds").unwrap();
+        let c: &CStr = s.borrow();
+        assert_eq!(c, c"cds");
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::clone::Clone;
+
+impl<L, SM, const C: usize> Clone for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { v: self.v.clone() }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.v.clone_from(&source.v)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{arraycstring::ArrayCString, len::U8};
+
+    #[test]
+    fn test_clone() {
+        let s = ArrayCString::<8, U8>::try_from("cds").unwrap();
+        let c = s.clone();
+        assert_eq!(c.as_bytes_with_nul(), b"cds\0");
+
+        let mut c = ArrayCString::<8, U8>::try_from("abcdef").unwrap();
+        c.clone_from(&s);
+        assert_eq!(c.as_bytes_with_nul(), b"cds\0");
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::fmt::{Debug, Formatter, Result};
+
+impl<L, SM, const C: usize> Debug for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(self.as_c_str(), f)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod testing {
+    use crate::arraycstring::ArrayCString;
+
+    #[test]
+    fn test_debug() {
+        let s = ArrayCString::<8>::try_from(&b"cds\xFF"[..]).unwrap();
+        let f = format!("{s:?}");
+        assert_eq!(f, "\"cds\\xff\"");
+    }
+}
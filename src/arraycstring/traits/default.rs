@@ -0,0 +1,25 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::default::Default;
+
+impl<L, SM, const C: usize> Default for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{arraycstring::ArrayCString, len::U8};
+
+    #[test]
+    fn test_default() {
+        let s = ArrayCString::<8, U8>::default();
+        assert!(s.is_empty());
+        assert_eq!(s.as_bytes_with_nul(), b"\0");
+    }
+}
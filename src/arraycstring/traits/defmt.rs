@@ -0,0 +1,34 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use defmt::{Format, Formatter};
+
+/// Implementation of [`Format`] for [`ArrayCString`].
+///
+/// An array-C-string is formatted as a byte string without the NUL terminator, with printable
+/// ASCII bytes displayed as characters.
+impl<L, SM, const C: usize> Format for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=[u8]:a}", self.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{
+        arraycstring::{errors::CStringError, ArrayCString},
+        len::U8,
+    };
+    use defmt::Format;
+
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn test_format() {
+        assert_format::<ArrayCString<16, U8>>();
+        assert_format::<CStringError>();
+    }
+}
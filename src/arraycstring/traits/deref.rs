@@ -0,0 +1,27 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::{ffi::CStr, ops::Deref};
+
+impl<L, SM, const C: usize> Deref for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Target = CStr;
+
+    #[inline]
+    fn deref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::arraycstring::ArrayCString;
+
+    #[test]
+    fn test_deref() {
+        let s = ArrayCString::<8>::try_from("cds").unwrap();
+        assert_eq!(s.to_bytes(), b"cds");
+        assert_eq!(s.count_bytes(), 3);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::{
+    cmp::{Eq, PartialEq},
+    ffi::CStr,
+};
+
+impl<LT, LU, SMT, SMU, const C: usize, const N: usize> PartialEq<ArrayCString<N, LU, SMU>>
+    for ArrayCString<C, LT, SMT>
+where
+    LT: LengthType,
+    LU: LengthType,
+    SMT: SpareMemoryPolicy<u8>,
+    SMU: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn eq(&self, other: &ArrayCString<N, LU, SMU>) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<L, SM, const C: usize> PartialEq<CStr> for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn eq(&self, other: &CStr) -> bool {
+        self.as_bytes() == other.to_bytes()
+    }
+}
+
+impl<L, SM, const C: usize> PartialEq<&'_ CStr> for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn eq(&self, other: &&'_ CStr) -> bool {
+        self.as_bytes() == other.to_bytes()
+    }
+}
+
+impl<L, SM, const C: usize> PartialEq<ArrayCString<C, L, SM>> for CStr
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn eq(&self, other: &ArrayCString<C, L, SM>) -> bool {
+        self.to_bytes() == other.as_bytes()
+    }
+}
+
+impl<L, SM, const C: usize> PartialEq<ArrayCString<C, L, SM>> for &'_ CStr
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn eq(&self, other: &ArrayCString<C, L, SM>) -> bool {
+        self.to_bytes() == other.as_bytes()
+    }
+}
+
+impl<L: LengthType, SM: SpareMemoryPolicy<u8>, const C: usize> Eq for ArrayCString<C, L, SM> {}
+
+#[cfg(test)]
+mod testing {
+    use crate::{arraycstring::ArrayCString, len::U8, mem::Zeroed};
+
+    #[test]
+    fn test_eq() {
+        let a = ArrayCString::<8>::try_from("cds").unwrap();
+        let b = ArrayCString::<4, U8, Zeroed>::try_from("cds").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, ArrayCString::<4>::try_from("cd").unwrap());
+    }
+
+    #[test]
+    fn test_eq_c_str() {
+        let s = ArrayCString::<8>::try_from("cds").unwrap();
+        assert!(s == c"cds");
+        assert!(c"cds" == s);
+        assert!(s == *c"cds");
+        assert!(*c"cds" == s);
+        assert!(s != c"cd");
+        assert!(c"cdsa" != s);
+    }
+}
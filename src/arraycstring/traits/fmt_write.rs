@@ -0,0 +1,37 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::fmt::{Error, Result, Write};
+
+/// Implementation of [`Write`] for [`ArrayCString`].
+///
+/// Note that, as `ArrayCString` is a fixed-capacity non-growable writer,
+/// these methods may fail due to capacity constraints. They fail on NUL characters too.
+///
+/// See [`format_lossy`] for lossy formatting.
+///
+/// [`format_lossy`]: crate::arraycstring::format_lossy
+impl<L, SM, const C: usize> Write for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result {
+        self.try_push_str(s).map_err(|_| Error {})
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::arraycstring::ArrayCString;
+    use core::fmt::Write;
+
+    #[test]
+    fn test_write_str() {
+        let mut s = ArrayCString::<8>::new();
+        assert!(core::write!(&mut s, "fd={}", 3).is_ok());
+        assert_eq!(s.as_c_str(), c"fd=3");
+        assert!(s.write_char('\0').is_err());
+        assert!(core::write!(&mut s, "abcd").is_err());
+        assert_eq!(s.as_c_str(), c"fd=3");
+    }
+}
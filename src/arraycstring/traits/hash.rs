@@ -0,0 +1,43 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::hash::{Hash, Hasher};
+
+impl<L, SM, const C: usize> Hash for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self.as_c_str(), state)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod testing {
+    use crate::arraycstring::ArrayCString;
+    use core::hash::{Hash, Hasher};
+    use std::collections::hash_map::DefaultHasher;
+
+    #[test]
+    fn test_hash() {
+        let mut hasher1 = DefaultHasher::new();
+        let s = ArrayCString::<8>::try_from("cds").unwrap();
+        s.hash(&mut hasher1);
+
+        let mut hasher2 = DefaultHasher::new();
+        c"cds".hash(&mut hasher2);
+
+        assert_eq!(hasher1.finish(), hasher2.finish());
+    }
+
+    #[test]
+    fn test_hash_map_lookup_by_c_str() {
+        use std::collections::HashMap;
+
+        type S = ArrayCString<8>;
+        let mut m = HashMap::<S, usize>::new();
+        m.insert(S::try_from("one").unwrap(), 1);
+        assert_eq!(m.get(c"one"), Some(&1));
+        assert_eq!(m.get(c"two"), None);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use core::cmp::{Ord, Ordering, PartialOrd};
+
+impl<L, SM, const C: usize> PartialOrd for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<L, SM, const C: usize> Ord for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(self.as_c_str(), other.as_c_str())
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::arraycstring::ArrayCString;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn test_ord() {
+        let a = ArrayCString::<8>::try_from("ab").unwrap();
+        let b = ArrayCString::<8>::try_from("abc").unwrap();
+        assert!(a < b);
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        assert_eq!(b.cmp(&a), Ordering::Greater);
+        assert_eq!(a.partial_cmp(&a), Some(Ordering::Equal));
+    }
+}
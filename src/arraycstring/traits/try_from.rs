@@ -0,0 +1,163 @@
+use crate::{
+    arraycstring::{errors::CStringError, ArrayCString},
+    len::LengthType,
+    mem::SpareMemoryPolicy,
+};
+use core::{convert::TryFrom, ffi::CStr};
+
+#[cfg(feature = "arraystring")]
+use crate::arraystring::ArrayString;
+
+impl<L, SM, const C: usize> TryFrom<&[u8]> for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Error = CStringError;
+
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let mut tmp = Self::new();
+        tmp.try_push_bytes(bytes)?;
+        Ok(tmp)
+    }
+}
+
+impl<L, SM, const C: usize> TryFrom<&str> for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Error = CStringError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_bytes())
+    }
+}
+
+impl<L, SM, const C: usize> TryFrom<&CStr> for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Error = CStringError;
+
+    #[inline]
+    fn try_from(s: &CStr) -> Result<Self, Self::Error> {
+        Self::try_from(s.to_bytes())
+    }
+}
+
+#[cfg(feature = "arraystring")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arraystring")))]
+impl<L, L2, SM, SM2, const C: usize, const C2: usize> TryFrom<&ArrayString<C2, L2, SM2>>
+    for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    L2: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    SM2: SpareMemoryPolicy<u8>,
+{
+    type Error = CStringError;
+
+    #[inline]
+    fn try_from(s: &ArrayString<C2, L2, SM2>) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_bytes())
+    }
+}
+
+/// Converts an [`ArrayString`] into an [`ArrayCString`].
+///
+/// On success, the source array-string is cleared, so that its spare memory policy `SM2` is
+/// applied to the copied bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::{array_str, arraycstring::ArrayCString};
+/// let s = array_str![8; "cds"];
+/// let c = ArrayCString::<8>::try_from(s).unwrap();
+/// assert_eq!(c.as_c_str(), c"cds");
+/// ```
+#[cfg(feature = "arraystring")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arraystring")))]
+impl<L, L2, SM, SM2, const C: usize, const C2: usize> TryFrom<ArrayString<C2, L2, SM2>>
+    for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    L2: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    SM2: SpareMemoryPolicy<u8>,
+{
+    type Error = CStringError;
+
+    #[inline]
+    fn try_from(mut s: ArrayString<C2, L2, SM2>) -> Result<Self, Self::Error> {
+        let tmp = Self::try_from(s.as_bytes())?;
+        s.clear();
+        Ok(tmp)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{
+        arraycstring::{errors::CStringError, ArrayCString},
+        len::U8,
+        mem::Pattern,
+    };
+
+    type S = ArrayCString<4, U8, Pattern<0xBA>>;
+
+    #[test]
+    fn test_try_from_bytes() {
+        let s = S::try_from(&b"cds"[..]).unwrap();
+        assert_eq!(s.as_bytes_with_nul(), b"cds\0");
+        assert_eq!(S::try_from(&b"c\0s"[..]), Err(CStringError::InteriorNul(1)));
+        assert_eq!(
+            S::try_from(&b"abcd"[..]),
+            Err(CStringError::InsufficientCapacity)
+        );
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let s = S::try_from("cds").unwrap();
+        assert_eq!(s.as_bytes_with_nul(), b"cds\0");
+        assert_eq!(S::try_from("\0"), Err(CStringError::InteriorNul(0)));
+        assert_eq!(S::try_from("abcd"), Err(CStringError::InsufficientCapacity));
+    }
+
+    #[test]
+    fn test_try_from_c_str() {
+        let s = S::try_from(c"cds").unwrap();
+        assert_eq!(s.as_bytes_with_nul(), b"cds\0");
+        assert_eq!(
+            S::try_from(c"abcd"),
+            Err(CStringError::InsufficientCapacity)
+        );
+    }
+
+    #[cfg(feature = "arraystring")]
+    #[test]
+    fn test_try_from_array_string() {
+        use crate as cds;
+        use cds::{array_str, arraystring::ArrayString};
+
+        let a = array_str![8; "cds"];
+        let s = S::try_from(&a).unwrap();
+        assert_eq!(s.as_bytes_with_nul(), b"cds\0");
+        assert_eq!(
+            S::try_from(&array_str![8; "a\0"]),
+            Err(CStringError::InteriorNul(1))
+        );
+
+        let a = ArrayString::<8, U8, Pattern<0xCD>>::try_from("cds").unwrap();
+        let s = S::try_from(a).unwrap();
+        assert_eq!(s.as_bytes_with_nul(), b"cds\0");
+
+        let a = array_str![8; "abcd"];
+        assert_eq!(S::try_from(a), Err(CStringError::InsufficientCapacity));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{arraycstring::ArrayCString, len::LengthType, mem::SpareMemoryPolicy};
+use ufmt::{uDebug, uWrite, Formatter};
+
+/// Implementation of [`uDebug`] for [`ArrayCString`].
+///
+/// The bytes are quoted and escaped as by [`u8::escape_ascii`], similar to [`Debug`] of
+/// [`CStr`].
+///
+/// [`Debug`]: core::fmt::Debug
+/// [`CStr`]: core::ffi::CStr
+impl<L, SM, const C: usize> uDebug for ArrayCString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_char('"')?;
+        for e in self.as_bytes().iter().flat_map(|b| b.escape_ascii()) {
+            f.write_char(char::from(e))?;
+        }
+        f.write_char('"')
+    }
+}
+
+#[cfg(all(test, feature = "arraystring"))]
+mod testing {
+    use crate as cds;
+    use cds::{array_str, arraycstring::ArrayCString};
+    use ufmt::uwrite;
+
+    #[test]
+    fn test_debug() {
+        let a = ArrayCString::<8>::try_from(&b"c\"ds\xFF"[..]).unwrap();
+        let mut s = array_str![16;];
+        uwrite!(s, "{:?}", a).unwrap();
+        assert_eq!(s, "\"c\\\"ds\\xff\"");
+    }
+}
@@ -1,10 +1,19 @@
 //! A string-like array.
 
+#[cfg(feature = "bytemuck")]
+use crate::mem::Zeroed;
 use crate::{
-    len::{LengthType, Usize},
+    len::{self, LengthType, Usize},
     mem::{SpareMemoryPolicy, Uninitialized},
 };
-use core::{marker::PhantomData, mem, ptr, slice};
+use core::{
+    convert::Infallible,
+    ffi::CStr,
+    marker::PhantomData,
+    mem,
+    ops::{Bound, RangeBounds},
+    ptr, slice,
+};
 
 /// A non-growable array with string-like API.
 ///
@@ -14,8 +23,25 @@ use core::{marker::PhantomData, mem, ptr, slice};
 ///
 /// Similar to [`str`] `ArrayString` is UTF-8 encoded.
 ///
+/// # Layout
+///
+/// `ArrayString` is `#[repr(C)]`: an array of `C` bytes, followed by the length of type `L`,
+/// followed by trailing padding up to the alignment of `L` (if any).
+///
+/// This layout is guaranteed, regardless of enabled features, and may be relied upon, e.g. when
+/// sharing an array-string with C code or through shared memory. Changing it is considered
+/// a breaking change.
+///
+/// Note that `ArrayString` doesn't implement `bytemuck::Pod`, because not every bit pattern is
+/// a valid array-string: the length must not exceed the capacity (and must not be zero for
+/// niche-optimized length types), the bytes within the length must be UTF-8, and spare memory
+/// must be zeroed with the [`Zeroed`] spare memory policy. Use `from_bytes`, which validates
+/// these, instead.
+///
+/// [`Zeroed`]: crate::mem::Zeroed
 /// [`spare memory policy`]: SpareMemoryPolicy
 /// [`length type`]: LengthType
+#[repr(C)]
 pub struct ArrayString<const C: usize, L = Usize, SM = Uninitialized>
 where
     L: LengthType,
@@ -62,7 +88,7 @@ where
     ///
     /// [`CAPACITY`]: ArrayString::CAPACITY
     #[inline]
-    pub fn capacity(&self) -> usize {
+    pub const fn capacity(&self) -> usize {
         Self::CAPACITY
     }
 
@@ -89,6 +115,9 @@ where
 
     /// Creates a new empty `ArrayString`.
     ///
+    /// Requested capacity `C` may not exceed the maximal value that can be stored in
+    /// length type `L`. This is verified at compile time.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -96,6 +125,30 @@ where
     /// type AS = ArrayString<7, U8>;
     /// let s = AS::new();
     /// ```
+    ///
+    /// An `ArrayString` whose capacity exceeds `L::MAX` fails to compile:
+    ///
+    /// ```compile_fail
+    /// # #[cfg(feature = "arraystring")] {
+    /// # use cds::{arraystring::ArrayString, len::U8};
+    /// type AS = ArrayString<256, U8>; // U8::MAX(255) < CAPACITY(256)
+    /// let s = AS::new();
+    /// # }
+    /// ```
+    ///
+    /// The same holds at the niche boundary of niche-optimized length types:
+    ///
+    /// ```compile_fail
+    /// # #[cfg(feature = "arraystring")] {
+    /// # use cds::{arraystring::ArrayString, len::U8NZ};
+    /// type AS = ArrayString<255, U8NZ>; // U8NZ::MAX(254) < CAPACITY(255)
+    /// let s = AS::try_from("cds");
+    /// # }
+    /// ```
+    ///
+    /// See [`new_const`] for a `const fn` counterpart.
+    ///
+    /// [`new_const`]: ArrayString::new_const
     #[inline]
     pub fn new() -> Self {
         let mut s = Self::new_raw(0);
@@ -103,8 +156,13 @@ where
         s
     }
 
+    // Fails compilation of any `ArrayString` type whose capacity exceeds `L::MAX`
+    const CAPACITY_CHECK: () = assert!(C <= L::MAX, "ArrayString capacity exceeds L::MAX");
+
     #[inline(always)]
     fn new_raw(len: usize) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_CHECK;
         Self {
             // it is safe to call `assume_init` to create an array of `MaybeUninit`
             arr: unsafe { mem::MaybeUninit::uninit().assume_init() },
@@ -114,7 +172,7 @@ where
     }
 
     #[inline]
-    fn as_ptr(&self) -> *const u8 {
+    const fn as_ptr(&self) -> *const u8 {
         self.arr.as_ptr() as *const u8
     }
 
@@ -144,9 +202,20 @@ where
     /// let s = array_str![16; "€"];
     /// assert_eq!(s.len(), 3); // the length of array-string's UTF-8 encoding in bytes
     /// ```
+    ///
+    /// This is a `const fn`, usable with constants built by [`from_str_const`]:
+    ///
+    /// ```rust
+    /// # use cds::{arraystring::ArrayString, len::U8};
+    /// const S: ArrayString<16, U8> = ArrayString::from_str_const("€");
+    /// const LEN: usize = S.len();
+    /// assert_eq!(LEN, 3);
+    /// ```
+    ///
+    /// [`from_str_const`]: ArrayString::from_str_const
     #[inline]
-    pub fn len(&self) -> usize {
-        self.len.as_usize()
+    pub const fn len(&self) -> usize {
+        len::const_as_usize(&self.len)
     }
 
     /// Checks of the `ArrayString` is empty.
@@ -161,7 +230,7 @@ where
     /// assert_eq!(a.len(), 0);
     /// ```
     #[inline]
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
@@ -175,7 +244,7 @@ where
     /// assert_eq!(s.as_bytes(), &[99, 100, 115]);
     /// ```
     #[inline]
-    pub fn as_bytes(&self) -> &[u8] {
+    pub const fn as_bytes(&self) -> &[u8] {
         unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
     }
 
@@ -188,12 +257,16 @@ where
     ///
     /// # Examples
     /// ```rust
-    /// # use cds::array_str;
+    /// # use cds::{array_str, arraystring::ArrayString};
     /// let s = array_str![16; "cds"];
     /// assert_eq!(s.as_str(), "cds");
+    ///
+    /// const S: ArrayString<16> = ArrayString::from_str_const("cds");
+    /// const STR: &str = S.as_str();
+    /// assert_eq!(STR, "cds");
     /// ```
     #[inline]
-    pub fn as_str(&self) -> &str {
+    pub const fn as_str(&self) -> &str {
         unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
     }
 
@@ -213,6 +286,50 @@ where
         unsafe { core::str::from_utf8_unchecked_mut(self.as_bytes_mut()) }
     }
 
+    /// NUL-terminates the `ArrayString` and returns it as a C string slice.
+    ///
+    /// The terminator is written into the spare capacity, right after the last byte of the
+    /// string, and is not counted in its length. No allocation takes place, which makes this
+    /// suitable for passing short strings to FFI functions.
+    ///
+    /// Note that the terminator byte is left in spare memory, until it is overwritten by a
+    /// subsequent modification of the string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CStrError::InteriorNul`] if the string contains a NUL byte, or
+    /// [`CStrError::InsufficientCapacity`] if there is no spare capacity for the terminator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_str, arraystring::errors::CStrError};
+    /// let mut s = array_str![4; "cds"];
+    /// assert_eq!(s.as_c_str().unwrap().to_bytes_with_nul(), b"cds\0");
+    /// assert_eq!(s, "cds");
+    ///
+    /// s.push('!');
+    /// assert!(matches!(s.as_c_str(), Err(CStrError::InsufficientCapacity)));
+    ///
+    /// let mut s = array_str![8; "c\0ds"];
+    /// assert!(matches!(s.as_c_str(), Err(CStrError::InteriorNul(1))));
+    /// ```
+    #[inline]
+    pub fn as_c_str(&mut self) -> Result<&CStr, CStrError> {
+        if let Some(pos) = self.as_bytes().iter().position(|&b| b == 0) {
+            return Err(CStrError::InteriorNul(pos));
+        }
+        let len = self.len();
+        if len == C {
+            return Err(CStrError::InsufficientCapacity);
+        }
+        unsafe {
+            self.as_mut_ptr().add(len).write(0);
+            let bytes = slice::from_raw_parts(self.as_ptr(), len + 1);
+            Ok(CStr::from_bytes_with_nul_unchecked(bytes))
+        }
+    }
+
     /// Truncates this `ArrayString`, removing all contents.
     ///
     /// # Examples
@@ -410,6 +527,59 @@ where
         Ok(())
     }
 
+    /// Tries to extend this `ArrayString` with the characters of an iterator.
+    ///
+    /// Returns [`InsufficientCapacityError`] if there is no enough spare capacity to accommodate
+    /// all the characters. In that case the array-string is rolled back to its original length.
+    ///
+    /// See [`try_push_str`] for appending a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_str, arraystring::errors::InsufficientCapacityError};
+    /// let mut s = array_str![5; "ab"];
+    /// assert!(s.try_extend(['c', 'd']).is_ok());
+    /// assert_eq!(s, "abcd");
+    ///
+    /// assert!(matches!(s.try_extend("ef".chars()), Err(e) if e == InsufficientCapacityError));
+    /// assert_eq!(s, "abcd");
+    /// ```
+    ///
+    /// [`try_push_str`]: ArrayString::try_push_str
+    #[inline]
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), InsufficientCapacityError>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let len = self.len();
+        for ch in iter {
+            if let Err(e) = self.try_push(ch) {
+                self.truncate(len);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Tries to clone this `ArrayString`.
+    ///
+    /// This is the counterpart of `SmallVec::try_clone`. As an array-string never allocates,
+    /// cloning it can't fail, hence the error type is [`Infallible`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let s = array_str![8; "cds"];
+    /// let t = s.try_clone().unwrap();
+    /// assert_eq!(s, t);
+    /// ```
+    #[inline]
+    pub fn try_clone(&self) -> Result<Self, Infallible> {
+        Ok(self.clone())
+    }
+
     /// Appends a given string slice to the end of this `ArrayString` without spare capacity check.
     ///
     /// # Safety
@@ -744,14 +914,784 @@ where
 
         Ok(())
     }
+
+    /// Creates a draining iterator that removes the specified byte range in the array-string
+    /// and yields the removed characters.
+    ///
+    /// When the iterator is dropped, the range is removed from the array-string, even if the
+    /// iterator was not fully consumed. The freed bytes are handled by the spare memory policy.
+    /// If the iterator is not dropped (with [`mem::forget`] for example), the array-string is
+    /// truncated to the start of the range.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the following conditions is true:
+    ///
+    /// - the start or the end of the range doesn't lie on a [`char`] boundary
+    /// - the start of the range is greater than its end
+    /// - the end of the range is greater than the array-string length
+    ///
+    /// See [`try_drain`] for a method that returns [`IndexError`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let mut s = array_str![8; "a€bc"];
+    /// let mut d = s.drain(1..5);
+    /// assert_eq!(d.next(), Some('€'));
+    /// assert_eq!(d.as_str(), "b");
+    /// drop(d);
+    /// assert_eq!(s, "ac");
+    /// ```
+    ///
+    /// [`try_drain`]: ArrayString::try_drain
+    /// [`mem::forget`]: core::mem::forget
+    #[inline]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, L, SM, C>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.try_drain(range).expect("invalid range")
+    }
+
+    /// Tries to create a draining iterator that removes the specified byte range in the
+    /// array-string and yields the removed characters.
+    ///
+    /// This is a non-panic version of [`drain`].
+    ///
+    /// This method returns [`IndexError`] if the range is invalid, out of bounds, or if any of its
+    /// ends doesn't lie on a [`char`] boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_str, arraystring::errors::IndexError};
+    /// # fn foo() -> Result<(), IndexError> {
+    /// let mut s = array_str![8; "2€"];
+    /// assert!(matches!(s.try_drain(2..), Err(IndexError))); // <-- 2 is not a char boundary
+    /// assert!(matches!(s.try_drain(..5), Err(IndexError))); // <-- 5 is out of bounds
+    /// assert!(s.try_drain(1..)?.rev().eq(['€']));
+    /// assert_eq!(s, "2");
+    /// # Ok(())
+    /// # }
+    /// # foo().unwrap();
+    /// ```
+    ///
+    /// [`drain`]: ArrayString::drain
+    #[inline]
+    pub fn try_drain<R>(&mut self, range: R) -> Result<Drain<'_, L, SM, C>, IndexError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+
+        let end = match range.end_bound() {
+            Bound::Included(e) => e.checked_add(1).ok_or(IndexError)?,
+            Bound::Excluded(e) => *e,
+            Bound::Unbounded => len,
+        };
+
+        let start = match range.start_bound() {
+            Bound::Included(s) => *s,
+            Bound::Excluded(s) => s.checked_add(1).ok_or(IndexError)?,
+            Bound::Unbounded => 0,
+        };
+
+        if start > end || end > len || !self.is_char_boundary(start) || !self.is_char_boundary(end)
+        {
+            return Err(IndexError);
+        }
+
+        unsafe {
+            // set `len` to reflect the head only
+            self.set_len(start);
+
+            let bytes = slice::from_raw_parts(self.as_ptr().add(start), end - start);
+
+            Ok(Drain {
+                s: ptr::NonNull::new_unchecked(self),
+                iter: core::str::from_utf8_unchecked(bytes).chars(),
+                tail: end,
+                tail_len: len - end,
+            })
+        }
+    }
+
+    /// Converts this `ArrayString` to its ASCII lower case equivalent in-place.
+    ///
+    /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but non-ASCII letters are unchanged.
+    ///
+    /// See [`to_lowercase`] for lowercasing all Unicode characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let mut s = array_str![8; "GRÜßE"];
+    /// s.make_ascii_lowercase();
+    /// assert_eq!(s, "grÜße");
+    /// ```
+    ///
+    /// [`to_lowercase`]: ArrayString::to_lowercase
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.as_mut_str().make_ascii_lowercase()
+    }
+
+    /// Converts this `ArrayString` to its ASCII upper case equivalent in-place.
+    ///
+    /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters are unchanged.
+    ///
+    /// See [`to_uppercase`] for uppercasing all Unicode characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let mut s = array_str![8; "grüße"];
+    /// s.make_ascii_uppercase();
+    /// assert_eq!(s, "GRüßE");
+    /// ```
+    ///
+    /// [`to_uppercase`]: ArrayString::to_uppercase
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.as_mut_str().make_ascii_uppercase()
+    }
+
+    /// Removes leading and trailing whitespace from this `ArrayString` in-place.
+    ///
+    /// 'Whitespace' is defined according to the terms of the Unicode Derived Core Property
+    /// `White_Space`. The freed bytes are handled by the spare memory policy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let mut s = array_str![16; "\t cds \n"];
+    /// s.trim_in_place();
+    /// assert_eq!(s, "cds");
+    /// ```
+    #[inline]
+    pub fn trim_in_place(&mut self) {
+        self.trim_end_in_place();
+        self.trim_start_in_place();
+    }
+
+    /// Removes leading whitespace from this `ArrayString` in-place.
+    ///
+    /// This is an O(n) operation, as it potentially copies all bytes in the array-string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let mut s = array_str![16; "\t cds \n"];
+    /// s.trim_start_in_place();
+    /// assert_eq!(s, "cds \n");
+    /// ```
+    #[inline]
+    pub fn trim_start_in_place(&mut self) {
+        let len = self.len();
+        let new_len = self.trim_start().len();
+        let start = len - new_len;
+        if start == 0 {
+            return;
+        }
+
+        unsafe {
+            let base = self.as_mut_ptr();
+            ptr::copy(base.add(start), base, new_len);
+            SM::init(base.add(new_len), start);
+            self.set_len(new_len);
+        }
+    }
+
+    /// Removes trailing whitespace from this `ArrayString` in-place.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let mut s = array_str![16; "\t cds \n"];
+    /// s.trim_end_in_place();
+    /// assert_eq!(s, "\t cds");
+    /// ```
+    #[inline]
+    pub fn trim_end_in_place(&mut self) {
+        let len = self.len();
+        let new_len = self.trim_end().len();
+        unsafe {
+            self.set_len(new_len);
+            SM::init(self.as_mut_ptr().add(new_len), len - new_len);
+        }
+    }
+
+    /// Returns the lowercase equivalent of this `ArrayString`, as a new `ArrayString`.
+    ///
+    /// Every character is mapped with [`char::to_lowercase`]. Note that unlike
+    /// [`str::to_lowercase`], a final 'Σ' is mapped to 'σ' rather than 'ς'.
+    ///
+    /// Returns [`InsufficientCapacityError`] if the result doesn't fit in the capacity of the
+    /// array-string, which is possible, as some characters expand to multiple (or longer)
+    /// characters when lowercased.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_str, arraystring::errors::InsufficientCapacityError};
+    /// let s = array_str![8; "CDS"];
+    /// assert_eq!(s.to_lowercase().unwrap(), "cds");
+    ///
+    /// let s = array_str![2; "İ"];
+    /// assert!(matches!(s.to_lowercase(), Err(InsufficientCapacityError)));
+    /// ```
+    #[inline]
+    pub fn to_lowercase(&self) -> Result<Self, InsufficientCapacityError> {
+        let mut s = Self::new();
+        s.try_extend(self.chars().flat_map(char::to_lowercase))?;
+        Ok(s)
+    }
+
+    /// Returns the uppercase equivalent of this `ArrayString`, as a new `ArrayString`.
+    ///
+    /// Every character is mapped with [`char::to_uppercase`].
+    ///
+    /// Returns [`InsufficientCapacityError`] if the result doesn't fit in the capacity of the
+    /// array-string, which is possible, as some characters expand to multiple characters when
+    /// uppercased.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_str, arraystring::errors::InsufficientCapacityError};
+    /// let s = array_str![2; "ŉ"];
+    /// assert!(matches!(s.to_uppercase(), Err(InsufficientCapacityError)));
+    ///
+    /// let s = array_str![8; "grüß"];
+    /// assert_eq!(s.to_uppercase().unwrap(), "GRÜSS");
+    /// ```
+    #[inline]
+    pub fn to_uppercase(&self) -> Result<Self, InsufficientCapacityError> {
+        let mut s = Self::new();
+        s.try_extend(self.chars().flat_map(char::to_uppercase))?;
+        Ok(s)
+    }
+
+    /// Creates a new `ArrayString` by repeating this array-string `n` times.
+    ///
+    /// Returns [`InsufficientCapacityError`] if the result doesn't fit in the capacity of the
+    /// array-string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_str, arraystring::errors::InsufficientCapacityError};
+    /// let s = array_str![8; "ab"];
+    /// assert_eq!(s.repeat(3).unwrap(), "ababab");
+    /// assert!(matches!(s.repeat(5), Err(InsufficientCapacityError)));
+    /// ```
+    #[inline]
+    pub fn repeat(&self, n: usize) -> Result<Self, InsufficientCapacityError> {
+        match self.len().checked_mul(n) {
+            Some(len) if len <= C => (),
+            _ => return Err(InsufficientCapacityError),
+        }
+
+        let mut s = Self::new();
+        if self.is_empty() {
+            return Ok(s);
+        }
+
+        for _ in 0..n {
+            unsafe { s.push_str_unchecked(self) };
+        }
+        Ok(s)
+    }
+
+    /// Replaces all matches of a string slice with another string slice, and returns the result
+    /// as a new `ArrayString`.
+    ///
+    /// Returns [`InsufficientCapacityError`] if the result doesn't fit in the capacity of the
+    /// array-string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_str, arraystring::errors::InsufficientCapacityError};
+    /// let s = array_str![8; "a-b-c"];
+    /// assert_eq!(s.replace("-", "+").unwrap(), "a+b+c");
+    /// assert_eq!(s.replace("-", "").unwrap(), "abc");
+    /// assert!(matches!(s.replace("-", "---"), Err(InsufficientCapacityError)));
+    /// ```
+    #[inline]
+    pub fn replace(&self, from: &str, to: &str) -> Result<Self, InsufficientCapacityError> {
+        let mut s = Self::new();
+        let mut last_end = 0;
+        for (start, part) in self.match_indices(from) {
+            s.try_push_str(unsafe { self.get_unchecked(last_end..start) })?;
+            s.try_push_str(to)?;
+            last_end = start + part.len();
+        }
+        s.try_push_str(unsafe { self.get_unchecked(last_end..self.len()) })?;
+        Ok(s)
+    }
+
+    /// Appends an integer formatted in a given radix to the end of this `ArrayString`.
+    ///
+    /// Digits greater than 9 are formatted as lowercase letters `a` to `z`.
+    /// Negative values are preceded by a minus sign.
+    ///
+    /// Unlike [`aformat!`] and [`lformat!`], this method doesn't go through [`core::fmt`], which
+    /// makes it considerably faster and smaller in code size.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `radix` is not in the range `2..=36`, or if there is no spare
+    /// capacity to accommodate the formatted integer.
+    ///
+    /// See [`try_push_int`] for a method that returns [`InsufficientCapacityError`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let mut s = array_str![16; "x="];
+    /// s.push_int(-42, 10);
+    /// s.push_str(", y=0x");
+    /// s.push_int(255u8, 16);
+    /// assert_eq!(s, "x=-42, y=0xff");
+    /// ```
+    ///
+    /// [`try_push_int`]: ArrayString::try_push_int
+    /// [`aformat!`]: crate::aformat
+    /// [`lformat!`]: crate::lformat
+    #[inline]
+    pub fn push_int<I: Integer>(&mut self, value: I, radix: u32) {
+        self.try_push_int(value, radix)
+            .expect("insufficient capacity")
+    }
+
+    /// Tries to append an integer formatted in a given radix to the end of this `ArrayString`.
+    ///
+    /// This is a non-panic version of [`push_int`], with respect to the spare capacity.
+    ///
+    /// Returns [`InsufficientCapacityError`] if there is no enough spare capacity to accommodate
+    /// the formatted integer. In that case the array-string is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `radix` is not in the range `2..=36`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_str, arraystring::errors::InsufficientCapacityError};
+    /// let mut s = array_str![4;];
+    /// assert!(s.try_push_int(1234u16, 10).is_ok());
+    /// assert!(matches!(s.try_push_int(5, 10), Err(InsufficientCapacityError)));
+    /// assert_eq!(s, "1234");
+    /// ```
+    ///
+    /// [`push_int`]: ArrayString::push_int
+    #[inline]
+    pub fn try_push_int<I: Integer>(
+        &mut self,
+        value: I,
+        radix: u32,
+    ) -> Result<(), InsufficientCapacityError> {
+        self.try_push_int_padded(value, radix, 0)
+    }
+
+    /// Appends an integer formatted in a given radix, and zero-padded to a given width, to the end
+    /// of this `ArrayString`.
+    ///
+    /// If the formatted integer is shorter than `width` bytes, it is padded with leading zeros
+    /// (after the minus sign for negative values), similar to `{:0width$}` format specifier.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `radix` is not in the range `2..=36`, or if there is no spare
+    /// capacity to accommodate the formatted integer.
+    ///
+    /// See [`try_push_int_padded`] for a method that returns [`InsufficientCapacityError`]
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let mut s = array_str![16;];
+    /// s.push_int_padded(7, 10, 3);
+    /// s.push(' ');
+    /// s.push_int_padded(-7, 10, 3);
+    /// s.push(' ');
+    /// s.push_int_padded(1234, 10, 3);
+    /// assert_eq!(s, "007 -07 1234");
+    /// ```
+    ///
+    /// [`try_push_int_padded`]: ArrayString::try_push_int_padded
+    #[inline]
+    pub fn push_int_padded<I: Integer>(&mut self, value: I, radix: u32, width: usize) {
+        self.try_push_int_padded(value, radix, width)
+            .expect("insufficient capacity")
+    }
+
+    /// Tries to append an integer formatted in a given radix, and zero-padded to a given width,
+    /// to the end of this `ArrayString`.
+    ///
+    /// This is a non-panic version of [`push_int_padded`], with respect to the spare capacity.
+    ///
+    /// Returns [`InsufficientCapacityError`] if there is no enough spare capacity to accommodate
+    /// the formatted integer. In that case the array-string is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `radix` is not in the range `2..=36`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_str, arraystring::errors::InsufficientCapacityError};
+    /// let mut s = array_str![4;];
+    /// assert!(matches!(s.try_push_int_padded(1, 16, 5), Err(InsufficientCapacityError)));
+    /// assert!(s.try_push_int_padded(0xab, 16, 4).is_ok());
+    /// assert_eq!(s, "00ab");
+    /// ```
+    ///
+    /// [`push_int_padded`]: ArrayString::push_int_padded
+    #[inline]
+    pub fn try_push_int_padded<I: Integer>(
+        &mut self,
+        value: I,
+        radix: u32,
+        width: usize,
+    ) -> Result<(), InsufficientCapacityError> {
+        assert!((2..=36).contains(&radix), "invalid radix");
+
+        let mut buf = [0u8; INT_BUF_LEN];
+        let mut start = value.write_radix(radix, &mut buf);
+        let digits_len = INT_BUF_LEN - start;
+
+        let pad_len = width.saturating_sub(digits_len);
+        if digits_len + pad_len > self.spare_capacity() {
+            return Err(InsufficientCapacityError);
+        }
+
+        unsafe {
+            let mut p = self.as_mut_ptr().add(self.len());
+            if pad_len > 0 {
+                if buf[start] == b'-' {
+                    p.write(b'-');
+                    p = p.add(1);
+                    start += 1;
+                }
+                ptr::write_bytes(p, b'0', pad_len);
+                p = p.add(pad_len);
+            }
+            ptr::copy_nonoverlapping(buf.as_ptr().add(start), p, INT_BUF_LEN - start);
+            self.len += digits_len + pad_len;
+        }
+
+        Ok(())
+    }
+
+    /// Appends the lowercase hexadecimal representation of a byte slice to the end of this
+    /// `ArrayString`.
+    ///
+    /// Every byte is formatted as exactly two hexadecimal digits.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there is no spare capacity to accommodate the formatted bytes.
+    ///
+    /// See [`try_push_hex`] for a method that returns [`InsufficientCapacityError`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let mut s = array_str![16; "0x"];
+    /// s.push_hex(&[0xde, 0xad, 0x0b]);
+    /// assert_eq!(s, "0xdead0b");
+    /// ```
+    ///
+    /// [`try_push_hex`]: ArrayString::try_push_hex
+    #[inline]
+    pub fn push_hex(&mut self, bytes: &[u8]) {
+        self.try_push_hex(bytes).expect("insufficient capacity")
+    }
+
+    /// Tries to append the lowercase hexadecimal representation of a byte slice to the end of
+    /// this `ArrayString`.
+    ///
+    /// This is a non-panic version of [`push_hex`].
+    ///
+    /// Returns [`InsufficientCapacityError`] if there is no enough spare capacity to accommodate
+    /// the formatted bytes. In that case the array-string is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_str, arraystring::errors::InsufficientCapacityError};
+    /// let mut s = array_str![4;];
+    /// assert!(matches!(s.try_push_hex(&[1, 2, 3]), Err(InsufficientCapacityError)));
+    /// assert!(s.try_push_hex(&[1, 2]).is_ok());
+    /// assert_eq!(s, "0102");
+    /// ```
+    ///
+    /// [`push_hex`]: ArrayString::push_hex
+    #[inline]
+    pub fn try_push_hex(&mut self, bytes: &[u8]) -> Result<(), InsufficientCapacityError> {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+
+        match bytes.len().checked_mul(2) {
+            Some(len) if len <= self.spare_capacity() => (),
+            _ => return Err(InsufficientCapacityError),
+        }
+
+        unsafe {
+            let mut p = self.as_mut_ptr().add(self.len());
+            for b in bytes {
+                p.write(HEX[(b >> 4) as usize]);
+                p.add(1).write(HEX[(b & 0xf) as usize]);
+                p = p.add(2);
+            }
+            self.len += bytes.len() * 2;
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new `ArrayString` from an integer formatted in decimal.
+    ///
+    /// Returns [`InsufficientCapacityError`] if the formatted integer doesn't fit in the capacity
+    /// of the array-string.
+    ///
+    /// See [`push_int`] for formatting in other radixes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arraystring::{errors::InsufficientCapacityError, ArrayString}, len::U8};
+    /// type S = ArrayString<4, U8>;
+    /// assert_eq!(S::from_int(-123).unwrap(), "-123");
+    /// assert!(matches!(S::from_int(12345), Err(InsufficientCapacityError)));
+    /// ```
+    ///
+    /// [`push_int`]: ArrayString::push_int
+    #[inline]
+    pub fn from_int<I: Integer>(value: I) -> Result<Self, InsufficientCapacityError> {
+        let mut s = Self::new();
+        s.try_push_int(value, 10)?;
+        Ok(s)
+    }
+}
+
+impl<L, const C: usize> ArrayString<C, L, Uninitialized>
+where
+    L: LengthType,
+{
+    /// Creates a new empty `ArrayString` in const context.
+    ///
+    /// This is a `const fn` counterpart of [`new`], intended for building constants and statics.
+    /// It is available with the [`Uninitialized`] spare memory policy only, as other policies
+    /// initialize the spare memory at runtime.
+    ///
+    /// Requested capacity `C` may not exceed the maximal value that can be stored in
+    /// length type `L`. This is verified at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arraystring::ArrayString, len::U8};
+    /// static S: ArrayString<7, U8> = ArrayString::new_const();
+    /// assert!(S.is_empty());
+    /// ```
+    ///
+    /// [`new`]: ArrayString::new
+    #[inline]
+    pub const fn new_const() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_CHECK;
+        Self {
+            // it is safe to call `assume_init` to create an array of `MaybeUninit`
+            arr: unsafe { mem::MaybeUninit::uninit().assume_init() },
+            len: len::const_new(0),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates an `ArrayString` from a string slice in const context.
+    ///
+    /// This is a `const fn` counterpart of `TryFrom<&str>`, intended for building constants
+    /// and statics from string literals. It is available with the [`Uninitialized`] spare memory
+    /// policy only, as other policies initialize the spare memory at runtime.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `s` doesn't fit into the capacity `C`.
+    ///
+    /// Unlike the capacity check of `C` against `L::MAX`, this check depends on the value of `s`
+    /// rather than on its type, and thus cannot be forced to happen at compile time. It is a
+    /// compile time error only when the method is evaluated in const context, e.g. when
+    /// initializing a `const` or a `static`. Otherwise, it is a runtime panic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arraystring::ArrayString, len::U8};
+    /// const NAME: ArrayString<16, U8> = ArrayString::from_str_const("abc");
+    /// assert_eq!(NAME, "abc");
+    /// assert_eq!(NAME.spare_capacity(), 13);
+    /// ```
+    ///
+    /// A string slice longer than the capacity fails to compile in const context:
+    ///
+    /// ```compile_fail
+    /// # #[cfg(feature = "arraystring")] {
+    /// # use cds::{arraystring::ArrayString, len::U8};
+    /// const NAME: ArrayString<2, U8> = ArrayString::from_str_const("abc");
+    /// # }
+    /// ```
+    ///
+    /// In non-const context it panics:
+    ///
+    /// ```should_panic
+    /// # use cds::{arraystring::ArrayString, len::U8};
+    /// let name = ArrayString::<2, U8>::from_str_const("abc");
+    /// ```
+    #[inline]
+    pub const fn from_str_const(s: &str) -> Self {
+        assert!(s.len() <= C, "string slice exceeds ArrayString capacity");
+        let mut a = Self::new_const();
+        unsafe { ptr::copy_nonoverlapping(s.as_ptr(), a.arr.as_mut_ptr() as *mut u8, s.len()) };
+        a.len = len::const_new(s.len());
+        a
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+impl<L, const C: usize> ArrayString<C, L, Zeroed>
+where
+    L: LengthType,
+{
+    /// Reinterprets a byte slice as an array-string, without copying.
+    ///
+    /// `bytes` must hold an array-string in the [layout] of `ArrayString`, i.e. it must be exactly
+    /// `size_of::<Self>()` bytes long and aligned to `align_of::<Self>()`. The length is
+    /// validated to be within the capacity, the bytes within the length are validated to be
+    /// UTF-8, and the spare memory after the length is validated to be zeroed, as required by the
+    /// [`Zeroed`] spare memory policy.
+    ///
+    /// This allows reading an array-string from shared memory or a DMA buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arraystring::ArrayString, len::U8, mem::Zeroed};
+    /// type S = ArrayString<7, U8, Zeroed>;
+    /// let mut buf = *b"cds\0\0\0\0\x03";
+    /// assert_eq!(S::from_bytes(&buf).unwrap(), "cds");
+    ///
+    /// buf[1] = 0xFF;
+    /// assert!(S::from_bytes(&buf).is_err());
+    ///
+    /// buf[1] = b'd';
+    /// buf[5] = b'!'; // <-- non-zero spare memory
+    /// assert!(S::from_bytes(&buf).is_err());
+    /// ```
+    ///
+    /// [layout]: ArrayString#layout
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, FromBytesError> {
+        Self::check_bytes(bytes)?;
+        // SAFETY: the size and alignment are verified, the length is valid, the bytes
+        // within the length are valid UTF-8, and the spare memory is zeroed
+        Ok(unsafe { &*bytes.as_ptr().cast::<Self>() })
+    }
+
+    /// Reinterprets a mutable byte slice as an array-string, without copying.
+    ///
+    /// This is similar to [`from_bytes`], but allows modifying an array-string in place, e.g.
+    /// in shared memory. Since writing a whole array-string may leave its padding bytes
+    /// uninitialized, this method fails to compile if `Self` has any padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arraystring::ArrayString, len::U8, mem::Zeroed};
+    /// type S = ArrayString<7, U8, Zeroed>;
+    /// let mut buf = [0u8; 8];
+    /// let s = S::from_bytes_mut(&mut buf).unwrap();
+    /// s.push_str("cds");
+    /// assert_eq!(&buf, b"cds\0\0\0\0\x03");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use cds::{arraystring::ArrayString, len::U16, mem::Zeroed};
+    /// type S = ArrayString<7, U16, Zeroed>; // <-- 1 padding byte after the length
+    /// let mut buf = [0u16; 5];
+    /// let s = S::from_bytes_mut(bytemuck::bytes_of_mut(&mut buf));
+    /// ```
+    ///
+    /// [`from_bytes`]: ArrayString::from_bytes
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, FromBytesError> {
+        const {
+            assert!(
+                mem::size_of::<Self>() == C + mem::size_of::<L>(),
+                "ArrayString has padding bytes"
+            )
+        };
+        Self::check_bytes(bytes)?;
+        // SAFETY: see `from_bytes`; in addition, `Self` has no padding bytes, hence writes
+        // through the returned reference can't leave `bytes` uninitialized
+        Ok(unsafe { &mut *bytes.as_mut_ptr().cast::<Self>() })
+    }
+
+    fn check_bytes(bytes: &[u8]) -> Result<(), FromBytesError> {
+        if bytes.len() != mem::size_of::<Self>() {
+            return Err(FromBytesError::InvalidSize);
+        }
+        if bytes.as_ptr().align_offset(mem::align_of::<Self>()) != 0 {
+            return Err(FromBytesError::Misaligned);
+        }
+        let o = mem::offset_of!(Self, len);
+        let len = match len::len_from_raw_bytes::<L>(&bytes[o..o + mem::size_of::<L>()]) {
+            Some(len) if len <= C => len,
+            _ => return Err(FromBytesError::InvalidLength),
+        };
+        if core::str::from_utf8(&bytes[..len]).is_err() {
+            return Err(FromBytesError::InvalidUtf8);
+        }
+        if bytes[len..C].iter().any(|&b| b != 0) {
+            return Err(FromBytesError::NonZeroSpareMemory);
+        }
+        Ok(())
+    }
 }
 
 pub mod errors;
 use errors::*;
 
+#[cfg(feature = "rkyv")]
+mod archived;
+#[cfg(feature = "rkyv")]
+pub use archived::*;
+
+mod drain;
+pub use drain::*;
+
 mod format;
 pub use format::*;
 
+mod int;
+pub use int::Integer;
+use int::INT_BUF_LEN;
+
 mod macros;
 mod traits;
 
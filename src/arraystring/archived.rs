@@ -0,0 +1,276 @@
+use crate::{
+    arraystring::{errors::CheckArchivedError, ArrayString},
+    len::ArchiveLength,
+    mem::SpareMemoryPolicy,
+};
+use core::{
+    fmt::{Debug, Display, Formatter},
+    ops::Deref,
+    ptr,
+};
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{fail, Fallible, Source},
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+/// An archived [`ArrayString`].
+///
+/// `ArchivedArrayString` has a fixed, inline layout: the archived length, followed by an array of
+/// `C` bytes. Hence, all archived records of the same `ArrayString` type have the same size, and
+/// may be accessed in place.
+///
+/// The length is archived as described in [`ArchiveLength`]. Spare bytes are zeroed.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use cds::{arraystring::{ArrayString, ArchivedArrayString}, len::U8};
+/// use rkyv::rancor::Error;
+///
+/// let s = ArrayString::<7, U8>::try_from("cds").unwrap();
+/// let bytes = rkyv::to_bytes::<Error>(&s).unwrap();
+/// assert_eq!(bytes.len(), 8); // 1 length byte + 7 bytes
+///
+/// let a = rkyv::access::<ArchivedArrayString<7, U8>, Error>(&bytes).unwrap();
+/// assert_eq!(a, "cds");
+///
+/// let d: ArrayString<7, U8> = rkyv::deserialize::<_, Error>(a).unwrap();
+/// assert_eq!(d, s);
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+#[repr(C)]
+pub struct ArchivedArrayString<const C: usize, L: ArchiveLength> {
+    len: L::Archived,
+    arr: [u8; C],
+}
+
+// SAFETY: `ArchivedArrayString` is `repr(C)`, and consists of portable fields only
+unsafe impl<const C: usize, L: ArchiveLength> Portable for ArchivedArrayString<C, L> {}
+
+impl<const C: usize, L: ArchiveLength> ArchivedArrayString<C, L> {
+    /// Returns the length of the archived string in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        L::unarchive_len(&self.len)
+    }
+
+    /// Returns `true` if the archived string has zero length.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the capacity of the archived string in bytes.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        C
+    }
+
+    /// Extracts a string slice of the archived string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `len <= C` and UTF-8 validity of the bytes are verified on access
+        unsafe { core::str::from_utf8_unchecked(self.arr.get_unchecked(..self.len())) }
+    }
+}
+
+impl<const C: usize, L: ArchiveLength> Deref for ArchivedArrayString<C, L> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const C: usize, L: ArchiveLength> Debug for ArchivedArrayString<C, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const C: usize, L: ArchiveLength> Display for ArchivedArrayString<C, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const C: usize, L: ArchiveLength> PartialEq<str> for ArchivedArrayString<C, L> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const C: usize, L: ArchiveLength> PartialEq<&str> for ArchivedArrayString<C, L> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const C: usize, L: ArchiveLength> PartialEq<ArchivedArrayString<C, L>> for str {
+    #[inline]
+    fn eq(&self, other: &ArchivedArrayString<C, L>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<const C: usize, L, SM> Archive for ArrayString<C, L, SM>
+where
+    L: ArchiveLength,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Archived = ArchivedArrayString<C, L>;
+    type Resolver = ();
+
+    fn resolve(&self, _resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: the fields are projected from a valid place of the archived type
+        let (len, arr) = unsafe {
+            let p = out.ptr();
+            (
+                Place::from_field_unchecked(out, ptr::addr_of_mut!((*p).len)),
+                Place::<[u8; C]>::from_field_unchecked(out, ptr::addr_of_mut!((*p).arr)),
+            )
+        };
+        len.write(L::archive_len(self.len()));
+        // SAFETY: `self.len() <= C`, and the source and destination don't overlap
+        unsafe { ptr::copy_nonoverlapping(self.as_ptr(), arr.ptr().cast::<u8>(), self.len()) };
+    }
+}
+
+impl<S, const C: usize, L, SM> Serialize<S> for ArrayString<C, L, SM>
+where
+    S: Fallible + ?Sized,
+    L: ArchiveLength,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn serialize(&self, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D, const C: usize, L, SM> Deserialize<ArrayString<C, L, SM>, D> for ArchivedArrayString<C, L>
+where
+    D: Fallible + ?Sized,
+    L: ArchiveLength,
+    SM: SpareMemoryPolicy<u8>,
+{
+    fn deserialize(&self, _deserializer: &mut D) -> Result<ArrayString<C, L, SM>, D::Error> {
+        let mut tmp = ArrayString::new();
+        // SAFETY: `self.len() <= C`
+        unsafe { tmp.push_str_unchecked(self.as_str()) };
+        Ok(tmp)
+    }
+}
+
+// SAFETY: `check_bytes` verifies the length, and UTF-8 validity of the bytes within the length
+unsafe impl<const C: usize, L, Ctx> CheckBytes<Ctx> for ArchivedArrayString<C, L>
+where
+    L: ArchiveLength,
+    L::Archived: CheckBytes<Ctx>,
+    Ctx: Fallible + ?Sized,
+    Ctx::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut Ctx) -> Result<(), Ctx::Error> {
+        let len = ptr::addr_of!((*value).len);
+        L::Archived::check_bytes(len, context)?;
+        let len = L::unarchive_len(&*len);
+        if len > C {
+            fail!(CheckArchivedError::LengthExceedsCapacity);
+        }
+        let arr = &*ptr::addr_of!((*value).arr);
+        if core::str::from_utf8(&arr[..len]).is_err() {
+            fail!(CheckArchivedError::InvalidUtf8);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod testing {
+    use crate as cds;
+    use cds::{
+        arraystring::{ArchivedArrayString, ArrayString},
+        arrayvec::{ArchivedArrayVec, ArrayVec},
+        len::{U16, U8, U8NZ},
+        mem::Pattern,
+    };
+    use rkyv::rancor::Error;
+
+    type S = ArrayString<15, U8>;
+    type AS = ArchivedArrayString<15, U8>;
+
+    #[test]
+    fn test_round_trip() {
+        let s = S::try_from("Hello, world!").unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&s).unwrap();
+        let a = rkyv::access::<AS, Error>(&bytes).unwrap();
+        assert_eq!(a.len(), 13);
+        assert!(!a.is_empty());
+        assert_eq!(a.capacity(), 15);
+        assert_eq!(a.as_str(), "Hello, world!");
+        assert_eq!(*a, *"Hello, world!");
+        assert_eq!(format!("{a}"), "Hello, world!");
+        assert_eq!(format!("{a:?}"), "\"Hello, world!\"");
+        let d: S = rkyv::deserialize::<_, Error>(a).unwrap();
+        assert_eq!(d, s);
+    }
+
+    #[test]
+    fn test_constant_size() {
+        let e = rkyv::to_bytes::<Error>(&S::new()).unwrap();
+        let f = rkyv::to_bytes::<Error>(&S::try_from("0123456789abcde").unwrap()).unwrap();
+        assert_eq!(e.len(), 16);
+        assert_eq!(f.len(), 16);
+        let a = rkyv::access::<AS, Error>(&e).unwrap();
+        assert!(a.is_empty());
+        assert_eq!(a, "");
+    }
+
+    #[test]
+    fn test_spare_bytes_zeroed() {
+        type P = ArrayString<4, U8NZ, Pattern<0xAB>>;
+        let s = P::try_from("ab").unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&s).unwrap();
+        assert_eq!(&bytes[..], &[2, b'a', b'b', 0, 0]);
+        let a = rkyv::access::<ArchivedArrayString<4, U8NZ>, Error>(&bytes).unwrap();
+        let d: P = rkyv::deserialize::<_, Error>(a).unwrap();
+        assert_eq!(d, "ab");
+    }
+
+    #[test]
+    fn test_in_array_vec() {
+        type V = ArrayVec<S, 3, U16>;
+        let mut v = V::new();
+        v.push(S::try_from("abc").unwrap());
+        v.push(S::try_from("def").unwrap());
+        let bytes = rkyv::to_bytes::<Error>(&v).unwrap();
+        assert_eq!(bytes.len(), 2 + 3 * 16);
+        let a = rkyv::access::<ArchivedArrayVec<AS, 3, U16>, Error>(&bytes).unwrap();
+        assert_eq!(a.len(), 2);
+        assert_eq!(a[1], "def");
+        let d: V = rkyv::deserialize::<_, Error>(a).unwrap();
+        assert_eq!(d, v);
+    }
+
+    #[test]
+    fn test_check_bytes() {
+        let s = S::try_from("abc").unwrap();
+        let mut bytes = rkyv::to_bytes::<Error>(&s).unwrap().to_vec();
+        assert!(rkyv::access::<AS, Error>(&bytes).is_ok());
+
+        bytes[2] = 0xFF;
+        let e = rkyv::access::<AS, Error>(&bytes).unwrap_err();
+        assert!(format!("{e}").contains("arraystring archived error: invalid utf-8"));
+
+        bytes[2] = b'b';
+        bytes[0] = 16;
+        let e = rkyv::access::<AS, Error>(&bytes).unwrap_err();
+        assert!(format!("{e}").contains("arraystring archived error: length exceeds capacity"));
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{arraystring::ArrayString, len::LengthType, mem::SpareMemoryPolicy};
+use core::{
+    fmt::{Debug, Formatter},
+    iter::{DoubleEndedIterator, FusedIterator, Iterator},
+    ops::Drop,
+    ptr,
+    str::Chars,
+};
+
+/// A draining iterator for [`ArrayString`].
+///
+/// See [`ArrayString::drain`] for more information.
+pub struct Drain<'a, L, SM, const C: usize>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    // the owner ArrayString
+    pub(super) s: ptr::NonNull<ArrayString<C, L, SM>>,
+    // an iterator over the characters to be drained
+    pub(super) iter: Chars<'a>,
+    // the byte index of the first character past the drained range
+    pub(super) tail: usize,
+    // the length of the tail to preserve, in bytes
+    pub(super) tail_len: usize,
+}
+
+impl<'a, L, SM, const C: usize> Drain<'a, L, SM, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    /// Returns the remaining (sub)string of this iterator as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_str;
+    /// let mut s = array_str![8; "abc"];
+    /// let mut drain = s.drain(..);
+    /// assert_eq!(drain.as_str(), "abc");
+    /// assert_eq!(drain.next(), Some('a'));
+    /// assert_eq!(drain.as_str(), "bc");
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.iter.as_str()
+    }
+}
+
+impl<L, SM, const C: usize> Debug for Drain<'_, L, SM, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_str()).finish()
+    }
+}
+
+impl<L, SM, const C: usize> AsRef<str> for Drain<'_, L, SM, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<L, SM, const C: usize> AsRef<[u8]> for Drain<'_, L, SM, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl<L, SM, const C: usize> Iterator for Drain<'_, L, SM, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<char> {
+        self.next_back()
+    }
+}
+
+impl<L, SM, const C: usize> DoubleEndedIterator for Drain<'_, L, SM, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        self.iter.next_back()
+    }
+}
+
+impl<L, SM, const C: usize> FusedIterator for Drain<'_, L, SM, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+}
+
+impl<L, SM, const C: usize> Drop for Drain<'_, L, SM, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn drop(&mut self) {
+        // Append the tail to the head to preserve array-string's continuity,
+        // and invoke SpareMemoryPolicy on the freed bytes.
+
+        let s = unsafe { self.s.as_mut() };
+        let head = s.len(); // `ArrayString::drain` sets `len` to reflect the head only.
+        let tail = self.tail;
+        let tail_len = self.tail_len;
+
+        debug_assert!(tail >= head); // `ArrayString::drain` must ensure this
+
+        unsafe {
+            let base_p = s.as_mut_ptr();
+            let new_len = head + tail_len;
+            ptr::copy(base_p.add(tail), base_p.add(head), tail_len);
+            SM::init(base_p.add(new_len), tail - head);
+            s.set_len(new_len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate as cds;
+    use crate::{
+        array_str,
+        arraystring::{test_arraystring::check_spare_memory, ArrayString},
+        len::U8,
+        mem::Pattern,
+    };
+
+    type S = ArrayString<16, U8, Pattern<0xBA>>;
+
+    #[test]
+    fn test_drain_full() {
+        let mut s = S::try_from("2€ab").unwrap();
+        assert!(s.drain(..).eq("2€ab".chars()));
+        assert_eq!(s, "");
+        check_spare_memory(&s, 0xBA);
+    }
+
+    #[test]
+    fn test_drain_partially_consumed() {
+        let mut s = S::try_from("a€bc€d").unwrap();
+        let mut d = s.drain(1..9);
+        assert_eq!(d.next(), Some('€'));
+        assert_eq!(d.next_back(), Some('€'));
+        assert_eq!(d.as_str(), "bc");
+        drop(d);
+        assert_eq!(s, "ad");
+        check_spare_memory(&s, 0xBA);
+    }
+
+    #[test]
+    fn test_drain_rev() {
+        let mut s = array_str![8; "abcd"];
+        assert!(s.drain(1..=2).rev().eq("cb".chars()));
+        assert_eq!(s, "ad");
+    }
+
+    #[test]
+    fn test_drain_empty_range() {
+        let mut s = S::try_from("abc").unwrap();
+        let mut d = s.drain(1..1);
+        assert_eq!(d.next(), None);
+        assert_eq!(d.as_str(), "");
+        drop(d);
+        assert_eq!(s, "abc");
+        check_spare_memory(&s, 0xBA);
+    }
+
+    #[test]
+    fn test_drain_tail() {
+        let mut s = S::try_from("abc€").unwrap();
+        assert!(s.drain(1..).eq("bc€".chars()));
+        assert_eq!(s, "a");
+        check_spare_memory(&s, 0xBA);
+    }
+
+    #[test]
+    fn test_drain_forget() {
+        let mut s = S::try_from("abcd").unwrap();
+        core::mem::forget(s.drain(1..3));
+        assert_eq!(s, "a");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_drain_debug() {
+        let mut s = array_str![8; "abc"];
+        let d = s.drain(1..);
+        assert_eq!(format!("{:?}", d), "Drain(\"bc\")");
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_try_drain_invalid() {
+        let mut s = array_str![8; "2€"];
+        assert!(s.try_drain(2..).is_err());
+        assert!(s.try_drain(..2).is_err());
+        assert!(s.try_drain(1..5).is_err());
+        assert!(s.try_drain(1..0).is_err());
+        assert!(s
+            .try_drain((
+                core::ops::Bound::Excluded(usize::MAX),
+                core::ops::Bound::Unbounded
+            ))
+            .is_err());
+        assert!(s.try_drain(..=usize::MAX).is_err());
+        assert_eq!(s, "2€");
+        assert!(s.try_drain(1..4).unwrap().eq("€".chars()));
+        assert_eq!(s, "2");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_drain_panics_on_char_boundary() {
+        let mut s = array_str![8; "2€"];
+        s.drain(..2);
+    }
+}
@@ -12,6 +12,7 @@ use core::{
 
 /// An error returned when there is no enough spare capacity.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct InsufficientCapacityError;
 
 impl Display for InsufficientCapacityError {
@@ -31,6 +32,7 @@ impl std::error::Error for InsufficientCapacityError {}
 /// [`try_insert`]: super::ArrayString::try_insert
 /// [`try_insert_str`]: super::ArrayString::try_insert_str
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum InsertError {
     /// Index is out of bounds, or doesn't lie on a character boundary.
     InvalidIndex,
@@ -59,6 +61,7 @@ impl std::error::Error for InsertError {}
 ///
 /// This error is returned when an index is out of bounds, or doesn't lie on a character boundary.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct IndexError;
 
 impl core::fmt::Display for IndexError {
@@ -74,6 +77,114 @@ impl core::fmt::Display for IndexError {
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl std::error::Error for IndexError {}
 
+// ---------------------------------------------------------------------------
+
+/// An error returned from [`as_c_str`] method.
+///
+/// [`as_c_str`]: super::ArrayString::as_c_str
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CStrError {
+    /// The string contains a NUL byte at the given position.
+    InteriorNul(usize),
+
+    /// There is no spare capacity for the NUL terminator.
+    InsufficientCapacity,
+}
+
+impl Display for CStrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            CStrError::InteriorNul(pos) => {
+                write!(f, "arraystring c-str error: interior nul byte at {pos}")
+            }
+            CStrError::InsufficientCapacity => {
+                write!(f, "arraystring c-str error: insufficient capacity")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for CStrError {}
+
+// ---------------------------------------------------------------------------
+
+/// An error returned when validation of an [`ArchivedArrayString`] fails.
+///
+/// [`ArchivedArrayString`]: super::ArchivedArrayString
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CheckArchivedError {
+    /// The archived length exceeds the capacity.
+    LengthExceedsCapacity,
+
+    /// The archived bytes are not valid UTF-8.
+    InvalidUtf8,
+}
+
+#[cfg(feature = "rkyv")]
+impl Display for CheckArchivedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let s = match *self {
+            CheckArchivedError::LengthExceedsCapacity => "length exceeds capacity",
+            CheckArchivedError::InvalidUtf8 => "invalid utf-8",
+        };
+        write!(f, "arraystring archived error: {s}")
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl core::error::Error for CheckArchivedError {}
+
+/// An error returned from [`from_bytes`] and [`from_bytes_mut`] methods.
+///
+/// [`from_bytes`]: super::ArrayString::from_bytes
+/// [`from_bytes_mut`]: super::ArrayString::from_bytes_mut
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FromBytesError {
+    /// The byte slice size is different from the size of the array-string.
+    InvalidSize,
+
+    /// The byte slice is not aligned for the array-string.
+    Misaligned,
+
+    /// The length is invalid or exceeds the capacity.
+    InvalidLength,
+
+    /// The bytes within the length are not valid UTF-8.
+    InvalidUtf8,
+
+    /// The spare memory after the length is not zeroed.
+    NonZeroSpareMemory,
+}
+
+#[cfg(feature = "bytemuck")]
+impl Display for FromBytesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let s = match *self {
+            FromBytesError::InvalidSize => "invalid size",
+            FromBytesError::Misaligned => "misaligned",
+            FromBytesError::InvalidLength => "invalid length",
+            FromBytesError::InvalidUtf8 => "invalid utf-8",
+            FromBytesError::NonZeroSpareMemory => "non-zero spare memory",
+        };
+        write!(f, "arraystring from bytes error: {s}")
+    }
+}
+
+#[cfg(all(feature = "std", feature = "bytemuck"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for FromBytesError {}
+
+// ---------------------------------------------------------------------------
+
 #[cfg(all(test, feature = "std"))]
 mod testing {
     use super::*;
@@ -105,4 +216,51 @@ mod testing {
             "arraystring index error: index is out of bounds or doesn't lie on character boundary"
         );
     }
+
+    #[test]
+    fn c_str_error_display() {
+        let e = CStrError::InteriorNul(3);
+        let s = format!("{e}");
+        assert_eq!(s, "arraystring c-str error: interior nul byte at 3");
+
+        let e = CStrError::InsufficientCapacity;
+        let s = format!("{e}");
+        assert_eq!(s, "arraystring c-str error: insufficient capacity");
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn check_archived_error_display() {
+        let e = CheckArchivedError::LengthExceedsCapacity;
+        let s = format!("{e}");
+        assert_eq!(s, "arraystring archived error: length exceeds capacity");
+
+        let e = CheckArchivedError::InvalidUtf8;
+        let s = format!("{e}");
+        assert_eq!(s, "arraystring archived error: invalid utf-8");
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn from_bytes_error_display() {
+        let e = FromBytesError::InvalidSize;
+        let s = format!("{e}");
+        assert_eq!(s, "arraystring from bytes error: invalid size");
+
+        let e = FromBytesError::Misaligned;
+        let s = format!("{e}");
+        assert_eq!(s, "arraystring from bytes error: misaligned");
+
+        let e = FromBytesError::InvalidLength;
+        let s = format!("{e}");
+        assert_eq!(s, "arraystring from bytes error: invalid length");
+
+        let e = FromBytesError::InvalidUtf8;
+        let s = format!("{e}");
+        assert_eq!(s, "arraystring from bytes error: invalid utf-8");
+
+        let e = FromBytesError::NonZeroSpareMemory;
+        let s = format!("{e}");
+        assert_eq!(s, "arraystring from bytes error: non-zero spare memory");
+    }
 }
@@ -1,24 +1,99 @@
 use crate::{arraystring::ArrayString, len::LengthType, mem::SpareMemoryPolicy};
 use core::fmt::{self, Arguments, Write};
 
-struct LossyWriter<'a, const C: usize, L: LengthType, SM: SpareMemoryPolicy<u8>>(
-    &'a mut ArrayString<C, L, SM>,
-);
+/// A lossy writer into an [`ArrayString`].
+///
+/// `LossyWriter` appends formatted text to an array-string, silently dropping whatever doesn't
+/// fit in the remaining capacity. The result is always a prefix (on character boundary) of the
+/// formatted text, and writing never fails.
+///
+/// This is the writer behind [`lformat!`] and [`tformat!`], and, with the `ufmt` feature, of
+/// `ulformat!`. It is useful for lossy formatting into an existing array-string.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::{arraystring::{ArrayString, LossyWriter}, len::U8};
+/// use core::fmt::Write;
+///
+/// let mut s = ArrayString::<8, U8>::try_from("a=").unwrap();
+/// let mut w = LossyWriter::new(&mut s);
+/// write!(w, "{}", 1234567).unwrap();
+/// assert_eq!(w.finish("…"), 4);
+/// assert_eq!(s, "a=123…");
+/// ```
+///
+/// [`lformat!`]: crate::lformat
+/// [`tformat!`]: crate::tformat
+pub struct LossyWriter<'a, const C: usize, L: LengthType, SM: SpareMemoryPolicy<u8>> {
+    s: &'a mut ArrayString<C, L, SM>,
+    // the number of formatted bytes dropped due to insufficient capacity
+    dropped: usize,
+}
 
-impl<'a, L, SM, const C: usize> Write for LossyWriter<'a, C, L, SM>
+impl<'a, L, SM, const C: usize> LossyWriter<'a, C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    /// Creates a lossy writer appending to `s`.
+    #[inline]
+    pub fn new(s: &'a mut ArrayString<C, L, SM>) -> Self {
+        Self { s, dropped: 0 }
+    }
+
+    /// Finishes writing, and returns the number of bytes dropped due to insufficient capacity.
+    ///
+    /// If the written text was truncated, and `ellipsis` is not empty, the array-string ends
+    /// with `ellipsis`. This may drop more characters to make room for the ellipsis, which are
+    /// counted as dropped too.
+    #[inline]
+    pub fn finish(self, ellipsis: &str) -> usize {
+        let mut dropped = self.dropped;
+        if dropped > 0 && !ellipsis.is_empty() {
+            while self.s.spare_capacity() < ellipsis.len() {
+                match self.s.pop() {
+                    Some(ch) => dropped += ch.len_utf8(),
+                    None => break,
+                }
+            }
+            self.s.add_str(ellipsis);
+        }
+        dropped
+    }
+
+    #[inline]
+    pub(crate) fn write_str_lossy(&mut self, s: &str) {
+        // once truncated, drop everything that follows to keep the result a prefix
+        if self.dropped == 0 {
+            self.dropped = s.len() - self.s.add_str(s);
+        } else {
+            self.dropped += s.len();
+        }
+    }
+
+    #[inline]
+    pub(crate) fn write_char_lossy(&mut self, c: char) {
+        if self.dropped > 0 || self.s.try_push(c).is_err() {
+            self.dropped += c.len_utf8();
+        }
+    }
+}
+
+impl<L, SM, const C: usize> Write for LossyWriter<'_, C, L, SM>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<u8>,
 {
     #[inline]
     fn write_str(&mut self, s: &str) -> core::fmt::Result {
-        self.0.add_str(s);
+        self.write_str_lossy(s);
         Ok(())
     }
 
     #[inline]
     fn write_char(&mut self, c: char) -> core::fmt::Result {
-        self.0.try_push(c).ok();
+        self.write_char_lossy(c);
         Ok(())
     }
 }
@@ -57,18 +132,69 @@ where
 /// assert_eq!(s, "a=25");  // <-- !! the result may be completely wrong in some use cases
 /// ```
 ///
+/// See [`format_truncated`] for a function that reports the truncation, and optionally ends
+/// a truncated result with an ellipsis.
+///
 /// [`format`]: std::fmt::format
 /// [`format_args!`]: core::format_args
 #[inline]
 pub fn format_lossy<const C: usize, L, SM>(args: Arguments<'_>) -> ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    format_truncated(args, "").0
+}
+
+/// Formats an `ArrayString` possibly truncating the result, and reports the truncation.
+///
+/// This function is similar to [`format_lossy`], but returns the number of bytes of the formatted
+/// string that were dropped due to insufficient capacity, along with the resulting array-string.
+/// A zero indicates that the string was not truncated.
+///
+/// If the result is truncated, and `ellipsis` is not empty, the result ends with `ellipsis`
+/// (e.g. `"…"` or `"..."`). This may drop more characters of the formatted string to make room
+/// for the ellipsis, which are counted as dropped too. An empty `ellipsis` disables this mode.
+///
+/// The [`Arguments`] instance can be created with the [`format_args!`] macro.
+/// See the [`tformat!`] macro for a convenience wrapper of this function.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::{arraystring::{format_truncated, ArrayString}, len::U8};
+/// # use core::format_args;
+/// type S = ArrayString<16, U8>;
+/// let (s, dropped): (S, usize) = format_truncated(format_args!("a={}", 2500), "");
+/// assert_eq!(s, "a=2500");
+/// assert_eq!(dropped, 0);
+///
+/// type T = ArrayString<4, U8>;
+/// let (s, dropped): (T, usize) = format_truncated(format_args!("a={}", 2500), "");
+/// assert_eq!(s, "a=25");
+/// assert_eq!(dropped, 2);
+///
+/// let (s, dropped): (T, usize) = format_truncated(format_args!("a={}", 2500), "..");
+/// assert_eq!(s, "a=..");
+/// assert_eq!(dropped, 4);
+/// ```
+///
+/// [`format_args!`]: core::format_args
+/// [`tformat!`]: crate::tformat
+#[inline]
+pub fn format_truncated<const C: usize, L, SM>(
+    args: Arguments<'_>,
+    ellipsis: &str,
+) -> (ArrayString<C, L, SM>, usize)
 where
     L: LengthType,
     SM: SpareMemoryPolicy<u8>,
 {
     let mut s = ArrayString::<C, L, SM>::new();
-    let mut pw = LossyWriter(&mut s);
-    pw.write_fmt(args).ok();
-    s
+    let mut lw = LossyWriter::new(&mut s);
+    lw.write_fmt(args).ok();
+    let dropped = lw.finish(ellipsis);
+    (s, dropped)
 }
 
 /// Formats an `ArrayString`.
@@ -137,5 +263,78 @@ mod testing {
 
         let s = cds::lformat!(0, "cds");
         assert_eq!(s, "");
+
+        // the result is always a prefix of the formatted string
+        let s = cds::lformat!(5, "{}{}", "2€€", 'a');
+        assert_eq!(s, "2€");
+        let s = cds::lformat!(5, "{}{}", "2€€", "a");
+        assert_eq!(s, "2€");
+    }
+
+    #[test]
+    fn test_format_lossy_ellipsis() {
+        let s = cds::lformat!(8, ellipsis = "…", "cds");
+        assert_eq!(s, "cds");
+
+        let s = cds::lformat!(8, ellipsis = "…", "Hello, world!");
+        assert_eq!(s, "Hello…");
+
+        let s = cds::lformat!(8, ellipsis = "...", "Hello, world!");
+        assert_eq!(s, "Hello...");
+
+        let s = cds::lformat!(2, ellipsis = "…", "cds");
+        assert_eq!(s, "");
+
+        type S = cds::arraystring::ArrayString<4, cds::len::U8>;
+        let s = cds::lformat!(S, ellipsis = "...", "a€b");
+        assert_eq!(s, "a...");
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn test_ufmt_format_lossy() {
+        let s = cds::ulformat!(16, "Hello, {}!", "world");
+        assert_eq!(s, "Hello, world!");
+
+        let s = cds::ulformat!(5, "{}{}", "2€€", 'a');
+        assert_eq!(s, "2€");
+
+        let s = cds::ulformat!(8, ellipsis = "…", "{:?}", [1u8, 2, 3]);
+        assert_eq!(s, "[1, 2…");
+
+        type S = cds::arraystring::ArrayString<4, cds::len::U8>;
+        let s = cds::ulformat!(S, ellipsis = "..", "a={}", 2500u16);
+        assert_eq!(s, "a=..");
+
+        let s = cds::ulformat!(S, "{}", 'a');
+        assert_eq!(s, "a");
+    }
+
+    #[test]
+    fn test_format_truncated() {
+        let (s, dropped) = cds::tformat!(16, "Hello, world!");
+        assert_eq!(s, "Hello, world!");
+        assert_eq!(dropped, 0);
+
+        let (s, dropped) = cds::tformat!(5, "{}{}", "2€€", 'a');
+        assert_eq!(s, "2€");
+        assert_eq!(dropped, 4);
+
+        let (s, dropped) = cds::tformat!(5, ellipsis = "…", "{}", "2€€");
+        assert_eq!(s, "2…");
+        assert_eq!(dropped, 6);
+
+        let (s, dropped) = cds::tformat!(2, ellipsis = "…", "cds");
+        assert_eq!(s, "");
+        assert_eq!(dropped, 3);
+
+        type S = cds::arraystring::ArrayString<4, cds::len::U8>;
+        let (s, dropped) = cds::tformat!(S, ellipsis = "..", "a={}", 2500);
+        assert_eq!(s, "a=..");
+        assert_eq!(dropped, 4);
+
+        let (s, dropped) = cds::tformat!(S, "{}", 'a');
+        assert_eq!(s, "a");
+        assert_eq!(dropped, 0);
     }
 }
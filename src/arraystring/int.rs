@@ -0,0 +1,125 @@
+// the maximal length of a formatted integer: `i128::MIN` in radix 2
+pub(super) const INT_BUF_LEN: usize = 129;
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+mod int_private {
+    pub trait IntegerBase: Copy {
+        /// Writes the digits of `self` in `radix` into the end of `buf`, preceded by a minus sign
+        /// if `self` is negative.
+        ///
+        /// Returns the index of the first written byte. `radix` must be in the range `2..=36`.
+        fn write_radix(self, radix: u32, buf: &mut [u8; super::INT_BUF_LEN]) -> usize;
+    }
+}
+
+use int_private::IntegerBase;
+
+/// A trait of primitive integer types that can be formatted into an `ArrayString`.
+///
+/// This trait is implemented for all primitive integer types and cannot be implemented outside
+/// of this crate.
+///
+/// See [`ArrayString::push_int`] for more information.
+///
+/// [`ArrayString::push_int`]: super::ArrayString::push_int
+pub trait Integer: IntegerBase {}
+
+macro_rules! write_digits {
+    ($value:expr, $radix:expr, $buf:expr, $ty:ty) => {{
+        let mut v = $value;
+        let mut i = $buf.len();
+        if $radix == 10 {
+            // a separate loop lets the compiler optimize division by a constant
+            loop {
+                i -= 1;
+                $buf[i] = DIGITS[(v % 10) as usize];
+                v /= 10;
+                if v == 0 {
+                    break;
+                }
+            }
+        } else {
+            let r = $radix as $ty;
+            loop {
+                i -= 1;
+                $buf[i] = DIGITS[(v % r) as usize];
+                v /= r;
+                if v == 0 {
+                    break;
+                }
+            }
+        }
+        i
+    }};
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl IntegerBase for $t {
+                #[inline]
+                fn write_radix(self, radix: u32, buf: &mut [u8; INT_BUF_LEN]) -> usize {
+                    write_digits!(self, radix, buf, $t)
+                }
+            }
+
+            impl Integer for $t {}
+        )*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl IntegerBase for $t {
+                #[inline]
+                fn write_radix(self, radix: u32, buf: &mut [u8; INT_BUF_LEN]) -> usize {
+                    let mut i = write_digits!(self.unsigned_abs(), radix, buf, $u);
+                    if self < 0 {
+                        i -= 1;
+                        buf[i] = b'-';
+                    }
+                    i
+                }
+            }
+
+            impl Integer for $t {}
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+#[cfg(all(test, feature = "std"))]
+mod testing {
+    use super::*;
+
+    fn fmt<I: Integer>(v: I, radix: u32) -> std::string::String {
+        let mut buf = [0u8; INT_BUF_LEN];
+        let i = v.write_radix(radix, &mut buf);
+        std::string::String::from_utf8(buf[i..].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_write_radix() {
+        assert_eq!(fmt(0u8, 10), "0");
+        assert_eq!(fmt(u8::MAX, 10), "255");
+        assert_eq!(fmt(i8::MIN, 10), "-128");
+        assert_eq!(fmt(i8::MIN, 16), "-80");
+        assert_eq!(fmt(u64::MAX, 16), "ffffffffffffffff");
+        assert_eq!(fmt(35u32, 36), "z");
+        assert_eq!(fmt(-5i32, 2), "-101");
+        assert_eq!(fmt(i128::MIN, 10), i128::MIN.to_string());
+        assert_eq!(fmt(u128::MAX, 10), u128::MAX.to_string());
+        assert_eq!(
+            fmt(isize::MIN, 8),
+            std::format!("-{:o}", isize::MIN.unsigned_abs())
+        );
+
+        let s = fmt(i128::MIN, 2);
+        assert_eq!(s.len(), INT_BUF_LEN);
+        assert!(s.starts_with("-1"));
+    }
+}
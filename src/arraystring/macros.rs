@@ -94,13 +94,33 @@ macro_rules! array_str {
 /// assert_eq!(s, "a=250");  // <-- !! the result may be wrong in some use cases
 /// ```
 ///
+/// Specify `ellipsis = <&str>` before the format string to make a truncated result end with
+/// an ellipsis. See [`tformat!`] for a macro that reports the truncation as well.
+///
+/// ```rust
+/// # use cds::{lformat, len::U8, arraystring::ArrayString};
+/// let s = lformat!(8, ellipsis = "…", "Hello, world!");
+/// assert_eq!(s, "Hello…");
+///
+/// type A = ArrayString<8, U8>;
+/// let s = lformat!(A, ellipsis = "...", "a={}", 2500);
+/// assert_eq!(s, "a=2500");  // <-- the ellipsis is used only when the result is truncated
+/// ```
+///
 /// [`ArrayString`]: crate::arraystring::ArrayString
 /// [`format_lossy`]: crate::arraystring::format_lossy
+/// [`tformat!`]: crate::tformat
 /// [`Usize`]: crate::len::Usize
 /// [`Uninitialized`]: crate::mem::Uninitialized
 #[cfg_attr(docsrs, doc(cfg(feature = "arraystring")))]
 #[macro_export]
 macro_rules! lformat {
+    ($c:literal, ellipsis = $e:expr, $($arg:tt)*) => {{
+        cds::tformat!($c, ellipsis = $e, $($arg)*).0
+    }};
+    ($s:ty, ellipsis = $e:expr, $($arg:tt)*) => {{
+        cds::tformat!($s, ellipsis = $e, $($arg)*).0
+    }};
     ($c:literal, $($arg:tt)*) => {{
         let res = cds::arraystring::format_lossy::<$c, cds::len::Usize, cds::mem::Uninitialized>(
             core::format_args!($($arg)*),
@@ -113,6 +133,124 @@ macro_rules! lformat {
     }};
 }
 
+/// Formats an [`ArrayString`] with [`ufmt`], possibly truncating the result.
+///
+/// This macro is a variant of [`lformat!`] that formats with [`ufmt::uwrite!`] instead of
+/// [`core::fmt`], which is often preferred on embedded targets for its smaller code size.
+/// Hence, only `{}` ([`uDisplay`]) and `{:?}` ([`uDebug`]) arguments are supported, and the
+/// calling crate must depend on `ufmt` directly.
+///
+/// Like `lformat!`, the result is truncated (on character boundary) to fit the given capacity,
+/// and `ellipsis = <&str>` before the format string makes a truncated result end with an
+/// ellipsis. The formatting is done with [`LossyWriter`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::{ulformat, len::U8, arraystring::ArrayString};
+/// let s = ulformat!(16, "Hello, {}!", "world");
+/// assert_eq!(s, "Hello, world!");
+/// assert_eq!(s.capacity(), 16);
+///
+/// type A = ArrayString<8, U8>;
+/// let s = ulformat!(A, "a={}", 2500u16);
+/// assert_eq!(s, "a=2500");
+///
+/// let s = ulformat!(A, ellipsis = "...", "Hello, {}!", "world");
+/// assert_eq!(s, "Hello...");
+/// ```
+///
+/// [`ArrayString`]: crate::arraystring::ArrayString
+/// [`lformat!`]: crate::lformat
+/// [`LossyWriter`]: crate::arraystring::LossyWriter
+/// [`uDisplay`]: ufmt::uDisplay
+/// [`uDebug`]: ufmt::uDebug
+#[cfg(feature = "ufmt")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "arraystring", feature = "ufmt"))))]
+#[macro_export]
+macro_rules! ulformat {
+    ($c:literal, ellipsis = $e:expr, $($arg:tt)*) => {{
+        cds::ulformat!(
+            cds::arraystring::ArrayString<$c, cds::len::Usize, cds::mem::Uninitialized>,
+            ellipsis = $e,
+            $($arg)*
+        )
+    }};
+    ($s:ty, ellipsis = $e:expr, $($arg:tt)*) => {{
+        let mut res = <$s>::new();
+        let mut lw = cds::arraystring::LossyWriter::new(&mut res);
+        ufmt::uwrite!(lw, $($arg)*).ok();
+        lw.finish($e);
+        res
+    }};
+    ($c:literal, $($arg:tt)*) => {{
+        cds::ulformat!($c, ellipsis = "", $($arg)*)
+    }};
+    ($s:ty, $($arg:tt)*) => {{
+        cds::ulformat!($s, ellipsis = "", $($arg)*)
+    }};
+}
+
+/// Formats an [`ArrayString`] possibly truncating the result, and reports the truncation.
+///
+/// This macro is similar to [`lformat!`], but yields a tuple of the resulting [`ArrayString`] and
+/// the number of bytes of the formatted string that were dropped due to insufficient capacity.
+/// A zero indicates that the result was not truncated.
+///
+/// Specify `ellipsis = <&str>` before the format string to make a truncated result end with
+/// an ellipsis, e.g. `"…"` or `"..."`. Characters dropped to make room for the ellipsis are
+/// counted as dropped too.
+///
+/// This macro is a convenience wrapper of the [`format_truncated`] function.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::tformat;
+/// let (s, dropped) = tformat!(16, "a={}", 2500);
+/// assert_eq!(s, "a=2500");
+/// assert_eq!(dropped, 0);
+///
+/// let (s, dropped) = tformat!(4, "a={}", 2500);
+/// assert_eq!(s, "a=25");
+/// assert_eq!(dropped, 2);
+/// ```
+///
+/// Format an `ArrayString` specifying the array-string type, and ending truncated results with
+/// an ellipsis:
+///
+/// ```rust
+/// # use cds::{tformat, len::U8, mem::Pattern, arraystring::ArrayString};
+/// type A = ArrayString<8, U8, Pattern<0xCD>>;
+/// let (s, dropped) = tformat!(A, ellipsis = "...", "Hello, world!");
+/// assert_eq!(s, "Hello...");
+/// assert_eq!(dropped, 8);
+/// ```
+///
+/// [`ArrayString`]: crate::arraystring::ArrayString
+/// [`format_truncated`]: crate::arraystring::format_truncated
+#[cfg_attr(docsrs, doc(cfg(feature = "arraystring")))]
+#[macro_export]
+macro_rules! tformat {
+    ($c:literal, ellipsis = $e:expr, $($arg:tt)*) => {{
+        cds::arraystring::format_truncated::<$c, cds::len::Usize, cds::mem::Uninitialized>(
+            core::format_args!($($arg)*),
+            $e,
+        )
+    }};
+    ($s:ty, ellipsis = $e:expr, $($arg:tt)*) => {{
+        let res: ($s, usize) =
+            cds::arraystring::format_truncated(core::format_args!($($arg)*), $e);
+        res
+    }};
+    ($c:literal, $($arg:tt)*) => {{
+        cds::tformat!($c, ellipsis = "", $($arg)*)
+    }};
+    ($s:ty, $($arg:tt)*) => {{
+        cds::tformat!($s, ellipsis = "", $($arg)*)
+    }};
+}
+
 /// Formats an [`ArrayString`].
 ///
 /// This macro, similar to the standard [`std::format!`], formats a string yielding
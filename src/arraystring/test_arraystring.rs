@@ -2,10 +2,10 @@ use crate as cds;
 use crate::{
     array_str,
     arraystring::{
-        errors::{IndexError, InsertError, InsufficientCapacityError},
+        errors::{CStrError, IndexError, InsertError, InsufficientCapacityError},
         ArrayString,
     },
-    len::{LengthType, U8},
+    len::{LengthType, U16, U8, U8NZ},
     mem::{Pattern, SpareMemoryPolicy},
 };
 
@@ -35,6 +35,74 @@ fn test_new() {
     check_spare_memory(&s, 0xBA);
 }
 
+#[test]
+fn test_nz_size() {
+    type S = ArrayString<15, U8NZ>;
+    assert_eq!(core::mem::size_of::<S>(), 16);
+    assert_eq!(core::mem::size_of::<Option<S>>(), 16);
+
+    let s = Some(S::try_from("cds").unwrap());
+    assert!(matches!(s, Some(ref s) if s == "cds"));
+}
+
+#[test]
+fn test_new_capacity_at_max() {
+    // `CAPACITY_CHECK` accepts capacity up to `L::MAX`; see `ArrayString::new` for the
+    // `compile_fail` example of `ArrayString<256, U8>`
+    type S = ArrayString<255, U8>;
+    #[allow(clippy::let_unit_value)]
+    let () = S::CAPACITY_CHECK;
+    const S0: S = S::new_const();
+    assert_eq!(S0.capacity(), 255);
+    assert_eq!(S::new().capacity(), 255);
+}
+
+#[test]
+fn test_try_from_capacity_at_max() {
+    // the const path accepts capacity up to `L::MAX`; see `ArrayString::new` for the
+    // `compile_fail` example of `ArrayString<255, U8NZ>`
+    type S = ArrayString<254, U8NZ>;
+    const S0: S = S::from_str_const("cds");
+    assert_eq!(S0, "cds");
+    assert_eq!(S0.capacity(), 254);
+    assert_eq!(S::try_from("cds").unwrap(), S0);
+}
+
+#[test]
+fn test_const_new() {
+    const S: ArrayString<8, U8NZ> = ArrayString::new_const();
+    assert!(S.is_empty());
+    assert_eq!(S.capacity(), 8);
+    assert_eq!(S.len(), 0);
+    assert_eq!(S.as_str(), "");
+}
+
+#[test]
+fn test_from_str_const() {
+    const S: ArrayString<16, U8> = ArrayString::from_str_const("abc");
+    const LEN: usize = S.len();
+    const STR: &str = S.as_str();
+    assert_eq!(LEN, 3);
+    assert_eq!(STR, "abc");
+    assert_eq!(S.as_bytes(), b"abc");
+    assert_eq!(S.spare_capacity(), 13);
+
+    static T: ArrayString<3, U8NZ> = ArrayString::from_str_const("€");
+    assert_eq!(T, "€");
+    assert_eq!(T.spare_capacity(), 0);
+
+    let u = ArrayString::<4, U16>::from_str_const("cd");
+    assert_eq!(u, "cd");
+    assert_eq!(u.len(), u.len());
+}
+
+#[test]
+#[should_panic]
+fn test_from_str_const_panics() {
+    // not a const context, hence a runtime panic
+    ArrayString::<2, U8>::from_str_const("cds");
+}
+
 #[test]
 fn test_is_empty() {
     let mut s = array_str![16;];
@@ -364,3 +432,292 @@ fn test_add_str() {
     assert_eq!(s.add_str("12"), 1);
     assert_eq!(s, "€1");
 }
+
+#[test]
+fn test_try_extend() {
+    let mut s = ArrayString::<6, U8, Pattern<0xAB>>::try_from("a").unwrap();
+    s.try_extend("bc".chars()).unwrap();
+    assert_eq!(s, "abc");
+    assert_eq!(s.try_extend(['d', '€']), Err(InsufficientCapacityError));
+    assert_eq!(s, "abc");
+    check_spare_memory(&s, 0xAB);
+    s.try_extend(['€']).unwrap();
+    assert_eq!(s, "abc€");
+}
+
+#[test]
+fn test_make_ascii_case() {
+    let mut s = array_str![16; "Hello, Wörld!"];
+    s.make_ascii_uppercase();
+    assert_eq!(s, "HELLO, WöRLD!");
+    s.make_ascii_lowercase();
+    assert_eq!(s, "hello, wörld!");
+}
+
+#[test]
+fn test_trim_in_place() {
+    type AS = ArrayString<16, U8, Pattern<0xEF>>;
+
+    let mut s = AS::try_from("\u{3000} a b \t").unwrap();
+    s.trim_end_in_place();
+    assert_eq!(s, "\u{3000} a b");
+    check_spare_memory(&s, 0xEF);
+
+    s.trim_start_in_place();
+    assert_eq!(s, "a b");
+    check_spare_memory(&s, 0xEF);
+
+    let mut s = AS::try_from("  a  ").unwrap();
+    s.trim_in_place();
+    assert_eq!(s, "a");
+    check_spare_memory(&s, 0xEF);
+
+    let mut s = AS::try_from(" \n ").unwrap();
+    s.trim_in_place();
+    assert_eq!(s, "");
+    check_spare_memory(&s, 0xEF);
+
+    let mut s = AS::try_from("abc").unwrap();
+    s.trim_in_place();
+    assert_eq!(s, "abc");
+    check_spare_memory(&s, 0xEF);
+}
+
+#[test]
+fn test_to_lowercase_uppercase() {
+    type AS = ArrayString<8, U8, Pattern<0xEF>>;
+
+    let s = AS::try_from("ΑΒΓ").unwrap();
+    let l = s.to_lowercase().unwrap();
+    assert_eq!(l, "αβγ");
+    check_spare_memory(&l, 0xEF);
+    assert_eq!(l.to_uppercase().unwrap(), "ΑΒΓ");
+
+    let s = AS::try_from("ßß").unwrap();
+    assert_eq!(s.to_uppercase().unwrap(), "SSSS");
+
+    let s = AS::try_from("ŉŉŉŉ").unwrap();
+    assert_eq!(s.to_uppercase(), Err(InsufficientCapacityError));
+    assert_eq!(s.to_lowercase().unwrap(), "ŉŉŉŉ");
+}
+
+#[test]
+fn test_repeat() {
+    type AS = ArrayString<8, U8, Pattern<0xEF>>;
+
+    let s = AS::try_from("€").unwrap();
+    assert_eq!(s.repeat(0).unwrap(), "");
+    let r = s.repeat(2).unwrap();
+    assert_eq!(r, "€€");
+    check_spare_memory(&r, 0xEF);
+    assert_eq!(s.repeat(3), Err(InsufficientCapacityError));
+    assert_eq!(s.repeat(usize::MAX), Err(InsufficientCapacityError));
+    assert_eq!(AS::new().repeat(usize::MAX).unwrap(), "");
+}
+
+#[test]
+fn test_replace() {
+    type AS = ArrayString<8, U8, Pattern<0xEF>>;
+
+    let s = AS::try_from("a€b€").unwrap();
+    let r = s.replace("€", "-").unwrap();
+    assert_eq!(r, "a-b-");
+    check_spare_memory(&r, 0xEF);
+    assert_eq!(s.replace("x", "-").unwrap(), "a€b€");
+    assert_eq!(s.replace("€", "").unwrap(), "ab");
+    assert_eq!(s.replace("€", "€€"), Err(InsufficientCapacityError));
+
+    let s = AS::try_from("ab").unwrap();
+    assert_eq!(s.replace("", "-").unwrap(), "-a-b-");
+}
+
+#[test]
+fn test_push_int() {
+    type AS = ArrayString<8, U8, Pattern<0xEF>>;
+
+    let mut s = AS::new();
+    s.push_int(0u64, 10);
+    s.push_int(i8::MIN, 10);
+    assert_eq!(s, "0-128");
+    assert_eq!(s.try_push_int(-1000, 10), Err(InsufficientCapacityError));
+    assert_eq!(s, "0-128");
+    check_spare_memory(&s, 0xEF);
+
+    s.clear();
+    s.push_int(u32::MAX, 16);
+    assert_eq!(s, "ffffffff");
+
+    s.clear();
+    s.push_int(-35, 36);
+    s.push_int(5u8, 2);
+    assert_eq!(s, "-z101");
+}
+
+#[test]
+#[should_panic]
+fn test_push_int_panics_on_radix() {
+    let mut s = array_str![8;];
+    s.push_int(1, 37);
+}
+
+#[test]
+fn test_push_int_padded() {
+    type AS = ArrayString<8, U8, Pattern<0xEF>>;
+
+    let mut s = AS::new();
+    s.push_int_padded(5, 10, 0);
+    s.push_int_padded(-5, 10, 3);
+    s.push_int_padded(-5, 10, 1);
+    assert_eq!(s, "5-05-5");
+    assert_eq!(
+        s.try_push_int_padded(1, 10, 3),
+        Err(InsufficientCapacityError)
+    );
+    assert_eq!(s, "5-05-5");
+    check_spare_memory(&s, 0xEF);
+
+    s.clear();
+    s.push_int_padded(1u8, 2, 8);
+    assert_eq!(s, "00000001");
+}
+
+#[test]
+fn test_push_hex() {
+    type AS = ArrayString<8, U8, Pattern<0xEF>>;
+
+    let mut s = AS::new();
+    s.push_hex(&[]);
+    s.push_hex(&[0x00, 0xff, 0x1a]);
+    assert_eq!(s, "00ff1a");
+    assert_eq!(s.try_push_hex(&[0, 0]), Err(InsufficientCapacityError));
+    assert_eq!(s, "00ff1a");
+    check_spare_memory(&s, 0xEF);
+}
+
+#[test]
+fn test_from_int() {
+    type AS = ArrayString<20, U8>;
+    assert_eq!(AS::from_int(u64::MAX).unwrap(), "18446744073709551615");
+    assert_eq!(AS::from_int(i64::MIN).unwrap(), "-9223372036854775808");
+    assert_eq!(AS::from_int(i128::MIN), Err(InsufficientCapacityError));
+    assert_eq!(AS::from_int(0usize).unwrap(), "0");
+}
+
+#[test]
+fn test_as_c_str() {
+    let mut s = ArrayString::<8, U8, Pattern<0xBA>>::try_from("cds").unwrap();
+    let c = s.as_c_str().unwrap();
+    assert_eq!(c.to_bytes_with_nul(), b"cds\0");
+    assert_eq!(c.to_str(), Ok("cds"));
+    assert_eq!(s, "cds");
+    assert_eq!(unsafe { *s.as_ptr().add(3) }, 0);
+    assert!((4..8).all(|i| unsafe { *s.as_ptr().add(i) } == 0xBA));
+
+    s.push_str("abcd");
+    assert_eq!(s.as_c_str().unwrap().to_bytes(), b"cdsabcd");
+
+    s.push('e');
+    assert_eq!(s.as_c_str(), Err(CStrError::InsufficientCapacity));
+
+    let mut s = array_str![8; ""];
+    assert_eq!(s.as_c_str().unwrap().to_bytes_with_nul(), b"\0");
+
+    let mut s = array_str![8; "ab\0c"];
+    assert_eq!(s.as_c_str(), Err(CStrError::InteriorNul(2)));
+
+    let mut s = array_str![4; "\0abc"];
+    assert_eq!(s.as_c_str(), Err(CStrError::InteriorNul(0)));
+}
+
+#[cfg(feature = "bytemuck")]
+mod from_bytes {
+    use super::*;
+    use crate::{arraystring::errors::FromBytesError, len::U16, mem::Zeroed};
+
+    #[test]
+    fn test_from_bytes() {
+        type S = ArrayString<7, U8, Zeroed>;
+        let mut buf = *b"abc\0\0\0\0\x03";
+        assert_eq!(S::from_bytes(&buf).unwrap(), "abc");
+        buf[7] = 8;
+        assert!(matches!(
+            S::from_bytes(&buf),
+            Err(FromBytesError::InvalidLength)
+        ));
+        buf[7] = 3;
+        buf[2] = 0xC3;
+        assert!(matches!(
+            S::from_bytes(&buf),
+            Err(FromBytesError::InvalidUtf8)
+        ));
+        assert!(matches!(
+            S::from_bytes(&buf[..7]),
+            Err(FromBytesError::InvalidSize)
+        ));
+    }
+
+    #[test]
+    fn test_from_bytes_nz() {
+        type S = ArrayString<7, U8NZ, Zeroed>;
+        let mut buf = [0u8; 8];
+        assert!(matches!(
+            S::from_bytes(&buf),
+            Err(FromBytesError::InvalidLength)
+        ));
+        buf[7] = 1;
+        assert_eq!(S::from_bytes(&buf).unwrap(), "");
+    }
+
+    #[test]
+    fn test_from_bytes_spare_memory() {
+        type S = ArrayString<7, U8, Zeroed>;
+        let mut buf = *b"abc\0\0\0\0\x02";
+        assert!(matches!(
+            S::from_bytes(&buf),
+            Err(FromBytesError::NonZeroSpareMemory)
+        ));
+        assert!(matches!(
+            S::from_bytes_mut(&mut buf),
+            Err(FromBytesError::NonZeroSpareMemory)
+        ));
+        buf[2] = 0;
+        assert_eq!(S::from_bytes(&buf).unwrap(), "ab");
+        buf[6] = 1;
+        assert!(matches!(
+            S::from_bytes(&buf),
+            Err(FromBytesError::NonZeroSpareMemory)
+        ));
+    }
+
+    #[test]
+    fn test_from_bytes_misaligned() {
+        type S = ArrayString<6, U16, Zeroed>;
+        let buf = [0u16; 5];
+        let bytes = bytemuck::bytes_of(&buf);
+        assert!(matches!(
+            S::from_bytes(&bytes[1..9]),
+            Err(FromBytesError::Misaligned)
+        ));
+        assert_eq!(S::from_bytes(&bytes[2..10]).unwrap(), "");
+    }
+
+    #[test]
+    fn test_from_bytes_mut() {
+        type S = ArrayString<6, U16, Zeroed>;
+        let mut buf = [0u16; 4];
+        let s = S::from_bytes_mut(bytemuck::bytes_of_mut(&mut buf)).unwrap();
+        s.push_str("hello");
+        s.truncate(2);
+        assert_eq!(s, "he");
+        assert_eq!(bytemuck::bytes_of(&buf)[..6], *b"he\0\0\0\0");
+        assert_eq!(buf[3], 2);
+    }
+}
+
+#[test]
+fn test_try_clone() {
+    let s = ArrayString::<6, U8, Pattern<0xAB>>::try_from("ab€").unwrap();
+    let t = s.try_clone().unwrap();
+    assert_eq!(s, t);
+    check_spare_memory(&t, 0xAB);
+}
@@ -1,14 +1,27 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod as_mut;
 mod as_ref;
 mod borrow;
 mod clone;
 mod debug;
 mod default;
+#[cfg(feature = "defmt")]
+mod defmt;
 mod deref;
 mod display;
+#[cfg(feature = "encoding")]
+mod encoding;
 mod eq;
 mod fmt_write;
+mod from;
 mod from_str;
 mod hash;
 mod ord;
+#[cfg(feature = "proptest")]
+mod proptest;
 mod try_from;
+#[cfg(feature = "ufmt")]
+mod ufmt;
+#[cfg(feature = "bytemuck")]
+mod zeroable;
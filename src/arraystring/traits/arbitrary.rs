@@ -0,0 +1,51 @@
+use crate::{arraystring::ArrayString, len::LengthType, mem::SpareMemoryPolicy};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, L, SM, const C: usize> Arbitrary<'a> for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut s = Self::new();
+        s.add_str(<&str>::arbitrary(u)?);
+        Ok(s)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let mut s = Self::new();
+        s.add_str(<&str>::arbitrary_take_rest(u)?);
+        Ok(s)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&str>::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{arraystring::ArrayString, len::U8};
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn test_arbitrary_respects_capacity() {
+        type S = ArrayString<4, U8>;
+        let s = S::arbitrary_take_rest(Unstructured::new(b"abcdef")).unwrap();
+        assert_eq!(s, "abcd");
+
+        let s = S::arbitrary_take_rest(Unstructured::new("abcé".as_bytes())).unwrap();
+        assert_eq!(s, "abc");
+    }
+
+    #[test]
+    fn test_arbitrary() {
+        type S = ArrayString<8, U8>;
+        let mut data = b"hello".to_vec();
+        data.extend_from_slice(&(5u32).to_le_bytes());
+        let s = S::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert!(s.len() <= 5);
+        assert!("hello".starts_with(s.as_str()));
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{arraystring::ArrayString, len::LengthType, mem::SpareMemoryPolicy};
+use defmt::{Format, Formatter};
+
+/// Implementation of [`Format`] for [`ArrayString`].
+///
+/// An array-string is formatted as a string slice.
+impl<L, SM, const C: usize> Format for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn format(&self, f: Formatter<'_>) {
+        Format::format(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{
+        arraystring::{errors::*, ArrayString},
+        len::U8,
+    };
+    use defmt::Format;
+
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn test_format() {
+        assert_format::<ArrayString<16, U8>>();
+        assert_format::<InsufficientCapacityError>();
+        assert_format::<InsertError>();
+        assert_format::<IndexError>();
+        assert_format::<CStrError>();
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    arraystring::ArrayString,
+    encoding::{decode_len, encode_len, errors::*, len_width, Decode, Encode},
+    len::LengthType,
+    mem::SpareMemoryPolicy,
+};
+
+impl<L, SM, const C: usize> Encode for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        len_width::<L>() + self.len()
+    }
+
+    #[inline]
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        let n = encode_len::<L>(self.len(), buf)?;
+        let dst = buf.get_mut(n..n + self.len()).ok_or(EncodeError)?;
+        dst.copy_from_slice(self.as_bytes());
+        Ok(n + self.len())
+    }
+}
+
+impl<L, SM, const C: usize> Decode for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn decode(buf: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (len, n) = decode_len::<L>(buf, C)?;
+        let src = buf.get(n..n + len).ok_or(DecodeError::UnexpectedEnd)?;
+        let s = core::str::from_utf8(src).map_err(|_| DecodeError::InvalidUtf8)?;
+        let tmp = Self::try_from(s).map_err(|_| DecodeError::InsufficientCapacity)?;
+        Ok((tmp, n + len))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{
+        arraystring::ArrayString,
+        encoding::{errors::*, Decode, Encode},
+        len::{U16, U8},
+    };
+
+    #[test]
+    fn test_encode() {
+        let s = ArrayString::<8, U8>::try_from("2€").unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(s.encoded_len(), 5);
+        assert_eq!(s.encode(&mut buf), Ok(5));
+        assert_eq!(buf[..5], [4, b'2', 0xE2, 0x82, 0xAC]);
+        assert_eq!(s.encode(&mut buf[..4]), Err(EncodeError));
+
+        let s = ArrayString::<8, U16>::try_from("cds").unwrap();
+        assert_eq!(s.encode(&mut buf), Ok(5));
+        assert_eq!(buf[..5], [3, 0, b'c', b'd', b's']);
+    }
+
+    #[test]
+    fn test_decode() {
+        type S = ArrayString<3, U8>;
+        assert_eq!(
+            S::decode(&[2, b'a', b'b', 0]),
+            Ok((S::try_from("ab").unwrap(), 3))
+        );
+        assert_eq!(S::decode(&[2, b'a']), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(
+            S::decode(&[4, b'a', b'b', b'c', b'd']),
+            Err(DecodeError::InsufficientCapacity)
+        );
+        assert_eq!(S::decode(&[1, 0xFF]), Err(DecodeError::InvalidUtf8));
+    }
+}
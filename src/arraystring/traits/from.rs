@@ -0,0 +1,81 @@
+#![cfg(feature = "alloc")]
+
+use crate::{arraystring::ArrayString, len::LengthType, mem::SpareMemoryPolicy};
+use alloc::{borrow::Cow, boxed::Box, rc::Rc};
+
+impl<'a, L, SM, const C: usize> From<&'a ArrayString<C, L, SM>> for Cow<'a, str>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn from(s: &'a ArrayString<C, L, SM>) -> Self {
+        Cow::Borrowed(s.as_str())
+    }
+}
+
+impl<L, SM, const C: usize> From<ArrayString<C, L, SM>> for Box<str>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn from(s: ArrayString<C, L, SM>) -> Self {
+        Box::from(s.as_str())
+    }
+}
+
+impl<L, SM, const C: usize> From<ArrayString<C, L, SM>> for Rc<str>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn from(s: ArrayString<C, L, SM>) -> Self {
+        Rc::from(s.as_str())
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<L, SM, const C: usize> From<ArrayString<C, L, SM>> for alloc::sync::Arc<str>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn from(s: ArrayString<C, L, SM>) -> Self {
+        alloc::sync::Arc::from(s.as_str())
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate as cds;
+    use alloc::{borrow::Cow, boxed::Box, rc::Rc, sync::Arc};
+    use cds::array_str;
+
+    #[test]
+    fn test_cow_from() {
+        let s = array_str![8; "cds"];
+        let c = Cow::from(&s);
+        assert!(matches!(c, Cow::Borrowed("cds")));
+    }
+
+    #[test]
+    fn test_box_from() {
+        let b = Box::<str>::from(array_str![8; "cds"]);
+        assert_eq!(&*b, "cds");
+    }
+
+    #[test]
+    fn test_rc_from() {
+        let rc = Rc::<str>::from(array_str![8; "cds"]);
+        assert_eq!(&*rc, "cds");
+    }
+
+    #[test]
+    fn test_arc_from() {
+        let arc = Arc::<str>::from(array_str![8; "cds"]);
+        assert_eq!(&*arc, "cds");
+    }
+}
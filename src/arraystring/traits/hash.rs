@@ -30,4 +30,46 @@ mod testing {
 
         assert_eq!(hasher1.finish(), hasher2.finish());
     }
+
+    #[test]
+    fn test_hash_same_as_str() {
+        use cds::{arraystring::ArrayString, len::U8, mem::Pattern, testing::hasher::record};
+        for s in ["", "cds", "Hello, world!", "2€€"] {
+            assert_eq!(
+                record(&ArrayString::<16, U8>::try_from(s).unwrap()),
+                record(s)
+            );
+            assert_eq!(
+                record(&ArrayString::<16, U8, Pattern<0xAB>>::try_from(s).unwrap()),
+                record(s)
+            );
+            assert_eq!(record(&ArrayString::<32>::try_from(s).unwrap()), record(s));
+        }
+    }
+
+    #[test]
+    fn test_hash_map_lookup_by_str() {
+        use cds::{arraystring::ArrayString, len::U8};
+        use std::collections::{HashMap, HashSet};
+
+        type S = ArrayString<16, U8>;
+        let mut m = HashMap::<S, usize>::new();
+        m.insert(S::try_from("one").unwrap(), 1);
+        m.insert(S::try_from("two").unwrap(), 2);
+
+        assert_eq!(m.get("one"), Some(&1));
+        assert_eq!(m.get("two"), Some(&2));
+        assert_eq!(m.get("three"), None);
+        assert!(m.contains_key(&*String::from("one")));
+        *m.get_mut("one").unwrap() += 10;
+        assert_eq!(m.remove("one"), Some(11));
+        assert_eq!(m.len(), 1);
+
+        let set: HashSet<S> = ["a", "b"]
+            .iter()
+            .map(|s| S::try_from(*s).unwrap())
+            .collect();
+        assert!(set.contains("a"));
+        assert!(!set.contains("c"));
+    }
 }
@@ -0,0 +1,40 @@
+use crate::{
+    arraystring::ArrayString,
+    len::LengthType,
+    mem::SpareMemoryPolicy,
+    strategy::{array_string, ArrayStringStrategy},
+};
+use proptest::{
+    arbitrary::{any_with, Arbitrary},
+    collection::SizeRange,
+};
+
+impl<L, SM, const C: usize> Arbitrary for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Parameters = (SizeRange, <char as Arbitrary>::Parameters);
+    type Strategy = ArrayStringStrategy<<char as Arbitrary>::Strategy, C, L, SM>;
+
+    #[inline]
+    fn arbitrary_with((size, args): Self::Parameters) -> Self::Strategy {
+        array_string(any_with::<char>(args), size)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{arraystring::ArrayString, len::U8};
+    use proptest::prelude::*;
+
+    type S = ArrayString<5, U8>;
+
+    proptest! {
+        #[test]
+        fn test_any_respects_capacity(s in any::<S>()) {
+            prop_assert!(s.len() <= 5);
+            prop_assert!(s.chars().count() <= 5);
+        }
+    }
+}
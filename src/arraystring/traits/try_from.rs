@@ -77,6 +77,66 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<L, SM, const C: usize> TryFrom<alloc::borrow::Cow<'_, str>> for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Error = InsufficientCapacityError;
+
+    #[inline]
+    fn try_from(cow: alloc::borrow::Cow<'_, str>) -> Result<Self, Self::Error> {
+        Self::try_from(&*cow)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<L, SM, const C: usize> TryFrom<alloc::boxed::Box<str>> for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Error = InsufficientCapacityError;
+
+    #[inline]
+    fn try_from(b: alloc::boxed::Box<str>) -> Result<Self, Self::Error> {
+        Self::try_from(&*b)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<L, SM, const C: usize> TryFrom<alloc::rc::Rc<str>> for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Error = InsufficientCapacityError;
+
+    #[inline]
+    fn try_from(rc: alloc::rc::Rc<str>) -> Result<Self, Self::Error> {
+        Self::try_from(&*rc)
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<L, SM, const C: usize> TryFrom<alloc::sync::Arc<str>> for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Error = InsufficientCapacityError;
+
+    #[inline]
+    fn try_from(arc: alloc::sync::Arc<str>) -> Result<Self, Self::Error> {
+        Self::try_from(&*arc)
+    }
+}
+
 #[cfg(test)]
 mod testing {
     use crate as cds;
@@ -151,4 +211,31 @@ mod testing {
         type S = ArrayString<2, U8, Pattern<PATTERN>>;
         assert!(matches!(S::try_from(&string), Err(e) if e == InsufficientCapacityError));
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_from_alloc() {
+        use alloc::{borrow::Cow, boxed::Box, rc::Rc, string::String, sync::Arc};
+        type S = ArrayString<4, U8, Pattern<PATTERN>>;
+
+        let s = S::try_from(Cow::Borrowed("cds")).unwrap();
+        assert_eq!(s, "cds");
+        check_spare_memory(&s, PATTERN);
+        let s = S::try_from(Cow::<str>::Owned(String::from("abcd"))).unwrap();
+        assert_eq!(s, "abcd");
+        assert!(S::try_from(Cow::Borrowed("abcde")).is_err());
+
+        let s = S::try_from(Box::<str>::from("cds")).unwrap();
+        assert_eq!(s, "cds");
+        check_spare_memory(&s, PATTERN);
+        assert!(S::try_from(Box::<str>::from("abcde")).is_err());
+
+        let s = S::try_from(Rc::<str>::from("cds")).unwrap();
+        assert_eq!(s, "cds");
+        assert!(S::try_from(Rc::<str>::from("abcde")).is_err());
+
+        let s = S::try_from(Arc::<str>::from("cds")).unwrap();
+        assert_eq!(s, "cds");
+        assert!(S::try_from(Arc::<str>::from("abcde")).is_err());
+    }
 }
@@ -0,0 +1,157 @@
+use crate::{
+    arraystring::{errors::InsufficientCapacityError, ArrayString, LossyWriter},
+    len::LengthType,
+    mem::SpareMemoryPolicy,
+};
+use core::convert::Infallible;
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+/// Implementation of [`uWrite`] for [`ArrayString`].
+///
+/// Note that, as `ArrayString` is a fixed-capacity non-growable writer,
+/// these methods may fail due to capacity constraints.
+///
+/// See [`ulformat!`] for lossy formatting.
+///
+/// [`ulformat!`]: crate::ulformat
+impl<L, SM, const C: usize> uWrite for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Error = InsufficientCapacityError;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.try_push_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> Result<(), Self::Error> {
+        self.try_push(c)
+    }
+}
+
+/// Implementation of [`uWrite`] for [`LossyWriter`].
+///
+/// Writing never fails, as text that doesn't fit in the array-string is dropped.
+impl<L, SM, const C: usize> uWrite for LossyWriter<'_, C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    type Error = Infallible;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+        self.write_str_lossy(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> Result<(), Infallible> {
+        self.write_char_lossy(c);
+        Ok(())
+    }
+}
+
+impl<L, SM, const C: usize> uDisplay for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    #[inline]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(self)
+    }
+}
+
+/// Implementation of [`uDebug`] for [`ArrayString`].
+///
+/// The string is quoted and escaped as by [`char::escape_debug`].
+impl<L, SM, const C: usize> uDebug for ArrayString<C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_char('"')?;
+        let mut from = 0;
+        for (i, c) in self.char_indices() {
+            let esc = c.escape_debug();
+            if esc.len() != 1 {
+                f.write_str(&self[from..i])?;
+                for e in esc {
+                    f.write_char(e)?;
+                }
+                from = i + c.len_utf8();
+            }
+        }
+        f.write_str(&self[from..])?;
+        f.write_char('"')
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate as cds;
+    use cds::{
+        arraystring::{errors::InsufficientCapacityError, ArrayString, LossyWriter},
+        len::U8,
+    };
+    use ufmt::uwrite;
+
+    type S = ArrayString<16, U8>;
+
+    #[test]
+    fn test_write() {
+        let mut s = S::new();
+        assert!(uwrite!(s, "Hello, {}! {}", "world", 42u8).is_ok());
+        assert_eq!(s, "Hello, world! 42");
+    }
+
+    #[test]
+    fn test_write_fails() {
+        let mut s = ArrayString::<7, U8>::new();
+        assert_eq!(
+            uwrite!(s, "Hello, {}!", "world"),
+            Err(InsufficientCapacityError)
+        );
+        assert_eq!(s, "Hello, ");
+
+        let mut s = ArrayString::<1, U8>::new();
+        assert_eq!(uwrite!(s, "{}", '€'), Err(InsufficientCapacityError));
+        assert_eq!(s, "");
+    }
+
+    #[test]
+    fn test_display() {
+        let a = S::try_from("cds").unwrap();
+        let mut s = S::new();
+        uwrite!(s, "<{}>", a).unwrap();
+        assert_eq!(s, "<cds>");
+    }
+
+    #[test]
+    fn test_debug() {
+        let a = ArrayString::<8, U8>::try_from("a\"b\n€").unwrap();
+        let mut s = S::new();
+        uwrite!(s, "{:?}", a).unwrap();
+        assert_eq!(s, "\"a\\\"b\\n€\"");
+    }
+
+    #[test]
+    fn test_lossy_writer() {
+        let mut s = ArrayString::<4, U8>::new();
+        let mut w = LossyWriter::new(&mut s);
+        let Ok(()) = uwrite!(w, "a={}", 2500u16);
+        assert_eq!(w.finish(""), 2);
+        assert_eq!(s, "a=25");
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{arraystring::ArrayString, len::LengthType, mem::Zeroed};
+use bytemuck::Zeroable;
+
+// SAFETY: all-zero `ArrayString` is empty, and has zeroed spare memory
+//
+// `Pod` is not implemented, as an arbitrary length, invalid UTF-8 or non-zero spare memory is
+// invalid
+unsafe impl<L, const C: usize> Zeroable for ArrayString<C, L, Zeroed> where L: LengthType + Zeroable {}
+
+#[cfg(test)]
+mod testing {
+    use crate::{arraystring::ArrayString, len::U16, mem::Zeroed};
+    use bytemuck::Zeroable;
+
+    #[test]
+    fn test_zeroed() {
+        type S = ArrayString<8, U16, Zeroed>;
+        let mut s = S::zeroed();
+        assert!(s.is_empty());
+        s.push_str("cds");
+        assert_eq!(s, "cds");
+    }
+}
@@ -1,10 +1,13 @@
 //! A vector-like array.
 
+#[cfg(feature = "bytemuck")]
+use crate::mem::Zeroed;
 use crate::{
-    len::{LengthType, Usize},
+    len::{self, LengthType, Usize},
     mem::{SpareMemoryPolicy, Uninitialized},
 };
 use core::{
+    convert::Infallible,
     marker::PhantomData,
     mem,
     ops::{Bound, RangeBounds},
@@ -13,9 +16,20 @@ use core::{
     slice,
 };
 
+#[cfg(feature = "rkyv")]
+mod archived;
+#[cfg(feature = "rkyv")]
+pub use archived::*;
+
 mod drain;
 pub use drain::*;
 
+mod extract_if;
+pub use extract_if::*;
+
+mod into_iter;
+pub use into_iter::*;
+
 pub mod errors;
 use errors::*;
 
@@ -52,7 +66,7 @@ use retain::*;
 /// assert_eq!(v.len(), 0);
 /// assert_eq!(v.capacity(), 12);
 /// assert_eq!(v.spare_capacity(), 12);
-/// assert_eq!(v, []);
+/// assert_eq!(v, [] as [u64; 0]);
 ///
 /// v.push(1);
 /// v.push(2);
@@ -129,11 +143,27 @@ use retain::*;
 /// assert!(matches!(A::try_from_iter(iter), Err(e) if e == InsufficientCapacityError));
 /// ```
 ///
+/// # Layout
+///
+/// `ArrayVec` is `#[repr(C)]`: an array of `C` elements of type `T`, followed by the length of
+/// type `L`, followed by trailing padding up to the alignment of `T` and `L` (if any).
+///
+/// This layout is guaranteed, regardless of enabled features, and may be relied upon, e.g. when
+/// sharing an array-vector with C code or through shared memory. Changing it is considered
+/// a breaking change.
+///
+/// Note that `ArrayVec` doesn't implement `bytemuck::Pod`, even if `T` does, because not every
+/// bit pattern is a valid array-vector: the length must not exceed the capacity (and must not be
+/// zero for niche-optimized length types), and spare memory must be zeroed with the [`Zeroed`]
+/// spare memory policy. Use `from_bytes`, which validates these, instead.
+///
+/// [`Zeroed`]: crate::mem::Zeroed
 /// [`array_vec!`]: crate::array_vec
 /// [`CAPACITY`]: ArrayVec::CAPACITY
 /// [`try_from_iter`]: ArrayVec::try_from_iter
 /// [`try_push`]: ArrayVec::try_push
 /// [`push`]: ArrayVec::push
+#[repr(C)]
 pub struct ArrayVec<T, const C: usize, L = Usize, SM = Uninitialized>
 where
     L: LengthType,
@@ -165,12 +195,13 @@ where
     /// [`capacity`]: ArrayVec::capacity
     pub const CAPACITY: usize = C;
 
+    // Fails compilation of any `ArrayVec` type whose capacity exceeds `L::MAX`
+    const CAPACITY_CHECK: () = assert!(C <= L::MAX, "ArrayVec capacity exceeds L::MAX");
+
     /// Creates an empty `ArrayVec`.
     ///
-    /// # Safety
-    ///
-    /// This method panics if requested capacity `C` exceeds the maximal value that can be stored in
-    /// length type `L`.
+    /// Requested capacity `C` may not exceed the maximal value that can be stored in
+    /// length type `L`. This is verified at compile time.
     ///
     /// # Examples
     ///
@@ -180,9 +211,32 @@ where
     /// assert_eq!(a.capacity(), 8);
     /// assert_eq!(a.len(), 0);
     /// ```
+    ///
+    /// An `ArrayVec` whose capacity exceeds `L::MAX` fails to compile:
+    ///
+    /// ```compile_fail
+    /// # #[cfg(feature = "arrayvec")] {
+    /// # use cds::{arrayvec::ArrayVec, len::U8};
+    /// let a = ArrayVec::<u64, 256, U8>::new(); // U8::MAX(255) < CAPACITY(256)
+    /// # }
+    /// ```
+    ///
+    /// The same holds at the niche boundary of niche-optimized length types:
+    ///
+    /// ```compile_fail
+    /// # #[cfg(feature = "arrayvec")] {
+    /// # use cds::{arrayvec::ArrayVec, len::U8NZ};
+    /// let a = ArrayVec::<u8, 255, U8NZ>::new(); // U8NZ::MAX(254) < CAPACITY(255)
+    /// # }
+    /// ```
+    ///
+    /// See [`new_const`] for a `const fn` counterpart.
+    ///
+    /// [`new_const`]: ArrayVec::new_const
     #[inline]
     pub fn new() -> Self {
-        assert!(C <= L::MAX);
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_CHECK;
         let mut v = ArrayVec {
             // it is safe to call `assume_init` to create an array of `MaybeUninit`
             arr: unsafe { mem::MaybeUninit::uninit().assume_init() },
@@ -204,9 +258,20 @@ where
     /// a.pop();
     /// assert_eq!(a.len(), 1);
     /// ```
+    ///
+    /// This is a `const fn`, usable with statics built by [`from_array_const`]:
+    ///
+    /// ```rust
+    /// # use cds::{arrayvec::ArrayVec, len::U8};
+    /// static A: ArrayVec<u16, 4, U8> = ArrayVec::from_array_const([1, 2, 3]);
+    /// const LEN: usize = A.len();
+    /// assert_eq!(LEN, 3);
+    /// ```
+    ///
+    /// [`from_array_const`]: ArrayVec::from_array_const
     #[inline]
-    pub fn len(&self) -> usize {
-        self.len.as_usize()
+    pub const fn len(&self) -> usize {
+        len::const_as_usize(&self.len)
     }
 
     /// Returns `true` if the array-vector contains no elements.
@@ -220,8 +285,8 @@ where
     /// assert_eq!(array_vec![3; u64; 1].is_empty(), false);
     /// ```
     #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.len == 0
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     /// Returns `true` if the array-vector is completely full.
@@ -252,7 +317,7 @@ where
     ///
     /// [`as_mut_ptr`]: ArrayVec::as_mut_ptr
     #[inline]
-    pub fn as_ptr(&self) -> *const T {
+    pub const fn as_ptr(&self) -> *const T {
         self.arr.as_ptr() as *const T
     }
 
@@ -266,9 +331,18 @@ where
     }
 
     /// Extracts a slice of the entire array-vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arrayvec::ArrayVec, len::U8};
+    /// static A: ArrayVec<u16, 4, U8> = ArrayVec::from_array_const([1, 2, 3]);
+    /// const LAST: u16 = A.as_slice()[2];
+    /// assert_eq!(LAST, 3);
+    /// ```
     #[inline]
-    pub fn as_slice(&self) -> &[T] {
-        unsafe { slice::from_raw_parts(self.as_ptr(), self.len.as_usize()) }
+    pub const fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
     }
 
     /// Extracts a mutable slice of the entire array-vector.
@@ -292,7 +366,7 @@ where
     ///
     /// [`CAPACITY`]: ArrayVec::CAPACITY
     #[inline]
-    pub fn capacity(&self) -> usize {
+    pub const fn capacity(&self) -> usize {
         Self::CAPACITY
     }
 
@@ -443,7 +517,7 @@ where
     /// ```rust
     /// # use cds::{array_vec, arrayvec::errors::InsufficientCapacityErrorVal};
     /// let mut v = array_vec![2; u64];
-    /// assert_eq!(v, []);
+    /// assert_eq!(v, [] as [u64; 0]);
     ///
     /// assert!(v.try_push_val(1).is_ok());
     /// assert!(v.try_push_val(2).is_ok());
@@ -543,7 +617,7 @@ where
     /// let mut a = array_vec![16; 1, 2, 3];
     /// assert_eq!(a, [1, 2, 3]);
     /// a.clear();
-    /// assert_eq!(a, []);
+    /// assert_eq!(a, [] as [i32; 0]);
     /// ```
     #[inline]
     pub fn clear(&mut self) {
@@ -676,6 +750,180 @@ where
         Ok(tmp)
     }
 
+    /// Creates an `ArrayVec` by moving the elements of an array.
+    ///
+    /// Unlike `TryFrom<&[T]>`, this doesn't require `T: Clone`. The array length `N` may not
+    /// exceed the capacity `C`. This is verified at compile time.
+    ///
+    /// Spare memory policy `SM` is applied to the spare memory after the moved elements.
+    /// See [`from_array_const`] for a `const fn` counterpart.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arrayvec::ArrayVec, len::U8, mem::Pattern};
+    /// let a = ArrayVec::<String, 4, U8, Pattern<0xCD>>::from_array(["a".into(), "b".into()]);
+    /// assert_eq!(a, ["a", "b"]);
+    /// assert_eq!(a.spare_capacity(), 2);
+    /// ```
+    ///
+    /// An array longer than the capacity fails to compile:
+    ///
+    /// ```compile_fail
+    /// # #[cfg(feature = "arrayvec")] {
+    /// # use cds::{arrayvec::ArrayVec, len::U8, mem::Zeroed};
+    /// let a = ArrayVec::<u16, 2, U8, Zeroed>::from_array([1, 2, 3]);
+    /// # }
+    /// ```
+    ///
+    /// [`from_array_const`]: ArrayVec::from_array_const
+    #[inline]
+    pub fn from_array<const N: usize>(a: [T; N]) -> Self {
+        const { assert!(N <= C, "array length exceeds ArrayVec capacity") };
+        let mut v = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(a.as_ptr(), v.as_mut_ptr(), N);
+            mem::forget(a);
+            v.set_len(N);
+        }
+        v
+    }
+
+    /// Converts the array-vector into an array of `C` elements.
+    ///
+    /// Returns `Err(self)` if the array-vector is not full.
+    ///
+    /// Spare memory policy `SM` is applied to the memory of the moved out elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arrayvec::ArrayVec, array_vec};
+    /// let mut v = array_vec![3; u64; 1, 2];
+    /// v = v.into_array().unwrap_err();
+    /// v.push(3);
+    /// assert_eq!(v.into_array(), Ok([1, 2, 3]));
+    /// ```
+    #[inline]
+    pub fn into_array(mut self) -> Result<[T; C], Self> {
+        if self.len() < Self::CAPACITY {
+            return Err(self);
+        }
+        unsafe {
+            let a = ptr::read(self.as_ptr() as *const [T; C]);
+            self.set_len(0);
+            SM::init(self.as_mut_ptr(), Self::CAPACITY);
+            Ok(a)
+        }
+    }
+
+    /// Returns the inner array of the array-vector.
+    ///
+    /// Returns `Err(self)` if the array-vector is not full.
+    ///
+    /// This is an alias of [`into_array`], named after the similar method of the
+    /// [arrayvec] crate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_vec;
+    /// let v = array_vec![2; u64; 1, 2];
+    /// assert_eq!(v.into_inner(), Ok([1, 2]));
+    /// ```
+    ///
+    /// [`into_array`]: ArrayVec::into_array
+    /// [arrayvec]: https://docs.rs/arrayvec
+    #[inline]
+    pub fn into_inner(self) -> Result<[T; C], Self> {
+        self.into_array()
+    }
+
+    /// Creates an array-vector by moving the elements of another array-vector, possibly of
+    /// different capacity, length type or spare memory policy.
+    ///
+    /// Spare memory policy `SM2` of the source array-vector is applied to the memory of the moved
+    /// out elements.
+    ///
+    /// Note that this conversion cannot be expressed as a generic `TryFrom` implementation,
+    /// as it would conflict with the reflexive `TryFrom` implementation of the core library.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacityErrorVal`], holding the source array-vector untouched,
+    /// if `other` has more elements than `C`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arrayvec::ArrayVec, array_vec, len::{U8, Usize}, mem::Zeroed};
+    /// type A = ArrayVec<u64, 4, U8, Zeroed>;
+    /// let v = array_vec![16; u64; 1, 2, 3];
+    /// let a = A::try_from_array_vec(v).unwrap();
+    /// assert_eq!(a, [1, 2, 3]);
+    ///
+    /// let v = array_vec![16; u64; 1, 2, 3, 4, 5];
+    /// let e = A::try_from_array_vec(v).unwrap_err();
+    /// assert_eq!(e.0, [1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn try_from_array_vec<L2, SM2, const C2: usize>(
+        mut other: ArrayVec<T, C2, L2, SM2>,
+    ) -> Result<Self, InsufficientCapacityErrorVal<ArrayVec<T, C2, L2, SM2>>>
+    where
+        L2: LengthType,
+        SM2: SpareMemoryPolicy<T>,
+    {
+        let len = other.len();
+        if len > Self::CAPACITY {
+            return Err(InsufficientCapacityErrorVal(other));
+        }
+        let mut tmp = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), tmp.as_mut_ptr(), len);
+            tmp.set_len(len);
+            other.set_len(0);
+            SM2::init(other.as_mut_ptr(), len);
+        }
+        Ok(tmp)
+    }
+
+    /// Tries to extend the array-vector with the contents of an iterator.
+    ///
+    /// This is a non-panic version of [`extend`].
+    ///
+    /// Returns [`InsufficientCapacityError`] if the iterator yields more elements than the
+    /// spare capacity of the array-vector. In that case the array-vector is rolled back to its
+    /// original length, i.e. the elements appended by this call are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_vec, arrayvec::errors::InsufficientCapacityError};
+    /// let mut v = array_vec![5; u64; 1, 2];
+    /// assert!(v.try_extend(3..5).is_ok());
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    ///
+    /// assert!(matches!(v.try_extend(5..7), Err(e) if e == InsufficientCapacityError));
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    /// ```
+    ///
+    /// [`extend`]: ArrayVec::extend
+    #[inline]
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), InsufficientCapacityError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let len = self.len();
+        for e in iter {
+            if let Err(e) = self.try_push(e) {
+                self.truncate(len);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
     /// Inserts an element at position `index` within the vector, shifting all elements after it to
     /// the right.
     ///
@@ -1232,6 +1480,271 @@ where
         }
     }
 
+    /// Creates an iterator which uses a predicate to determine if an element in the specified
+    /// range should be removed.
+    ///
+    /// If the predicate returns `true`, the element is removed from the array-vector and yielded
+    /// by the iterator. If the predicate returns `false`, the element remains in the array-vector.
+    /// The predicate receives a mutable reference to every element, which allows modifying
+    /// the retained elements as well. Elements outside of `range` are never examined.
+    ///
+    /// When the iterator is dropped, the remaining elements are shifted to preserve
+    /// the array-vector's contiguity, and the freed slots are handled by the spare memory policy.
+    /// Elements in the range that were not examined, because the iterator was not fully consumed,
+    /// are retained. This holds even if the predicate panics.
+    ///
+    /// If the iterator is not dropped (with [`mem::forget`] for example), it is unspecified how
+    /// many elements are removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the array-vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_vec, arrayvec::ArrayVec};
+    /// let mut a = array_vec![8; 1, 2, 3, 4, 5, 6, 7, 8];
+    /// let evens = a.extract_if(.., |e| *e % 2 == 0).collect::<ArrayVec<_, 8>>();
+    /// assert_eq!(evens, [2, 4, 6, 8]);
+    /// assert_eq!(a, [1, 3, 5, 7]);
+    ///
+    /// let big = a.extract_if(1..3, |e| *e > 2).collect::<ArrayVec<_, 8>>();
+    /// assert_eq!(big, [3, 5]);
+    /// assert_eq!(a, [1, 7]);
+    /// ```
+    ///
+    /// [`mem::forget`]: core::mem::forget
+    #[inline]
+    pub fn extract_if<R, F>(&mut self, range: R, pred: F) -> ExtractIf<'_, T, L, SM, F, C>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut T) -> bool,
+    {
+        let old_len = self.len();
+
+        let end = match range.end_bound() {
+            Bound::Included(e) => e
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("end bound overflows")),
+            Bound::Excluded(e) => *e,
+            Bound::Unbounded => old_len,
+        };
+
+        if end > old_len {
+            panic!("invalid end bound");
+        }
+
+        let start = match range.start_bound() {
+            Bound::Included(s) => *s,
+            Bound::Excluded(s) => s
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("start bound overflows")),
+            Bound::Unbounded => 0,
+        };
+
+        if start > end {
+            panic!("invalid range");
+        }
+
+        // set `len` to reflect the head only, to avoid double-drop of extracted elements
+        // if the iterator is leaked. `len` is restored by `ExtractIf`.
+        unsafe { self.set_len(start) };
+
+        ExtractIf {
+            av: self,
+            idx: start,
+            end,
+            del: 0,
+            old_len,
+            pred,
+        }
+    }
+
+    /// Inserts an element into a sorted array-vector, keeping it sorted, and returns the index
+    /// the element was inserted at.
+    ///
+    /// The element is inserted after any equal elements, so that the order of insertion of equal
+    /// elements is preserved. The array-vector must be sorted in ascending order, otherwise the
+    /// insertion position is unspecified.
+    ///
+    /// This method uses binary search to find the insertion position, but still has a worst-case
+    /// performance of O(n), as elements may be shifted right.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there is no spare capacity.
+    ///
+    /// See [`try_insert_sorted_val`] for a method that returns the element back instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_vec;
+    /// let mut v = array_vec![5; 1, 3, 5];
+    /// assert_eq!(v.insert_sorted(4), 2);
+    /// assert_eq!(v.insert_sorted(0), 0);
+    /// assert_eq!(v, [0, 1, 3, 4, 5]);
+    /// ```
+    ///
+    /// [`try_insert_sorted_val`]: ArrayVec::try_insert_sorted_val
+    #[inline]
+    pub fn insert_sorted(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        self.try_insert_sorted_val(value)
+            .expect("insufficient capacity")
+    }
+
+    /// Tries to insert an element into a sorted array-vector, keeping it sorted, and returns the
+    /// index the element was inserted at.
+    ///
+    /// Returns [`InsufficientCapacityErrorVal`] with the element if there is no spare capacity.
+    ///
+    /// This is a non-panic version of [`insert_sorted`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_vec, arrayvec::errors::InsufficientCapacityErrorVal};
+    /// let mut v = array_vec![3; 1, 3];
+    /// assert!(matches!(v.try_insert_sorted_val(2), Ok(1)));
+    /// assert!(matches!(v.try_insert_sorted_val(0), Err(InsufficientCapacityErrorVal(0))));
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    ///
+    /// [`insert_sorted`]: ArrayVec::insert_sorted
+    #[inline]
+    pub fn try_insert_sorted_val(
+        &mut self,
+        value: T,
+    ) -> Result<usize, InsufficientCapacityErrorVal<T>>
+    where
+        T: Ord,
+    {
+        if self.is_full() {
+            return Err(InsufficientCapacityErrorVal(value));
+        }
+        let index = self.partition_point(|e| *e <= value);
+        unsafe { self.insert_unchecked(index, value) };
+        Ok(index)
+    }
+
+    /// Inserts an element into an array-vector sorted by a key extraction function, keeping it
+    /// sorted, and returns the index the element was inserted at.
+    ///
+    /// The element is inserted after any elements with an equal key. The array-vector must be
+    /// sorted by the key in ascending order, otherwise the insertion position is unspecified.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there is no spare capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_vec;
+    /// let mut v = array_vec![4; (u8, char); (1, 'a'), (3, 'b')];
+    /// assert_eq!(v.insert_sorted_by_key((1, 'c'), |e| e.0), 1);
+    /// assert_eq!(v.insert_sorted_by_key((5, 'd'), |e| e.0), 3);
+    /// assert_eq!(v, [(1, 'a'), (1, 'c'), (3, 'b'), (5, 'd')]);
+    /// ```
+    #[inline]
+    pub fn insert_sorted_by_key<K, F>(&mut self, value: T, mut f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        if self.is_full() {
+            panic!("insufficient capacity");
+        }
+        let key = f(&value);
+        let index = self.partition_point(|e| f(e) <= key);
+        unsafe { self.insert_unchecked(index, value) };
+        index
+    }
+
+    /// Removes an element equal to `value` from a sorted array-vector, and returns it.
+    ///
+    /// Returns `None` if there is no such element. If there are multiple equal elements, any one
+    /// of them may be removed. The array-vector must be sorted in ascending order, otherwise
+    /// the result is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_vec;
+    /// let mut v = array_vec![4; 1, 2, 3];
+    /// assert_eq!(v.remove_sorted(&2), Some(2));
+    /// assert_eq!(v.remove_sorted(&2), None);
+    /// assert_eq!(v, [1, 3]);
+    /// ```
+    #[inline]
+    pub fn remove_sorted(&mut self, value: &T) -> Option<T>
+    where
+        T: Ord,
+    {
+        let index = self.binary_search(value).ok()?;
+        Some(unsafe { self.remove_unchecked(index) })
+    }
+
+    /// Removes consecutive repeated elements from a sorted array-vector.
+    ///
+    /// If the array-vector is sorted, this removes all duplicates. The order of the remaining
+    /// elements is preserved, and the freed slots are handled by the spare memory policy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_vec;
+    /// let mut v = array_vec![8; 1, 1, 2, 3, 3, 3, 4];
+    /// v.dedup_sorted();
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn dedup_sorted(&mut self)
+    where
+        T: PartialEq,
+    {
+        let len = self.len();
+        if len <= 1 {
+            return;
+        }
+
+        // set `len` to zero, to avoid double-drop of deleted items.
+        // `len` is restored by RetainGuard.
+        unsafe { self.set_len(0) };
+
+        // the first element is always retained
+        let mut g = RetainGuard {
+            av: self,
+            len,
+            deleted: 0,
+            processed: 1,
+        };
+
+        unsafe {
+            let base_p = g.av.as_mut_ptr();
+            while g.processed < len {
+                let cur = base_p.add(g.processed);
+                let last = base_p.add(g.processed - g.deleted - 1);
+                if *cur == *last {
+                    // update counters before drop_in_place, as it may panic
+                    g.processed += 1;
+                    g.deleted += 1;
+                    ptr::drop_in_place(cur);
+                } else {
+                    if g.deleted > 0 {
+                        ptr::copy_nonoverlapping(cur, last.add(1), 1);
+                    }
+                    g.processed += 1;
+                }
+            }
+        }
+    }
+
     /// Returns the remaining spare capacity of the array-vector as a slice of `MaybeUninit<T>`.
     ///
     /// The returned slice can be used to fill the array-vector with data (e.g. by reading from a
@@ -1374,7 +1887,7 @@ where
     /// # use cds::{array_vec, arrayvec::errors::InsufficientCapacityError};
     /// # fn foo() -> Result<(), InsufficientCapacityError> {
     /// let mut a = array_vec![5;];
-    /// assert_eq!(a, []);
+    /// assert_eq!(a, [] as [i32; 0]);
     ///
     /// a.try_resize_with(3, Default::default)?;
     /// assert_eq!(a, [0, 0, 0]);
@@ -1447,7 +1960,7 @@ where
     /// ```rust
     /// # use cds::array_vec;
     /// let mut a = array_vec![5;];
-    /// assert_eq!(a, []);
+    /// assert_eq!(a, [] as [i32; 0]);
     ///
     /// a.resize(2, 1);
     /// assert_eq!(a, [1, 1]);
@@ -1522,6 +2035,62 @@ where
         Ok(())
     }
 
+    /// Tries to clone the array-vector.
+    ///
+    /// This is the counterpart of `SmallVec::try_clone`. As an array-vector never allocates,
+    /// cloning it can't fail, hence the error type is [`Infallible`]. Like [`clone`], this
+    /// method panics only if `T::clone` panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::array_vec;
+    /// let v = array_vec![4; String; "a".into(), "b".into()];
+    /// let w = v.try_clone().unwrap();
+    /// assert_eq!(v, w);
+    /// ```
+    ///
+    /// [`clone`]: Clone::clone
+    #[inline]
+    pub fn try_clone(&self) -> Result<Self, Infallible> {
+        Ok(self.clone())
+    }
+
+    /// Tries to extend the array-vector by cloning elements from a slice.
+    ///
+    /// Returns [`InsufficientCapacityError`] if there is no enough spare capacity to accommodate
+    /// the whole slice. In that case the array-vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{array_vec, arrayvec::errors::InsufficientCapacityError};
+    /// let mut v = array_vec![4; String];
+    /// assert!(v.try_extend_from_slice(&["a".into(), "b".into()]).is_ok());
+    /// assert_eq!(v, ["a", "b"]);
+    ///
+    /// let e = v.try_extend_from_slice(&["c".into(), "d".into(), "e".into()]);
+    /// assert!(matches!(e, Err(e) if e == InsufficientCapacityError));
+    /// assert_eq!(v, ["a", "b"]);
+    /// ```
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, s: &[T]) -> Result<(), InsufficientCapacityError> {
+        if s.len() > self.spare_capacity() {
+            return Err(InsufficientCapacityError {});
+        }
+        let mut p = unsafe { self.as_mut_ptr().add(self.len()) };
+        // Update `len` one-by-one, as `clone()` may panic and `self.drop()` may be implicitly
+        // invoked. This way we drop only successfully written slots.
+        for e in s {
+            unsafe {
+                p.write(e.clone());
+                p = p.add(1);
+            }
+            self.len += 1;
+        }
+        Ok(())
+    }
+
     #[inline]
     fn _clone_from(&mut self, other: &Self) {
         unsafe {
@@ -1645,6 +2214,233 @@ where
     }
 }
 
+impl<T, L, const C: usize> ArrayVec<T, C, L, Uninitialized>
+where
+    L: LengthType,
+{
+    /// Creates an empty `ArrayVec` in const context.
+    ///
+    /// This is a `const fn` counterpart of [`new`], intended for building constants and statics.
+    /// It is available with the [`Uninitialized`] spare memory policy only, as other policies
+    /// initialize the spare memory at runtime.
+    ///
+    /// Requested capacity `C` may not exceed the maximal value that can be stored in
+    /// length type `L`. This is verified at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arrayvec::ArrayVec, len::U8};
+    /// static A: ArrayVec<u16, 4, U8> = ArrayVec::new_const();
+    /// assert!(A.is_empty());
+    /// assert_eq!(A.capacity(), 4);
+    /// ```
+    ///
+    /// [`new`]: ArrayVec::new
+    #[inline]
+    pub const fn new_const() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_CHECK;
+        ArrayVec {
+            // it is safe to call `assume_init` to create an array of `MaybeUninit`
+            arr: unsafe { mem::MaybeUninit::uninit().assume_init() },
+            len: len::const_new(0),
+            phantom1: PhantomData,
+        }
+    }
+
+    /// Creates an `ArrayVec` from an array in const context.
+    ///
+    /// This is a `const fn` counterpart of [`from_array`], intended for building constants
+    /// and statics. It is available with the [`Uninitialized`] spare memory policy only, as other
+    /// policies initialize the spare memory at runtime.
+    ///
+    /// The array length `N` may not exceed the capacity `C`. This is verified at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arrayvec::ArrayVec, len::U8};
+    /// const A: ArrayVec<u16, 4, U8> = ArrayVec::from_array_const([1, 2, 3]);
+    /// assert_eq!(A, [1, 2, 3]);
+    /// assert_eq!(A.spare_capacity(), 1);
+    /// ```
+    ///
+    /// An array longer than the capacity fails to compile:
+    ///
+    /// ```compile_fail
+    /// # #[cfg(feature = "arrayvec")] {
+    /// # use cds::{arrayvec::ArrayVec, len::U8};
+    /// const A: ArrayVec<u16, 2, U8> = ArrayVec::from_array_const([1, 2, 3]);
+    /// # }
+    /// ```
+    ///
+    /// [`from_array`]: ArrayVec::from_array
+    #[inline]
+    pub const fn from_array_const<const N: usize>(a: [T; N]) -> Self {
+        const { assert!(N <= C, "array length exceeds ArrayVec capacity") };
+        let mut v = Self::new_const();
+        unsafe { ptr::copy_nonoverlapping(a.as_ptr(), v.arr.as_mut_ptr() as *mut T, N) };
+        mem::forget(a);
+        v.len = len::const_new(N);
+        v
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+impl<T, L, const C: usize> ArrayVec<T, C, L, Zeroed>
+where
+    T: bytemuck::Pod,
+    L: LengthType,
+{
+    /// Reinterprets a byte slice as an array-vector, without copying.
+    ///
+    /// `bytes` must hold an array-vector in the [layout] of `ArrayVec`, i.e. it must be exactly
+    /// `size_of::<Self>()` bytes long and aligned to `align_of::<Self>()`. The length is
+    /// validated to be within the capacity, and the spare memory after the length is validated
+    /// to be zeroed, as required by the [`Zeroed`] spare memory policy.
+    ///
+    /// This allows reading an array-vector from shared memory or a DMA buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arrayvec::ArrayVec, len::U32, mem::Zeroed};
+    /// type A = ArrayVec<u32, 3, U32, Zeroed>;
+    /// let mut buf = [0u32; 4];
+    /// buf[0] = 7;
+    /// buf[3] = 1; // the length
+    /// let a = A::from_bytes(bytemuck::bytes_of(&buf)).unwrap();
+    /// assert_eq!(a, &[7]);
+    ///
+    /// buf[3] = 4;
+    /// assert!(A::from_bytes(bytemuck::bytes_of(&buf)).is_err());
+    ///
+    /// buf[3] = 1;
+    /// buf[2] = 9; // <-- non-zero spare memory
+    /// assert!(A::from_bytes(bytemuck::bytes_of(&buf)).is_err());
+    /// ```
+    ///
+    /// [layout]: ArrayVec#layout
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, FromBytesError> {
+        Self::check_bytes(bytes)?;
+        // SAFETY: the size and alignment are verified, `T` is `Pod`, the length is valid,
+        // the first `len` elements are initialized, as all bytes are, and the spare memory
+        // is zeroed
+        Ok(unsafe { &*bytes.as_ptr().cast::<Self>() })
+    }
+
+    /// Reinterprets a mutable byte slice as an array-vector, without copying.
+    ///
+    /// This is similar to [`from_bytes`], but allows modifying an array-vector in place, e.g.
+    /// in shared memory. Since writing a whole array-vector may leave its padding bytes
+    /// uninitialized, this method fails to compile if `Self` has any padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{arrayvec::ArrayVec, len::U32, mem::Zeroed};
+    /// type A = ArrayVec<u32, 3, U32, Zeroed>;
+    /// let mut buf = [0u32; 4];
+    /// let a = A::from_bytes_mut(bytemuck::bytes_of_mut(&mut buf)).unwrap();
+    /// a.push(7);
+    /// a.push(8);
+    /// assert_eq!(buf, [7, 8, 0, 2]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use cds::{arrayvec::ArrayVec, len::U8, mem::Zeroed};
+    /// type A = ArrayVec<u32, 3, U8, Zeroed>; // <-- 3 padding bytes after the length
+    /// let mut buf = [0u32; 4];
+    /// let a = A::from_bytes_mut(bytemuck::bytes_of_mut(&mut buf));
+    /// ```
+    ///
+    /// [`from_bytes`]: ArrayVec::from_bytes
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, FromBytesError> {
+        const {
+            assert!(
+                mem::size_of::<Self>() == C * mem::size_of::<T>() + mem::size_of::<L>(),
+                "ArrayVec has padding bytes"
+            )
+        };
+        Self::check_bytes(bytes)?;
+        // SAFETY: see `from_bytes`; in addition, `Self` has no padding bytes, hence writes
+        // through the returned reference can't leave `bytes` uninitialized
+        Ok(unsafe { &mut *bytes.as_mut_ptr().cast::<Self>() })
+    }
+
+    fn check_bytes(bytes: &[u8]) -> Result<(), FromBytesError> {
+        if bytes.len() != mem::size_of::<Self>() {
+            return Err(FromBytesError::InvalidSize);
+        }
+        if bytes.as_ptr().align_offset(mem::align_of::<Self>()) != 0 {
+            return Err(FromBytesError::Misaligned);
+        }
+        let o = mem::offset_of!(Self, len);
+        let len = match len::len_from_raw_bytes::<L>(&bytes[o..o + mem::size_of::<L>()]) {
+            Some(len) if len <= C => len,
+            _ => return Err(FromBytesError::InvalidLength),
+        };
+        let spare = &bytes[len * mem::size_of::<T>()..C * mem::size_of::<T>()];
+        if spare.iter().any(|&b| b != 0) {
+            return Err(FromBytesError::NonZeroSpareMemory);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+impl<T, L, SM, const C: usize> ArrayVec<T, C, L, SM>
+where
+    T: bytemuck::NoUninit,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    /// Feeds the bytes of the elements into the given [`Hasher`] in a single call.
+    ///
+    /// This hashes the length as `usize`, followed by the elements as one byte slice. Unlike
+    /// [`Hash`], which hashes a slice of compound elements element by element and field by
+    /// field, this issues a single [`write`] of the whole content, which is considerably faster
+    /// with word-oriented hashers like *FxHash*.
+    ///
+    /// For primitive integer elements the result is the same as that of [`Hash`]. In general,
+    /// however, it differs, and two array-vectors hash equally only if their elements are equal
+    /// byte-wise. Hence, don't mix `hash_bytes` with [`Hash`] for the same keys, and avoid it for
+    /// elements whose equality is not byte-wise, e.g. floating point numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// # use cds::{arrayvec::ArrayVec, len::U8};
+    /// use std::hash::{BuildHasher, Hasher, RandomState};
+    ///
+    /// let s = RandomState::new();
+    /// let a = ArrayVec::<[u16; 2], 4, U8>::try_from([[1, 2], [3, 4]]).unwrap();
+    /// let b = ArrayVec::<[u16; 2], 8, U8>::try_from([[1, 2], [3, 4]]).unwrap();
+    ///
+    /// let mut h1 = s.build_hasher();
+    /// a.hash_bytes(&mut h1);
+    /// let mut h2 = s.build_hasher();
+    /// b.hash_bytes(&mut h2);
+    /// assert_eq!(h1.finish(), h2.finish());
+    /// # }
+    /// ```
+    ///
+    /// [`Hasher`]: core::hash::Hasher
+    /// [`Hash`]: core::hash::Hash
+    /// [`write`]: core::hash::Hasher::write
+    #[inline]
+    pub fn hash_bytes<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        state.write(bytemuck::cast_slice(self.as_slice()));
+    }
+}
+
 mod macros;
 mod traits;
 
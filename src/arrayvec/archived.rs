@@ -0,0 +1,309 @@
+use crate::{
+    arrayvec::{errors::CheckArchivedError, ArrayVec},
+    len::ArchiveLength,
+    mem::SpareMemoryPolicy,
+};
+use core::{
+    fmt::{Debug, Formatter},
+    mem::MaybeUninit,
+    ops::Deref,
+    ptr, slice,
+};
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{fail, Fallible, Source},
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+/// An archived [`ArrayVec`].
+///
+/// `ArchivedArrayVec` has a fixed, inline layout: the archived length, followed by an array of
+/// `C` archived elements. Hence, all archived records of the same `ArrayVec` type have the same
+/// size, and may be accessed in place.
+///
+/// The length is archived as described in [`ArchiveLength`]. Spare elements are zeroed.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use cds::{arrayvec::{ArrayVec, ArchivedArrayVec}, len::U8};
+/// use rkyv::rancor::Error;
+///
+/// let v = ArrayVec::<u16, 4, U8>::try_from([1, 2, 3]).unwrap();
+/// let bytes = rkyv::to_bytes::<Error>(&v).unwrap();
+/// assert_eq!(bytes.len(), 10); // 1 length byte + padding byte + 4 elements
+///
+/// let a = rkyv::access::<ArchivedArrayVec<rkyv::Archived<u16>, 4, U8>, Error>(&bytes).unwrap();
+/// assert_eq!(a.len(), 3);
+/// assert_eq!(a[1], 2);
+///
+/// let d: ArrayVec<u16, 4, U8> = rkyv::deserialize::<_, Error>(a).unwrap();
+/// assert_eq!(d, v);
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+#[repr(C)]
+pub struct ArchivedArrayVec<T, const C: usize, L: ArchiveLength> {
+    len: L::Archived,
+    arr: [MaybeUninit<T>; C],
+}
+
+// SAFETY: `ArchivedArrayVec` is `repr(C)`, and consists of portable fields only
+unsafe impl<T: Portable, const C: usize, L: ArchiveLength> Portable for ArchivedArrayVec<T, C, L> {}
+
+impl<T, const C: usize, L: ArchiveLength> ArchivedArrayVec<T, C, L> {
+    /// Returns the number of elements in the archived array-vector.
+    #[inline]
+    pub fn len(&self) -> usize {
+        L::unarchive_len(&self.len)
+    }
+
+    /// Returns `true` if the archived array-vector contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the capacity of the archived array-vector.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        C
+    }
+
+    /// Extracts a slice of the archived elements.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` elements are initialized, and `len <= C` is verified on access
+        unsafe { slice::from_raw_parts(self.arr.as_ptr().cast(), self.len()) }
+    }
+}
+
+impl<T, const C: usize, L: ArchiveLength> Deref for ArchivedArrayVec<T, C, L> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Debug, const C: usize, L: ArchiveLength> Debug for ArchivedArrayVec<T, C, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T, U, const C: usize, L, const N: usize> PartialEq<[U; N]> for ArchivedArrayVec<T, C, L>
+where
+    T: PartialEq<U>,
+    L: ArchiveLength,
+{
+    #[inline]
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, U, const C: usize, L> PartialEq<[U]> for ArchivedArrayVec<T, C, L>
+where
+    T: PartialEq<U>,
+    L: ArchiveLength,
+{
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, const C: usize, L, SM> Archive for ArrayVec<T, C, L, SM>
+where
+    T: Archive,
+    L: ArchiveLength,
+    SM: SpareMemoryPolicy<T>,
+{
+    type Archived = ArchivedArrayVec<T::Archived, C, L>;
+    type Resolver = ArrayVec<T::Resolver, C>;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: the fields are projected from a valid place of the archived type
+        let (len, arr) = unsafe {
+            let p = out.ptr();
+            (
+                Place::from_field_unchecked(out, ptr::addr_of_mut!((*p).len)),
+                Place::from_field_unchecked(out, ptr::addr_of_mut!((*p).arr)),
+            )
+        };
+        len.write(L::archive_len(self.len()));
+        for (i, (e, r)) in self.iter().zip(resolver).enumerate() {
+            // SAFETY: `i < self.len() <= C`, and `MaybeUninit<T::Archived>` has the same layout
+            // as `T::Archived`
+            let out_i = unsafe { arr.index(i).cast_unchecked::<T::Archived>() };
+            e.resolve(r, out_i);
+        }
+    }
+}
+
+impl<T, S, const C: usize, L, SM> Serialize<S> for ArrayVec<T, C, L, SM>
+where
+    T: Serialize<S>,
+    S: Fallible + ?Sized,
+    L: ArchiveLength,
+    SM: SpareMemoryPolicy<T>,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let mut resolver = ArrayVec::new();
+        for e in self.iter() {
+            let r = e.serialize(serializer)?;
+            // SAFETY: `self.len() <= C`
+            unsafe { resolver.push_unchecked(r) };
+        }
+        Ok(resolver)
+    }
+}
+
+impl<T, D, const C: usize, L, SM> Deserialize<ArrayVec<T, C, L, SM>, D>
+    for ArchivedArrayVec<T::Archived, C, L>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+    L: ArchiveLength,
+    SM: SpareMemoryPolicy<T>,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<ArrayVec<T, C, L, SM>, D::Error> {
+        let mut tmp = ArrayVec::new();
+        for e in self.iter() {
+            let e = e.deserialize(deserializer)?;
+            // SAFETY: `self.len() <= C`
+            unsafe { tmp.push_unchecked(e) };
+        }
+        Ok(tmp)
+    }
+}
+
+// SAFETY: `check_bytes` verifies the length, and every element within the length
+unsafe impl<T, const C: usize, L, Ctx> CheckBytes<Ctx> for ArchivedArrayVec<T, C, L>
+where
+    T: CheckBytes<Ctx>,
+    L: ArchiveLength,
+    L::Archived: CheckBytes<Ctx>,
+    Ctx: Fallible + ?Sized,
+    Ctx::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut Ctx) -> Result<(), Ctx::Error> {
+        let len = ptr::addr_of!((*value).len);
+        L::Archived::check_bytes(len, context)?;
+        let len = L::unarchive_len(&*len);
+        if len > C {
+            fail!(CheckArchivedError);
+        }
+        let arr = ptr::addr_of!((*value).arr).cast::<T>();
+        for i in 0..len {
+            T::check_bytes(arr.add(i), context)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod testing {
+    use crate as cds;
+    use cds::{
+        arrayvec::{ArchivedArrayVec, ArrayVec},
+        len::{U16, U24, U8},
+        mem::Pattern,
+    };
+    use rkyv::{rancor::Error, Archived};
+
+    type A = ArrayVec<u32, 5, U8>;
+    type AA = ArchivedArrayVec<Archived<u32>, 5, U8>;
+
+    #[test]
+    fn test_round_trip() {
+        let v = A::try_from([1, 2, 3]).unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&v).unwrap();
+        let a = rkyv::access::<AA, Error>(&bytes).unwrap();
+        assert_eq!(a.len(), 3);
+        assert!(!a.is_empty());
+        assert_eq!(a.capacity(), 5);
+        assert_eq!(*a, [1, 2, 3]);
+        let d: A = rkyv::deserialize::<_, Error>(a).unwrap();
+        assert_eq!(d, v);
+    }
+
+    #[test]
+    fn test_constant_size() {
+        let e = rkyv::to_bytes::<Error>(&A::new()).unwrap();
+        let f = rkyv::to_bytes::<Error>(&A::try_from([1, 2, 3, 4, 5]).unwrap()).unwrap();
+        assert_eq!(e.len(), f.len());
+        assert_eq!(e.len(), core::mem::size_of::<AA>());
+        assert_eq!(core::mem::size_of::<AA>(), 24);
+
+        let a = rkyv::access::<AA, Error>(&e).unwrap();
+        assert!(a.is_empty());
+        assert_eq!(format!("{a:?}"), "[]");
+    }
+
+    #[test]
+    fn test_packed_len() {
+        type B = ArrayVec<u8, 5, U24>;
+        type AB = ArchivedArrayVec<u8, 5, U24>;
+        assert_eq!(core::mem::size_of::<AB>(), 8);
+        let v = B::try_from([7, 8]).unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&v).unwrap();
+        assert_eq!(&bytes[..], &[2, 0, 0, 7, 8, 0, 0, 0]);
+        let a = rkyv::access::<AB, Error>(&bytes).unwrap();
+        assert_eq!(a, &[7u8, 8][..]);
+    }
+
+    #[test]
+    fn test_spare_memory_policy() {
+        type P = ArrayVec<u8, 4, U8, Pattern<0xAB>>;
+        let v = P::try_from([1]).unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&v).unwrap();
+        assert_eq!(&bytes[..], &[1, 1, 0, 0, 0]);
+        let a = rkyv::access::<ArchivedArrayVec<u8, 4, U8>, Error>(&bytes).unwrap();
+        let d: P = rkyv::deserialize::<_, Error>(a).unwrap();
+        assert_eq!(d, [1]);
+    }
+
+    #[test]
+    fn test_nested() {
+        type I = ArrayVec<u16, 2, U8>;
+        type O = ArrayVec<I, 3, U16>;
+        let mut v = O::new();
+        v.push(I::try_from([1, 2]).unwrap());
+        v.push(I::new());
+        let bytes = rkyv::to_bytes::<Error>(&v).unwrap();
+        let a = rkyv::access::<
+            ArchivedArrayVec<ArchivedArrayVec<Archived<u16>, 2, U8>, 3, U16>,
+            Error,
+        >(&bytes)
+        .unwrap();
+        assert_eq!(a.len(), 2);
+        assert_eq!(a[0], [1, 2]);
+        assert!(a[1].is_empty());
+        let d: O = rkyv::deserialize::<_, Error>(a).unwrap();
+        assert_eq!(d, v);
+    }
+
+    #[test]
+    fn test_check_bytes_len_exceeds_capacity() {
+        let bytes = rkyv::to_bytes::<Error>(&ArrayVec::<u8, 4, U8>::new()).unwrap();
+        let mut bytes = bytes.to_vec();
+        bytes[0] = 5;
+        let e = rkyv::access::<ArchivedArrayVec<u8, 4, U8>, Error>(&bytes).unwrap_err();
+        assert!(format!("{e}").contains("arrayvec archived length exceeds capacity"));
+    }
+
+    #[test]
+    fn test_check_bytes_invalid_element() {
+        let v = ArrayVec::<bool, 2, U8>::try_from([true, false]).unwrap();
+        let mut bytes = rkyv::to_bytes::<Error>(&v).unwrap().to_vec();
+        bytes[2] = 2;
+        assert!(rkyv::access::<ArchivedArrayVec<bool, 2, U8>, Error>(&bytes).is_err());
+        bytes[0] = 1;
+        assert!(rkyv::access::<ArchivedArrayVec<bool, 2, U8>, Error>(&bytes).is_ok());
+    }
+}
@@ -13,6 +13,7 @@ use core::{
 
 /// An error returned when there is no enough spare capacity.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct InsufficientCapacityError;
 
 impl Display for InsufficientCapacityError {
@@ -47,6 +48,17 @@ impl<T> Debug for InsufficientCapacityErrorVal<T> {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for InsufficientCapacityErrorVal<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "arrayvec::InsufficientCapacityErrorVal<{=str}>",
+            type_name::<T>()
+        )
+    }
+}
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl<T> std::error::Error for InsufficientCapacityErrorVal<T> {}
@@ -57,6 +69,7 @@ impl<T> std::error::Error for InsufficientCapacityErrorVal<T> {}
 ///
 /// [`try_insert`]: super::ArrayVec::try_insert
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum InsertError {
     /// Requested index is out of bounds
     InvalidIndex,
@@ -135,12 +148,89 @@ impl<T> Debug for InsertErrorVal<T> {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for InsertErrorVal<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let name = type_name::<T>();
+        match self {
+            Self::InvalidIndex(_) => defmt::write!(f, "InsertErrorVal<{=str}>::InvalidIndex", name),
+            Self::InsufficientCapacity(_) => {
+                defmt::write!(f, "InsertErrorVal<{=str}>::InsufficientCapacity", name)
+            }
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl<T> std::error::Error for InsertErrorVal<T> {}
 
 // ----------------------------------------------------------------------------
 
+/// An error returned when validation of an [`ArchivedArrayVec`] fails.
+///
+/// This error is returned when the archived length exceeds the capacity.
+///
+/// [`ArchivedArrayVec`]: super::ArchivedArrayVec
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CheckArchivedError;
+
+#[cfg(feature = "rkyv")]
+impl Display for CheckArchivedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "arrayvec archived length exceeds capacity")
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl core::error::Error for CheckArchivedError {}
+
+// ----------------------------------------------------------------------------
+
+/// An error returned from [`from_bytes`] and [`from_bytes_mut`] methods.
+///
+/// [`from_bytes`]: super::ArrayVec::from_bytes
+/// [`from_bytes_mut`]: super::ArrayVec::from_bytes_mut
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FromBytesError {
+    /// The byte slice size is different from the size of the array-vector.
+    InvalidSize,
+
+    /// The byte slice is not aligned for the array-vector.
+    Misaligned,
+
+    /// The length is invalid or exceeds the capacity.
+    InvalidLength,
+
+    /// The spare memory after the length is not zeroed.
+    NonZeroSpareMemory,
+}
+
+#[cfg(feature = "bytemuck")]
+impl Display for FromBytesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let s = match *self {
+            FromBytesError::InvalidSize => "invalid size",
+            FromBytesError::Misaligned => "misaligned",
+            FromBytesError::InvalidLength => "invalid length",
+            FromBytesError::NonZeroSpareMemory => "non-zero spare memory",
+        };
+        write!(f, "arrayvec from bytes error: {s}")
+    }
+}
+
+#[cfg(all(feature = "std", feature = "bytemuck"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for FromBytesError {}
+
+// ----------------------------------------------------------------------------
+
 #[cfg(all(test, feature = "std"))]
 mod testing {
     use super::*;
@@ -270,4 +360,32 @@ mod testing {
         let v = e.into_value();
         assert_eq!(v, "Hello again!");
     }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_check_archived_error_display() {
+        let e = CheckArchivedError;
+        let s = format!("{e}");
+        assert_eq!(s, "arrayvec archived length exceeds capacity");
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_from_bytes_error_display() {
+        let e = FromBytesError::InvalidSize;
+        let s = format!("{e}");
+        assert_eq!(s, "arrayvec from bytes error: invalid size");
+
+        let e = FromBytesError::Misaligned;
+        let s = format!("{e}");
+        assert_eq!(s, "arrayvec from bytes error: misaligned");
+
+        let e = FromBytesError::InvalidLength;
+        let s = format!("{e}");
+        assert_eq!(s, "arrayvec from bytes error: invalid length");
+
+        let e = FromBytesError::NonZeroSpareMemory;
+        let s = format!("{e}");
+        assert_eq!(s, "arrayvec from bytes error: non-zero spare memory");
+    }
 }
@@ -0,0 +1,114 @@
+use crate::{arrayvec::ArrayVec, len::LengthType, mem::SpareMemoryPolicy};
+use core::{
+    fmt::{Debug, Formatter},
+    iter::{FusedIterator, Iterator},
+    ops::Drop,
+    ptr,
+};
+
+/// An iterator which uses a predicate to determine if an element should be removed from
+/// an [`ArrayVec`].
+///
+/// See [`ArrayVec::extract_if`] for more information.
+pub struct ExtractIf<'a, T, L, SM, F, const C: usize>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    F: FnMut(&mut T) -> bool,
+{
+    // the owner ArrayVec; its `len` is set to the start of the range while iterating
+    pub(super) av: &'a mut ArrayVec<T, C, L, SM>,
+    // the index of the next element to be examined
+    pub(super) idx: usize,
+    // the index of the first element past the range
+    pub(super) end: usize,
+    // the number of elements extracted so far
+    pub(super) del: usize,
+    // the original length of the array-vector
+    pub(super) old_len: usize,
+    // the predicate
+    pub(super) pred: F,
+}
+
+impl<T, L, SM, F, const C: usize> Debug for ExtractIf<'_, T, L, SM, F, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    F: FnMut(&mut T) -> bool,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "arrayvec::ExtractIf{{idx: {}, end: {}, del: {}, old_len: {}}}",
+            self.idx, self.end, self.del, self.old_len
+        )
+    }
+}
+
+impl<T, L, SM, F, const C: usize> Iterator for ExtractIf<'_, T, L, SM, F, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            let base_p = self.av.as_mut_ptr();
+            while self.idx < self.end {
+                let p = base_p.add(self.idx);
+                // if the predicate panics, the element is left in place, and `Drop` restores
+                // the array-vector's contiguity
+                if (self.pred)(&mut *p) {
+                    self.idx += 1;
+                    self.del += 1;
+                    return Some(ptr::read(p));
+                }
+                if self.del > 0 {
+                    ptr::copy_nonoverlapping(p, p.sub(self.del), 1);
+                }
+                self.idx += 1;
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.idx))
+    }
+}
+
+impl<T, L, SM, F, const C: usize> FusedIterator for ExtractIf<'_, T, L, SM, F, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    F: FnMut(&mut T) -> bool,
+{
+}
+
+impl<T, L, SM, F, const C: usize> Drop for ExtractIf<'_, T, L, SM, F, C>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        // Move the unprocessed elements to preserve array-vector's contiguity.
+        // Set the correct length, and invoke spare memory policy if needed.
+        unsafe {
+            let new_len = self.old_len - self.del;
+            if self.del > 0 {
+                let base_p = self.av.as_mut_ptr();
+                ptr::copy(
+                    base_p.add(self.idx),
+                    base_p.add(self.idx - self.del),
+                    self.old_len - self.idx,
+                );
+                SM::init(base_p.add(new_len), self.del);
+            }
+            self.av.set_len(new_len);
+        }
+    }
+}
@@ -2,11 +2,13 @@ use crate as cds;
 use cds::{
     array_vec,
     arrayvec::{
-        errors::{InsertError, InsertErrorVal, InsufficientCapacityError},
+        errors::{
+            InsertError, InsertErrorVal, InsufficientCapacityError, InsufficientCapacityErrorVal,
+        },
         ArrayVec, Drain,
     },
-    len::{LengthType, U8},
-    mem::{Pattern, SpareMemoryPolicy, Uninitialized},
+    len::{LengthType, U24, U32, U8, U8NZ},
+    mem::{Pattern, SpareMemoryPolicy, Uninitialized, Zeroed},
     testing::dropped::{Dropped, Track},
 };
 use core::{
@@ -65,6 +67,173 @@ fn test_zst_size() {
     assert_eq!(mem::size_of_val(&a), mem::size_of::<usize>());
 }
 
+#[test]
+fn test_nz_size() {
+    type A = ArrayVec<u8, 7, U8NZ>;
+    assert_eq!(mem::size_of::<A>(), 8);
+    assert_eq!(mem::size_of::<Option<A>>(), 8);
+}
+
+#[test]
+fn test_packed_size() {
+    assert_eq!(mem::size_of::<ArrayVec<u8, 1000, U32>>(), 1004);
+    assert_eq!(mem::size_of::<ArrayVec<u8, 1000, U24>>(), 1003);
+
+    // alignment of `U24` doesn't force padding of the enclosing struct
+    assert_eq!(mem::align_of::<ArrayVec<u8, 300, U24>>(), 1);
+    assert_eq!(mem::size_of::<[ArrayVec<u8, 300, U24>; 4]>(), 1212);
+    assert_eq!(mem::size_of::<[ArrayVec<u8, 300, U32>; 4]>(), 1216);
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn test_packed_size_64() {
+    use cds::len::{U48, U64};
+    assert_eq!(mem::size_of::<ArrayVec<u8, 10, U64>>(), 24);
+    assert_eq!(mem::size_of::<ArrayVec<u8, 10, U48>>(), 16);
+}
+
+#[test]
+fn test_packed_push_pop() {
+    type A = ArrayVec<u16, 300, U24>;
+    let mut a = A::new();
+    while a.has_spare_capacity() {
+        a.push(a.len() as u16);
+    }
+    assert_eq!(a.len(), 300);
+    assert!(a.try_push(0).is_err());
+    for i in (0..300).rev() {
+        assert_eq!(a.pop(), Some(i as u16));
+    }
+    assert!(a.is_empty());
+}
+
+#[test]
+fn test_nz_push_pop() {
+    type A = ArrayVec<u8, 254, U8NZ>;
+    let mut a = A::new();
+    while a.has_spare_capacity() {
+        a.push(a.len() as u8);
+    }
+    assert_eq!(a.len(), 254);
+    assert!(a.try_push(0).is_err());
+    for i in (0..254).rev() {
+        assert_eq!(a.pop(), Some(i as u8));
+    }
+    assert!(a.is_empty());
+}
+
+#[test]
+fn test_const_new() {
+    const A: ArrayVec<u16, 4, U8NZ> = ArrayVec::new_const();
+    assert!(A.is_empty());
+    assert_eq!(A.capacity(), 4);
+    assert_eq!(A.spare_capacity(), 4);
+    assert_eq!(A.len(), 0);
+    assert!(A.as_slice().is_empty());
+}
+
+#[test]
+fn test_const_from_array() {
+    const A: ArrayVec<u16, 4, U24> = ArrayVec::from_array_const([1, 2, 3]);
+    assert_eq!(A, [1, 2, 3]);
+    assert!(A.has_spare_capacity());
+    assert!(!A.is_full());
+
+    static B: ArrayVec<u16, 2, U8NZ> = ArrayVec::from_array_const([5, 6]);
+    const LEN: usize = B.len();
+    const LAST: u16 = B.as_slice()[1];
+    assert_eq!(LEN, 2);
+    assert_eq!(LAST, 6);
+    assert!(B.is_full());
+    assert_eq!(B.as_slice(), &[5, 6]);
+}
+
+#[test]
+fn test_from_array_dropped() {
+    let t = Track::<2>::new();
+    let a = ArrayVec::<_, 3, U8>::from_array([t.alloc(), t.alloc()]);
+    assert_eq!(t.n_allocated(), 2);
+    drop(a);
+    assert_eq!(t.n_allocated(), 0);
+}
+
+#[test]
+fn test_from_array_spare_memory() {
+    let t = Track::<2>::new();
+    let a = ArrayVec::<_, 4, U8, Pattern<0xAB>>::from_array([t.alloc(), t.alloc()]);
+    assert_eq!(a.len(), 2);
+    assert!(a.iter().enumerate().all(|(i, d)| d.idx() == i));
+    check_spare_memory(&a, 0xAB);
+    drop(a);
+    assert_eq!(t.n_allocated(), 0);
+
+    let a = ArrayVec::<u64, 3, U8NZ, Zeroed>::from_array([1, 2, 3]);
+    assert_eq!(a, [1, 2, 3]);
+    assert!(a.is_full());
+}
+
+#[test]
+fn test_into_array() {
+    let t = Track::<6>::new();
+    let mut a = ArrayVec::<_, 3, U8, Pattern<0xAB>>::new();
+    a.push(t.alloc());
+    a.push(t.alloc());
+    let mut a = a.into_array().err().unwrap();
+    assert_eq!(a.len(), 2);
+    a.push(t.alloc());
+    let arr = a.into_inner().ok().unwrap();
+    assert_eq!(t.n_allocated(), 3);
+    assert_eq!(arr[2].idx(), 2);
+    drop(arr);
+    assert_eq!(t.n_allocated(), 0);
+    assert!(t.dropped_range(0..3));
+
+    let a = ArrayVec::<(), 2, U8NZ>::from_array([(), ()]);
+    assert_eq!(a.into_array(), Ok([(), ()]));
+}
+
+#[test]
+fn test_try_from_array_vec() {
+    let t = Track::<8>::new();
+    let mut a = ArrayVec::<_, 8, U32, Pattern<0xAB>>::new();
+    for _ in 0..5 {
+        a.push(t.alloc());
+    }
+
+    let a = ArrayVec::<_, 4, U8NZ>::try_from_array_vec(a)
+        .err()
+        .unwrap()
+        .0;
+    assert_eq!(a.len(), 5);
+    assert_eq!(t.n_allocated(), 5);
+
+    let b = ArrayVec::<_, 5, U24, Pattern<0xCD>>::try_from_array_vec(a)
+        .ok()
+        .unwrap();
+    assert_eq!(b.len(), 5);
+    assert!(b.iter().enumerate().all(|(i, d)| d.idx() == i));
+    check_spare_memory(&b, 0xCD);
+    assert_eq!(t.n_allocated(), 5);
+
+    drop(b);
+    assert_eq!(t.n_allocated(), 0);
+    assert!(t.dropped_range(0..5));
+}
+
+#[test]
+fn test_nz_capacity_at_max() {
+    // `CAPACITY_CHECK` accepts capacity up to `L::MAX`; see `ArrayVec::new` for the
+    // `compile_fail` example of `ArrayVec<u8, 255, U8NZ>`
+    type A = ArrayVec<u8, 254, U8NZ>;
+    #[allow(clippy::let_unit_value)]
+    let () = A::CAPACITY_CHECK;
+    const A0: A = A::from_array_const([1, 2, 3]);
+    assert_eq!(A0, [1, 2, 3]);
+    assert_eq!(A0.capacity(), 254);
+    assert_eq!(A::new().capacity(), 254);
+}
+
 #[test]
 fn test_zst_push_pop() {
     let mut a = array_vec![3; ()];
@@ -142,7 +311,7 @@ fn test_push_unchecked() {
     type A = ArrayVec<u16, 5, U8, Pattern<0xBC>>;
     let mut a = A::new();
 
-    assert_eq!(a, []);
+    assert_eq!(a, [] as [u16; 0]);
     for i in 0..a.capacity() {
         assert_eq!(unsafe { a.as_ptr().add(i).read() }, 0xBCBC);
     }
@@ -1000,7 +1169,7 @@ fn test_resize() {
     assert_eq!(av, [1]);
 
     av.resize(0, 2);
-    assert_eq!(av, []);
+    assert_eq!(av, [] as [i32; 0]);
 }
 
 #[test]
@@ -1023,7 +1192,7 @@ fn test_try_resize() {
     assert_eq!(av, [1, 2, 7, 7]);
 
     av.try_resize(0, 0).unwrap();
-    assert_eq!(av, []);
+    assert_eq!(av, [] as [i32; 0]);
 
     assert!(matches!(av.try_resize(10, 0), Err(e) if e == InsufficientCapacityError));
 }
@@ -1070,7 +1239,7 @@ fn test_try_resize_with_dropped() {
 #[test]
 fn test_copy_from_slice() {
     let mut av = array_vec![5;];
-    assert_eq!(av, []);
+    assert_eq!(av, [] as [i32; 0]);
     av.copy_from_slice(&[1, 2]);
     assert_eq!(av, [1, 2]);
     av.copy_from_slice(&[3, 4]);
@@ -1090,7 +1259,7 @@ fn test_copy_from_slice_panics() {
 #[test]
 fn test_try_copy_from_slice() {
     let mut av = array_vec![5;];
-    assert_eq!(av, []);
+    assert_eq!(av, [] as [i32; 0]);
     av.try_copy_from_slice(&[1, 2]).unwrap();
     assert_eq!(av, [1, 2]);
     av.try_copy_from_slice(&[3, 4]).unwrap();
@@ -1124,3 +1293,348 @@ fn test_arrayvec_drain_covariance() {
         d
     }
 }
+
+#[test]
+fn test_try_extend() {
+    let t = Track::<8>::new();
+    let mut a = ArrayVec::<_, 5, U8, Pattern<0xAB>>::new();
+    a.try_extend(t.take(2)).unwrap();
+    assert_eq!(a.len(), 2);
+
+    assert_eq!(a.try_extend(t.take(4)), Err(InsufficientCapacityError));
+    assert_eq!(a.len(), 2);
+    check_spare_memory(&a, 0xAB);
+    assert_eq!(t.n_allocated(), 2);
+    assert!(t.dropped_range(2..6));
+
+    a.try_extend(t.take(2)).unwrap();
+    assert_eq!(a.len(), 4);
+    drop(a);
+    assert_eq!(t.n_allocated(), 0);
+}
+
+#[test]
+fn test_try_extend_from_slice() {
+    let mut a = ArrayVec::<u64, 5, U8, Pattern<0xAB>>::new();
+    a.try_extend_from_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(a, [1, 2, 3]);
+    assert_eq!(
+        a.try_extend_from_slice(&[4, 5, 6]),
+        Err(InsufficientCapacityError)
+    );
+    assert_eq!(a, [1, 2, 3]);
+    check_spare_memory(&a, 0xAB);
+    a.try_extend_from_slice(&[4, 5]).unwrap();
+    assert_eq!(a, [1, 2, 3, 4, 5]);
+    a.try_extend_from_slice(&[]).unwrap();
+}
+
+#[test]
+fn test_try_clone() {
+    let t = Track::<8>::new();
+    let mut a = ArrayVec::<_, 5, U8, Pattern<0xAB>>::new();
+    a.extend(t.take(3));
+    let b = a.try_clone().unwrap();
+    assert_eq!(b.len(), 3);
+    check_spare_memory(&b, 0xAB);
+    assert_eq!(t.n_allocated(), 6);
+    drop(a);
+    drop(b);
+    assert_eq!(t.n_allocated(), 0);
+}
+
+#[test]
+fn test_insert_sorted() {
+    let mut a = ArrayVec::<u8, 6, U8, Pattern<0xBA>>::new();
+    assert_eq!(a.insert_sorted(3), 0);
+    assert_eq!(a.insert_sorted(1), 0);
+    assert_eq!(a.insert_sorted(3), 2);
+    assert_eq!(a.insert_sorted(5), 3);
+    assert_eq!(a.insert_sorted(2), 1);
+    assert_eq!(a, [1, 2, 3, 3, 5]);
+    assert!(matches!(a.try_insert_sorted_val(4), Ok(4)));
+    assert_eq!(a, [1, 2, 3, 3, 4, 5]);
+    assert!(matches!(
+        a.try_insert_sorted_val(0),
+        Err(InsufficientCapacityErrorVal(0))
+    ));
+    assert_eq!(a, [1, 2, 3, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic]
+fn test_insert_sorted_panics() {
+    let mut a = array_vec![2; 1, 2];
+    a.insert_sorted(0);
+}
+
+#[test]
+fn test_insert_sorted_by_key() {
+    let mut a = ArrayVec::<(u8, char), 4>::new();
+    assert_eq!(a.insert_sorted_by_key((2, 'a'), |e| e.0), 0);
+    assert_eq!(a.insert_sorted_by_key((1, 'b'), |e| e.0), 0);
+    assert_eq!(a.insert_sorted_by_key((2, 'c'), |e| e.0), 2);
+    assert_eq!(a.insert_sorted_by_key((0, 'd'), |e| e.0), 0);
+    assert_eq!(a, [(0, 'd'), (1, 'b'), (2, 'a'), (2, 'c')]);
+}
+
+#[test]
+#[should_panic]
+fn test_insert_sorted_by_key_panics() {
+    let mut a = array_vec![1; 1];
+    a.insert_sorted_by_key(0, |e| *e);
+}
+
+#[test]
+fn test_remove_sorted() {
+    let mut a = ArrayVec::<u8, 4, U8, Pattern<0xBA>>::try_from([1, 2, 3]).unwrap();
+    assert_eq!(a.remove_sorted(&0), None);
+    assert_eq!(a.remove_sorted(&2), Some(2));
+    assert_eq!(a, [1, 3]);
+    check_spare_memory(&a, 0xBA);
+    assert_eq!(a.remove_sorted(&3), Some(3));
+    assert_eq!(a.remove_sorted(&1), Some(1));
+    assert_eq!(a.remove_sorted(&1), None);
+    assert!(a.is_empty());
+}
+
+#[test]
+fn test_dedup_sorted() {
+    let mut a = ArrayVec::<u8, 8, U8, Pattern<0xBA>>::try_from([1, 1, 2, 3, 3, 3, 4, 4]).unwrap();
+    a.dedup_sorted();
+    assert_eq!(a, [1, 2, 3, 4]);
+    check_spare_memory(&a, 0xBA);
+
+    a.dedup_sorted();
+    assert_eq!(a, [1, 2, 3, 4]);
+
+    let mut a = ArrayVec::<u8, 8, U8, Pattern<0xBA>>::try_from([7; 8]).unwrap();
+    a.dedup_sorted();
+    assert_eq!(a, [7]);
+    check_spare_memory(&a, 0xBA);
+
+    let mut a = ArrayVec::<u8, 8>::new();
+    a.dedup_sorted();
+    assert!(a.is_empty());
+}
+
+#[test]
+fn test_dedup_sorted_dropped() {
+    struct K<'a>(u8, Dropped<'a, 16>);
+    impl PartialEq for K<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    type A<'a> = ArrayVec<K<'a>, 8, U8, Pattern<0xBA>>;
+    let t = Track::<16>::new();
+    let mut a = A::from_iter(
+        [0, 0, 1, 2, 2, 2, 3, 3]
+            .into_iter()
+            .map(|k| K(k, t.alloc())),
+    );
+    a.dedup_sorted();
+    assert!(t.dropped_indices(&[1, 4, 5, 7]));
+    assert!(a.iter().map(|k| k.1.idx()).eq([0, 2, 3, 6]));
+    check_spare_memory_at(&a, 0xBA, 4, 8);
+    drop(a);
+    assert!(t.dropped_range(0..8));
+}
+
+#[test]
+fn test_extract_if() {
+    let mut a = ArrayVec::<u8, 8, U8, Pattern<0xBA>>::try_from([1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+    assert!(a.extract_if(.., |e| *e % 3 == 0).eq([3, 6]));
+    assert_eq!(a, [1, 2, 4, 5, 7, 8]);
+    check_spare_memory(&a, 0xBA);
+
+    assert!(a.extract_if(2..=3, |_| true).eq([4, 5]));
+    assert_eq!(a, [1, 2, 7, 8]);
+    check_spare_memory(&a, 0xBA);
+
+    assert!(a.extract_if(1..1, |_| true).eq([]));
+    assert!(a.extract_if(.., |_| false).eq([]));
+    assert_eq!(a, [1, 2, 7, 8]);
+
+    assert!(a
+        .extract_if(.., |e| {
+            *e *= 2;
+            *e > 10
+        })
+        .eq([14, 16]));
+    assert_eq!(a, [2, 4]);
+    check_spare_memory(&a, 0xBA);
+}
+
+#[test]
+fn test_extract_if_partially_consumed() {
+    let mut a = ArrayVec::<u8, 8, U8, Pattern<0xBA>>::try_from([1, 2, 3, 4, 5, 6]).unwrap();
+    let mut it = a.extract_if(..5, |e| *e % 2 == 0);
+    assert_eq!(it.size_hint(), (0, Some(5)));
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.size_hint(), (0, Some(3)));
+    drop(it);
+    assert_eq!(a, [1, 3, 4, 5, 6]);
+    check_spare_memory(&a, 0xBA);
+}
+
+#[test]
+fn test_extract_if_dropped() {
+    type A<'a> = ArrayVec<Dropped<'a, 16>, 8, U8, Pattern<0xBA>>;
+    let t = Track::<16>::new();
+    let mut a = A::from_iter(t.take(8));
+    let mut it = a.extract_if(1..7, |e| e.idx() % 2 == 1);
+    let e = it.next().unwrap();
+    assert_eq!(e.idx(), 1);
+    assert!(t.dropped_indices(&[]));
+    drop(e);
+    assert!(t.dropped_indices(&[1]));
+    it.for_each(drop);
+    assert!(t.dropped_indices(&[1, 3, 5]));
+    assert!(a.iter().map(|e| e.idx()).eq([0, 2, 4, 6, 7]));
+    check_spare_memory_at(&a, 0xBA, 5, 8);
+    drop(a);
+    assert!(t.dropped_range(0..8));
+}
+
+#[test]
+fn test_extract_if_forget() {
+    let mut a = array_vec![8; 1, 2, 3, 4];
+    let mut it = a.extract_if(1.., |_| true);
+    assert_eq!(it.next(), Some(2));
+    mem::forget(it);
+    assert_eq!(a, [1]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_extract_if_pred_panics() {
+    type A<'a> = ArrayVec<Dropped<'a, 16>, 8, U8, Pattern<0xBA>>;
+    let t = Track::<16>::new();
+    let mut a = A::from_iter(t.take(8));
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        a.extract_if(.., |e| {
+            if e.idx() == 4 {
+                panic!("predicate panics");
+            }
+            e.idx() % 2 == 0
+        })
+        .for_each(drop);
+    }));
+    assert!(res.is_err());
+    assert!(t.dropped_indices(&[0, 2]));
+    assert!(a.iter().map(|e| e.idx()).eq([1, 3, 4, 5, 6, 7]));
+    check_spare_memory_at(&a, 0xBA, 6, 8);
+    drop(a);
+    assert!(t.dropped_range(0..8));
+}
+
+#[test]
+#[should_panic]
+fn test_extract_if_panics_on_invalid_range() {
+    let mut a = array_vec![8; 1, 2, 3, 4];
+    a.extract_if(..5, |_| true);
+}
+
+#[cfg(feature = "bytemuck")]
+mod from_bytes {
+    use super::*;
+    use cds::{arrayvec::errors::FromBytesError, len::U16NZ, mem::Zeroed};
+
+    #[test]
+    fn test_from_bytes() {
+        type A = ArrayVec<u32, 3, U32, Zeroed>;
+        let mut buf = [1u32, 2, 0, 2];
+        let a = A::from_bytes(bytemuck::bytes_of(&buf)).unwrap();
+        assert_eq!(a, &[1, 2]);
+        assert_eq!(a.spare_capacity(), 1);
+
+        buf[3] = 3;
+        let a = A::from_bytes(bytemuck::bytes_of(&buf)).unwrap();
+        assert_eq!(a, &[1, 2, 0]);
+
+        buf[3] = 4;
+        assert!(matches!(
+            A::from_bytes(bytemuck::bytes_of(&buf)),
+            Err(FromBytesError::InvalidLength)
+        ));
+    }
+
+    #[test]
+    fn test_from_bytes_spare_memory() {
+        type A = ArrayVec<u32, 3, U32, Zeroed>;
+        let mut buf = [1u32, 2, 3, 1];
+        assert!(matches!(
+            A::from_bytes(bytemuck::bytes_of(&buf)),
+            Err(FromBytesError::NonZeroSpareMemory)
+        ));
+        assert!(matches!(
+            A::from_bytes_mut(bytemuck::bytes_of_mut(&mut buf)),
+            Err(FromBytesError::NonZeroSpareMemory)
+        ));
+        buf[1] = 0;
+        assert!(matches!(
+            A::from_bytes(bytemuck::bytes_of(&buf)),
+            Err(FromBytesError::NonZeroSpareMemory)
+        ));
+        buf[2] = 0;
+        assert_eq!(A::from_bytes(bytemuck::bytes_of(&buf)).unwrap(), &[1]);
+    }
+
+    #[test]
+    fn test_from_bytes_size_and_alignment() {
+        type A = ArrayVec<u32, 3, U32, Zeroed>;
+        let buf = [0u32; 5];
+        let bytes = bytemuck::bytes_of(&buf);
+        assert!(matches!(
+            A::from_bytes(&bytes[..15]),
+            Err(FromBytesError::InvalidSize)
+        ));
+        assert!(matches!(
+            A::from_bytes(&bytes[..17]),
+            Err(FromBytesError::InvalidSize)
+        ));
+        assert!(matches!(
+            A::from_bytes(&bytes[1..17]),
+            Err(FromBytesError::Misaligned)
+        ));
+        assert!(A::from_bytes(&bytes[4..20]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_from_bytes_padding() {
+        type A = ArrayVec<u32, 3, U8, Zeroed>;
+        assert_eq!(mem::size_of::<A>(), 16);
+        let buf = [0xFFFF_FF01u32, 0, 0, 0xFFFF_FF01];
+        let a = A::from_bytes(bytemuck::bytes_of(&buf)).unwrap();
+        assert_eq!(a, &[0xFFFF_FF01]);
+    }
+
+    #[test]
+    fn test_from_bytes_nz() {
+        type A = ArrayVec<u16, 3, U16NZ, Zeroed>;
+        let mut buf = [7u16, 0, 0, 0];
+        assert!(matches!(
+            A::from_bytes(bytemuck::bytes_of(&buf)),
+            Err(FromBytesError::InvalidLength)
+        ));
+        buf[3] = 2;
+        assert_eq!(A::from_bytes(bytemuck::bytes_of(&buf)).unwrap(), &[7]);
+    }
+
+    #[test]
+    fn test_from_bytes_mut() {
+        type A = ArrayVec<u16, 3, U16NZ, Zeroed>;
+        let mut buf = [0u16, 0, 0, 1];
+        let a = A::from_bytes_mut(bytemuck::bytes_of_mut(&mut buf)).unwrap();
+        a.push(7);
+        a.push(8);
+        a.push(9);
+        assert_eq!(a.pop(), Some(9));
+        assert_eq!(buf, [7, 8, 0, 3]);
+
+        *A::from_bytes_mut(bytemuck::bytes_of_mut(&mut buf)).unwrap() = A::new();
+        assert_eq!(buf, [0, 0, 0, 1]);
+    }
+}
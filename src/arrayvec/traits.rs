@@ -1,13 +1,20 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod as_mut;
 mod as_ref;
 mod borrow;
 mod clone;
 mod debug;
 mod default;
+#[cfg(feature = "defmt")]
+mod defmt;
 mod deref;
 mod drop;
+#[cfg(feature = "encoding")]
+mod encoding;
 mod eq;
 mod extend;
+mod from;
 mod from_iterator;
 mod hash;
 mod index;
@@ -15,4 +22,10 @@ mod into_iterator;
 #[cfg(feature = "std")]
 mod io_write;
 mod ord;
+#[cfg(feature = "proptest")]
+mod proptest;
 mod try_from;
+#[cfg(feature = "ufmt")]
+mod ufmt;
+#[cfg(feature = "bytemuck")]
+mod zeroable;
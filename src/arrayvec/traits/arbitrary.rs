@@ -0,0 +1,56 @@
+use crate::{arrayvec::ArrayVec, len::LengthType, mem::SpareMemoryPolicy};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, T, L, SM, const C: usize> Arbitrary<'a> for ArrayVec<T, C, L, SM>
+where
+    T: Arbitrary<'a>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut v = Self::new();
+        for e in u.arbitrary_iter()? {
+            if v.is_full() {
+                break;
+            }
+            v.push(e?);
+        }
+        Ok(v)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let mut v = Self::new();
+        for e in u.arbitrary_take_rest_iter()? {
+            if v.is_full() {
+                break;
+            }
+            v.push(e?);
+        }
+        Ok(v)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{arrayvec::ArrayVec, len::U8};
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn test_arbitrary_respects_capacity() {
+        type A = ArrayVec<u8, 3, U8>;
+        let data = [1, 10, 1, 11, 1, 12, 1, 13, 1, 14, 0];
+        let mut u = Unstructured::new(&data);
+        let a = A::arbitrary(&mut u).unwrap();
+        assert_eq!(a, [10, 11, 12]);
+
+        let a = A::arbitrary_take_rest(Unstructured::new(&[1, 7, 1, 8, 1, 9, 1, 10])).unwrap();
+        assert_eq!(a, [7, 8, 9]);
+    }
+
+    #[test]
+    fn test_arbitrary_empty() {
+        type A = ArrayVec<u64, 3, U8>;
+        let a = A::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert!(a.is_empty());
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{arrayvec::ArrayVec, len::LengthType, mem::SpareMemoryPolicy};
+use defmt::{Format, Formatter};
+
+/// Implementation of [`Format`] for [`ArrayVec`].
+///
+/// An array-vector is formatted as a slice of its elements.
+impl<T, L, SM, const C: usize> Format for ArrayVec<T, C, L, SM>
+where
+    T: Format,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn format(&self, f: Formatter<'_>) {
+        Format::format(self.as_slice(), f)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{
+        arrayvec::{errors::*, ArrayVec},
+        len::U8,
+    };
+    use defmt::Format;
+
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn test_format() {
+        assert_format::<ArrayVec<u32, 4, U8>>();
+        assert_format::<ArrayVec<ArrayVec<bool, 2>, 4>>();
+        assert_format::<InsufficientCapacityError>();
+        assert_format::<InsufficientCapacityErrorVal<u32>>();
+        assert_format::<InsertError>();
+        assert_format::<InsertErrorVal<u32>>();
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    arrayvec::ArrayVec,
+    encoding::{decode_len, encode_seq, encoded_seq_len, errors::*, Decode, Encode},
+    len::LengthType,
+    mem::SpareMemoryPolicy,
+};
+
+impl<T, L, SM, const C: usize> Encode for ArrayVec<T, C, L, SM>
+where
+    T: Encode,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        encoded_seq_len::<L, T>(self)
+    }
+
+    #[inline]
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        encode_seq::<L, T>(self, buf)
+    }
+}
+
+impl<T, L, SM, const C: usize> Decode for ArrayVec<T, C, L, SM>
+where
+    T: Decode,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn decode(buf: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (len, mut n) = decode_len::<L>(buf, C)?;
+        let mut tmp = Self::new();
+        for _ in 0..len {
+            let (e, m) = T::decode(&buf[n..])?;
+            n += m;
+            // `len <= C` is verified by `decode_len`
+            unsafe { tmp.push_unchecked(e) };
+        }
+        Ok((tmp, n))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate as cds;
+    use cds::{
+        arrayvec::ArrayVec,
+        encoding::{errors::*, Decode, Encode},
+        len::{U16, U8},
+    };
+
+    #[test]
+    fn test_encode() {
+        let a = ArrayVec::<u8, 200, U8>::try_from([1, 2, 3]).unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(a.encoded_len(), 4);
+        assert_eq!(a.encode(&mut buf), Ok(4));
+        assert_eq!(buf[..4], [3, 1, 2, 3]);
+        assert_eq!(a.encode(&mut buf[..3]), Err(EncodeError));
+
+        let a = ArrayVec::<u16, 300, U16>::try_from([0x102]).unwrap();
+        assert_eq!(a.encoded_len(), 4);
+        assert_eq!(a.encode(&mut buf), Ok(4));
+        assert_eq!(buf[..4], [1, 0, 2, 1]);
+    }
+
+    #[test]
+    fn test_decode() {
+        type A = ArrayVec<u8, 3, U8>;
+        assert_eq!(
+            A::decode(&[2, 7, 8, 9]),
+            Ok((A::try_from([7, 8]).unwrap(), 3))
+        );
+        assert_eq!(A::decode(&[0]), Ok((A::new(), 1)));
+        assert_eq!(A::decode(&[]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(A::decode(&[2, 7]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(
+            A::decode(&[4, 1, 2, 3, 4]),
+            Err(DecodeError::InsufficientCapacity)
+        );
+
+        type E = ArrayVec<bool, 2, U8>;
+        type B = ArrayVec<E, 2, U8>;
+        let b = B::try_from([
+            E::try_from([true]).unwrap(),
+            E::try_from([false, true]).unwrap(),
+        ])
+        .unwrap();
+        let mut buf = [0u8; 8];
+        let n = b.encode(&mut buf).unwrap();
+        assert_eq!(buf[..n], [2, 1, 1, 2, 0, 1]);
+        assert_eq!(B::decode(&buf[..n]), Ok((b, n)));
+        assert_eq!(B::decode(&[1, 1, 2]), Err(DecodeError::InvalidValue));
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{arrayvec::ArrayVec, len::LengthType, mem::SpareMemoryPolicy};
 use core::cmp::{Eq, PartialEq};
 
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, collections::VecDeque, vec::Vec};
+
 impl<T, L, U, SM, const C: usize, const N: usize> PartialEq<&'_ [U; N]> for ArrayVec<T, C, L, SM>
 where
     T: PartialEq<U>,
@@ -79,6 +82,87 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<T, L, U, SM, const C: usize> PartialEq<Vec<U>> for ArrayVec<T, C, L, SM>
+where
+    T: PartialEq<U>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, U, SM, const C: usize> PartialEq<ArrayVec<U, C, L, SM>> for Vec<T>
+where
+    T: PartialEq<U>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<U>,
+{
+    #[inline]
+    fn eq(&self, other: &ArrayVec<U, C, L, SM>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, U, SM, const C: usize> PartialEq<VecDeque<U>> for ArrayVec<T, C, L, SM>
+where
+    T: PartialEq<U>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn eq(&self, other: &VecDeque<U>) -> bool {
+        let (a, b) = other.as_slices();
+        self.len() == other.len() && self[..a.len()] == *a && self[a.len()..] == *b
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, U, SM, const C: usize> PartialEq<ArrayVec<U, C, L, SM>> for VecDeque<T>
+where
+    T: PartialEq<U>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<U>,
+{
+    #[inline]
+    fn eq(&self, other: &ArrayVec<U, C, L, SM>) -> bool {
+        let (a, b) = self.as_slices();
+        self.len() == other.len() && *a == other[..a.len()] && *b == other[a.len()..]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, U, SM, const C: usize> PartialEq<Cow<'_, [U]>> for ArrayVec<T, C, L, SM>
+where
+    T: PartialEq<U>,
+    U: Clone,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn eq(&self, other: &Cow<'_, [U]>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, U, SM, const C: usize> PartialEq<ArrayVec<U, C, L, SM>> for Cow<'_, [T]>
+where
+    T: PartialEq<U> + Clone,
+    L: LengthType,
+    SM: SpareMemoryPolicy<U>,
+{
+    #[inline]
+    fn eq(&self, other: &ArrayVec<U, C, L, SM>) -> bool {
+        self[..] == other[..]
+    }
+}
+
 impl<T: Eq, L: LengthType, SM: SpareMemoryPolicy<T>, const C: usize> Eq for ArrayVec<T, C, L, SM> {}
 
 #[cfg(test)]
@@ -86,6 +170,9 @@ mod testing {
     use crate as cds;
     use cds::{array_vec, arrayvec::ArrayVec};
 
+    #[cfg(feature = "alloc")]
+    use alloc::{borrow::Cow, collections::VecDeque, vec, vec::Vec};
+
     type A = ArrayVec<u64, 7>;
 
     #[test]
@@ -135,4 +222,44 @@ mod testing {
         assert!(a == b);
         assert!(a != array_vec![7; u64]);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_eq_vec() {
+        let a = A::from_iter(0..3);
+        let v: Vec<u64> = vec![0, 1, 2];
+        assert!(a == v);
+        assert!(v == a);
+        assert!(a != vec![0, 1]);
+        assert!(vec![0, 1] != a);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_eq_vec_deque() {
+        let a = A::from_iter(0..4);
+        let mut d = VecDeque::from(vec![2u64, 3]);
+        d.push_front(1);
+        d.push_front(0);
+        assert!(a == d);
+        assert!(d == a);
+        d.pop_back();
+        assert!(a != d);
+        assert!(d != a);
+        d.push_back(4);
+        assert!(a != d);
+        assert!(d != a);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_eq_cow() {
+        let a = A::from_iter(0..3);
+        let c: Cow<'_, [u64]> = Cow::Borrowed(&[0, 1, 2]);
+        assert!(a == c);
+        assert!(c == a);
+        let c: Cow<'_, [u64]> = Cow::Owned(vec![0, 1]);
+        assert!(a != c);
+        assert!(c != a);
+    }
 }
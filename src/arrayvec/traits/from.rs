@@ -0,0 +1,70 @@
+#![cfg(feature = "alloc")]
+
+use crate::{arrayvec::ArrayVec, len::LengthType, mem::SpareMemoryPolicy};
+use alloc::vec::Vec;
+use core::ptr;
+
+/// Moves the elements of an [`ArrayVec`] into a newly allocated [`Vec`].
+///
+/// Spare memory policy `SM` of the array-vector is applied to the memory of the moved out
+/// elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::array_vec;
+/// let a = array_vec![3; u64; 1, 2];
+/// let v = Vec::from(a);
+/// assert_eq!(v, [1, 2]);
+/// ```
+impl<T, L, SM, const C: usize> From<ArrayVec<T, C, L, SM>> for Vec<T>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn from(mut a: ArrayVec<T, C, L, SM>) -> Self {
+        let len = a.len();
+        let mut v = Vec::with_capacity(len);
+        unsafe {
+            ptr::copy_nonoverlapping(a.as_ptr(), v.as_mut_ptr(), len);
+            v.set_len(len);
+            a.set_len(0);
+            SM::init(a.as_mut_ptr(), len);
+        }
+        v
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate as cds;
+    use alloc::vec::Vec;
+    use cds::{
+        array_vec,
+        arrayvec::ArrayVec,
+        len::U8,
+        mem::Pattern,
+        testing::dropped::{Dropped, Track},
+    };
+
+    #[test]
+    fn test_vec_from() {
+        let v = Vec::from(array_vec![4; u64; 1, 2, 3]);
+        assert_eq!(v, [1, 2, 3]);
+
+        let v = Vec::from(array_vec![4; u64]);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn test_vec_from_dropped() {
+        let t = Track::<8>::new();
+        let a = ArrayVec::<Dropped<'_, 8>, 4, U8, Pattern<0xBA>>::from_iter(t.take(3));
+        let v = Vec::from(a);
+        assert!(t.dropped_indices(&[]));
+        assert!(v.iter().map(|e| e.idx()).eq(0..3));
+        drop(v);
+        assert!(t.dropped_range(0..3));
+    }
+}
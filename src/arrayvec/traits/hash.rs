@@ -32,4 +32,68 @@ mod testing {
 
         assert_eq!(hasher1.finish(), hasher2.finish());
     }
+
+    #[test]
+    fn test_hash_same_as_slice() {
+        use cds::{arrayvec::ArrayVec, len::U8, mem::Pattern, testing::hasher::record};
+        let s: &[u32] = &[3, 1, 2];
+        assert_eq!(
+            record(&ArrayVec::<u32, 4, U8>::try_from(s).unwrap()),
+            record(s)
+        );
+        assert_eq!(
+            record(&ArrayVec::<u32, 8, U8, Pattern<0xAB>>::try_from(s).unwrap()),
+            record(s)
+        );
+        assert_eq!(record(&ArrayVec::<u32, 4, U8>::new()), record(&s[..0]));
+
+        let s = ["a", "bc"];
+        assert_eq!(
+            record(&ArrayVec::<&str, 2>::try_from(s).unwrap()),
+            record(&s[..])
+        );
+    }
+
+    #[test]
+    fn test_hash_map_lookup_by_slice() {
+        use cds::{arrayvec::ArrayVec, len::U8};
+        use std::collections::HashMap;
+
+        type A = ArrayVec<u16, 4, U8>;
+        let mut m = HashMap::<A, usize>::new();
+        m.insert(A::try_from([1, 2]).unwrap(), 12);
+        m.insert(A::new(), 0);
+        assert_eq!(m.get(&[1u16, 2][..]), Some(&12));
+        assert_eq!(m.get(&[0u16; 0][..]), Some(&0));
+        assert_eq!(m.get(&[2u16, 1][..]), None);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_hash_bytes() {
+        use cds::{
+            arrayvec::ArrayVec,
+            len::U8,
+            testing::hasher::{record, RecordingHasher},
+        };
+
+        // a single write of the whole content, following the length
+        let a = ArrayVec::<[u16; 2], 4, U8>::try_from([[1, 2], [3, 4]]).unwrap();
+        let mut h = RecordingHasher::default();
+        a.hash_bytes(&mut h);
+        assert_eq!(h.0.len(), 2);
+        assert_eq!(h.0[0], 2usize.to_ne_bytes());
+        assert_eq!(h.0[1], bytemuck::bytes_of(&[[1u16, 2], [3, 4]]));
+
+        // same as `Hash` for primitive integers
+        let a = ArrayVec::<u32, 4, U8>::try_from([7, 8, 9]).unwrap();
+        let mut h = RecordingHasher::default();
+        a.hash_bytes(&mut h);
+        assert_eq!(h.0, record(&a));
+
+        let a = ArrayVec::<u32, 4, U8>::new();
+        let mut h = RecordingHasher::default();
+        a.hash_bytes(&mut h);
+        assert_eq!(h.0, record(&a));
+    }
 }
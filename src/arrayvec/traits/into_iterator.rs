@@ -1,6 +1,27 @@
-use crate::{arrayvec::ArrayVec, len::LengthType, mem::SpareMemoryPolicy};
+use crate::{
+    arrayvec::{ArrayVec, IntoIter},
+    len::LengthType,
+    mem::SpareMemoryPolicy,
+};
 use core::{iter::IntoIterator, slice};
 
+impl<T, L, SM, const C: usize> IntoIterator for ArrayVec<T, C, L, SM>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T, L, SM, C>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            av: self,
+            start: L::new(0),
+        }
+    }
+}
+
 impl<'a, T, L, SM, const C: usize> IntoIterator for &'a ArrayVec<T, C, L, SM>
 where
     L: LengthType,
@@ -1,6 +1,9 @@
 use crate::{arrayvec::ArrayVec, len::LengthType, mem::SpareMemoryPolicy};
 use core::cmp::{Ord, Ordering, PartialOrd};
 
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, collections::VecDeque, vec::Vec};
+
 impl<T, L, SM, const C: usize> PartialOrd for ArrayVec<T, C, L, SM>
 where
     T: PartialOrd,
@@ -25,12 +28,93 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<T, L, SM, const C: usize> PartialOrd<Vec<T>> for ArrayVec<T, C, L, SM>
+where
+    T: PartialOrd,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Vec<T>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, SM, const C: usize> PartialOrd<ArrayVec<T, C, L, SM>> for Vec<T>
+where
+    T: PartialOrd,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &ArrayVec<T, C, L, SM>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, SM, const C: usize> PartialOrd<VecDeque<T>> for ArrayVec<T, C, L, SM>
+where
+    T: PartialOrd,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &VecDeque<T>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, SM, const C: usize> PartialOrd<ArrayVec<T, C, L, SM>> for VecDeque<T>
+where
+    T: PartialOrd,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &ArrayVec<T, C, L, SM>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, SM, const C: usize> PartialOrd<Cow<'_, [T]>> for ArrayVec<T, C, L, SM>
+where
+    T: PartialOrd + Clone,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Cow<'_, [T]>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, SM, const C: usize> PartialOrd<ArrayVec<T, C, L, SM>> for Cow<'_, [T]>
+where
+    T: PartialOrd + Clone,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &ArrayVec<T, C, L, SM>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
 #[cfg(test)]
 mod testing {
     use crate as cds;
     use cds::array_vec;
     use core::cmp::Ordering;
 
+    #[cfg(feature = "alloc")]
+    use alloc::{borrow::Cow, collections::VecDeque, vec};
+
     #[test]
     #[allow(clippy::nonminimal_bool)]
     fn test_partial_ord() {
@@ -57,4 +141,37 @@ mod testing {
         assert_eq!(b.cmp(&a), Ordering::Greater);
         assert_eq!(a.cmp(&a), Ordering::Equal);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_partial_ord_vec() {
+        let a = array_vec![3; u64; 1, 2, 3];
+        assert!(a < vec![2, 2, 3]);
+        assert!(vec![2, 2, 3] > a);
+        assert!(a > vec![1, 2]);
+        assert!(vec![1, 2] < a);
+        assert_eq!(a.partial_cmp(&vec![1, 2, 3]), Some(Ordering::Equal));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_partial_ord_vec_deque() {
+        let a = array_vec![3; u64; 1, 2, 3];
+        let mut d = VecDeque::from(vec![2u64, 3]);
+        d.push_front(1);
+        assert_eq!(a.partial_cmp(&d), Some(Ordering::Equal));
+        assert_eq!(d.partial_cmp(&a), Some(Ordering::Equal));
+        d.push_front(0);
+        assert!(a > d);
+        assert!(d < a);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_partial_ord_cow() {
+        let a = array_vec![3; u64; 1, 2, 3];
+        let c: Cow<'_, [u64]> = Cow::Borrowed(&[1, 3]);
+        assert!(a < c);
+        assert!(c > a);
+    }
 }
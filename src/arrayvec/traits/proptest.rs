@@ -0,0 +1,45 @@
+use crate::{
+    arrayvec::ArrayVec,
+    len::LengthType,
+    mem::SpareMemoryPolicy,
+    strategy::{array_vec, ArrayVecStrategy},
+};
+use proptest::{
+    arbitrary::{any_with, Arbitrary},
+    collection::SizeRange,
+};
+
+impl<T, L, SM, const C: usize> Arbitrary for ArrayVec<T, C, L, SM>
+where
+    T: Arbitrary,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = ArrayVecStrategy<T::Strategy, C, L, SM>;
+
+    #[inline]
+    fn arbitrary_with((size, args): Self::Parameters) -> Self::Strategy {
+        array_vec(any_with::<T>(args), size)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{arrayvec::ArrayVec, len::U8};
+    use proptest::prelude::*;
+
+    type A = ArrayVec<u16, 5, U8>;
+
+    proptest! {
+        #[test]
+        fn test_any_respects_capacity(a in any::<A>()) {
+            prop_assert!(a.len() <= 5);
+        }
+
+        #[test]
+        fn test_any_with_size(a in any_with::<A>(((2..=3).into(), ()))) {
+            prop_assert!((2..=3).contains(&a.len()));
+        }
+    }
+}
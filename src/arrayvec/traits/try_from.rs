@@ -5,6 +5,11 @@ use crate::{
 };
 use core::{convert::TryFrom, mem, ptr};
 
+#[cfg(feature = "smallvec")]
+use crate::{
+    arrayvec::errors::InsufficientCapacityErrorVal, mem::GrowthPolicy, smallvec::SmallVec,
+};
+
 impl<T, L, SM, const C: usize> TryFrom<&[T]> for ArrayVec<T, C, L, SM>
 where
     T: Clone,
@@ -69,6 +74,57 @@ where
     }
 }
 
+/// Moves the elements of a [`SmallVec`] into an [`ArrayVec`].
+///
+/// Spare memory policy `SM2` of the small-vector is applied to the memory of the moved out
+/// elements, and its heap buffer, if any, is released.
+///
+/// # Errors
+///
+/// Returns [`InsufficientCapacityErrorVal`], holding the source small-vector untouched,
+/// if it has more elements than `C`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::{arrayvec::ArrayVec, small_vec};
+/// let v = small_vec![2; u64; 1, 2, 3];
+/// let a = ArrayVec::<u64, 4>::try_from(v).unwrap();
+/// assert_eq!(a, [1, 2, 3]);
+///
+/// let v = small_vec![2; u64; 1, 2, 3, 4, 5];
+/// let e = ArrayVec::<u64, 4>::try_from(v).unwrap_err();
+/// assert_eq!(e.0, [1, 2, 3, 4, 5]);
+/// ```
+#[cfg(feature = "smallvec")]
+impl<T, L, L2, SM, SM2, G, const C: usize, const C2: usize> TryFrom<SmallVec<T, C2, L2, SM2, G>>
+    for ArrayVec<T, C, L, SM>
+where
+    L: LengthType,
+    L2: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    SM2: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    type Error = InsufficientCapacityErrorVal<SmallVec<T, C2, L2, SM2, G>>;
+
+    #[inline]
+    fn try_from(mut v: SmallVec<T, C2, L2, SM2, G>) -> Result<Self, Self::Error> {
+        let len = v.len();
+        if len > Self::CAPACITY {
+            return Err(InsufficientCapacityErrorVal(v));
+        }
+        let mut tmp = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(v.as_ptr(), tmp.as_mut_ptr(), len);
+            tmp.set_len(len);
+            v.set_len(0);
+            SM2::init(v.as_mut_ptr(), len);
+        }
+        Ok(tmp)
+    }
+}
+
 #[cfg(test)]
 mod testing {
     use crate as cds;
@@ -116,4 +172,32 @@ mod testing {
             Err(e) if e == InsufficientCapacityError
         ));
     }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn test_try_from_small_vec() {
+        use cds::{
+            len::U8,
+            mem::Pattern,
+            smallvec::SmallVec,
+            testing::dropped::{Dropped, Track},
+        };
+
+        let v = SmallVec::<u64, 2>::from_iter(1..4);
+        assert!(v.is_heap());
+        let a = A::try_from(v).unwrap();
+        assert_eq!(a, [1, 2, 3]);
+
+        let v = SmallVec::<u64, 2>::from_iter(0..8);
+        let e = A::try_from(v).unwrap_err();
+        assert_eq!(e.0, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+        let t = Track::<8>::new();
+        let v = SmallVec::<Dropped<'_, 8>, 4, U8, Pattern<0xBA>>::from_iter(t.take(3));
+        let a = ArrayVec::<Dropped<'_, 8>, 3>::try_from(v).unwrap();
+        assert!(t.dropped_indices(&[]));
+        assert!(a.iter().map(|e| e.idx()).eq(0..3));
+        drop(a);
+        assert!(t.dropped_range(0..3));
+    }
 }
@@ -0,0 +1,32 @@
+use crate::{arrayvec::ArrayVec, len::LengthType, mem::SpareMemoryPolicy};
+use ufmt::{uDebug, uWrite, Formatter};
+
+impl<T, L, SM, const C: usize> uDebug for ArrayVec<T, C, L, SM>
+where
+    T: uDebug,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+{
+    #[inline]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        uDebug::fmt(self.as_slice(), f)
+    }
+}
+
+#[cfg(all(test, feature = "arraystring"))]
+mod testing {
+    use crate as cds;
+    use cds::{array_str, array_vec};
+    use ufmt::uwrite;
+
+    #[test]
+    fn test_debug() {
+        let a = array_vec![3; u16; 1, 20, 300];
+        let mut s = array_str![16;];
+        uwrite!(s, "{:?}", a).unwrap();
+        assert_eq!(s, "[1, 20, 300]");
+    }
+}
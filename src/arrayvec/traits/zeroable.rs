@@ -0,0 +1,25 @@
+use crate::{arrayvec::ArrayVec, len::LengthType, mem::Zeroed};
+use bytemuck::Zeroable;
+
+// SAFETY: all-zero `ArrayVec` is empty, and has zeroed spare memory
+//
+// `Pod` is not implemented, as an arbitrary length or non-zero spare memory is invalid
+unsafe impl<T, L, const C: usize> Zeroable for ArrayVec<T, C, L, Zeroed> where
+    L: LengthType + Zeroable
+{
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{arrayvec::ArrayVec, len::U8, mem::Zeroed};
+    use bytemuck::Zeroable;
+
+    #[test]
+    fn test_zeroed() {
+        type A = ArrayVec<u32, 4, U8, Zeroed>;
+        let mut a = A::zeroed();
+        assert!(a.is_empty());
+        a.push(7);
+        assert_eq!(a, [7]);
+    }
+}
@@ -0,0 +1,289 @@
+//! Compact binary encoding of *cds* collections.
+//!
+//! The encoding is similar in spirit to [borsh] and [postcard]: values are written back to back,
+//! without any framing or field names. Integers are encoded in little-endian byte order, with
+//! their native width. A `bool` is a single byte of `0` or `1`, a `char` is encoded as `u32`,
+//! and `()` takes no bytes at all.
+//!
+//! A collection is encoded as a length prefix followed by its elements. The width of the length
+//! prefix follows the collection's [`LengthType`] `L`, i.e. it is `size_of::<L>()` bytes:
+//! one byte for [`U8`] and [`U8NZ`], two bytes for [`U16`], three bytes for [`U24`] etc.
+//! Thus, a collection with `U8` length type, like `ArrayVec<u8, 200, U8>`, has a single byte
+//! length prefix. Note that the width of [`Usize`] depends on the target platform.
+//!
+//! Decoding never panics on malformed input. Lengths exceeding the capacity of a fixed-capacity
+//! collection are reported as [`DecodeError::InsufficientCapacity`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "arrayvec")] {
+//! use cds::{
+//!     arrayvec::ArrayVec,
+//!     encoding::{errors::DecodeError, Decode, Encode},
+//!     len::U8,
+//! };
+//! let a = ArrayVec::<u16, 4, U8>::try_from([1, 2, 3]).unwrap();
+//! let mut buf = [0u8; 16];
+//! let n = a.encode(&mut buf).unwrap();
+//! assert_eq!(&buf[..n], &[3, 1, 0, 2, 0, 3, 0]);
+//!
+//! let (b, m) = ArrayVec::<u16, 4, U8>::decode(&buf[..n]).unwrap();
+//! assert_eq!((b, m), (a, n));
+//!
+//! let e = ArrayVec::<u16, 2, U8>::decode(&buf[..n]).unwrap_err();
+//! assert_eq!(e, DecodeError::InsufficientCapacity);
+//! # }
+//! ```
+//!
+//! [borsh]: https://docs.rs/borsh
+//! [postcard]: https://docs.rs/postcard
+//! [`U8`]: crate::len::U8
+//! [`U8NZ`]: crate::len::U8NZ
+//! [`U16`]: crate::len::U16
+//! [`U24`]: crate::len::U24
+//! [`Usize`]: crate::len::Usize
+//! [`LengthType`]: crate::len::LengthType
+
+#[cfg(any(feature = "arrayvec", feature = "arraystring", feature = "smallvec"))]
+use crate::len::LengthType;
+use core::mem;
+
+pub mod errors;
+use errors::*;
+
+/// A trait of types that can be encoded into a byte buffer.
+pub trait Encode {
+    /// Returns the number of bytes the encoding of `self` takes.
+    fn encoded_len(&self) -> usize;
+
+    /// Encodes `self` into the beginning of `buf`.
+    ///
+    /// Returns the number of bytes written, or [`EncodeError`] if `buf` is too small.
+    /// In the latter case the contents of `buf` are unspecified.
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, EncodeError>;
+}
+
+/// A trait of types that can be decoded from a byte buffer.
+pub trait Decode: Sized {
+    /// Decodes a value from the beginning of `buf`.
+    ///
+    /// Returns the value and the number of bytes consumed, or [`DecodeError`] if `buf` doesn't
+    /// start with a valid encoding.
+    fn decode(buf: &[u8]) -> Result<(Self, usize), DecodeError>;
+}
+
+/// Returns the width, in bytes, of the length prefix of a collection with length type `L`.
+#[cfg(any(feature = "arrayvec", feature = "arraystring", feature = "smallvec"))]
+#[inline]
+pub(crate) const fn len_width<L: LengthType>() -> usize {
+    mem::size_of::<L>()
+}
+
+/// Encodes a length prefix of a collection with length type `L`.
+#[cfg(any(feature = "arrayvec", feature = "arraystring", feature = "smallvec"))]
+#[inline]
+pub(crate) fn encode_len<L: LengthType>(len: usize, buf: &mut [u8]) -> Result<usize, EncodeError> {
+    let w = len_width::<L>();
+    let dst = buf.get_mut(..w).ok_or(EncodeError)?;
+    dst.copy_from_slice(&(len as u64).to_le_bytes()[..w]);
+    Ok(w)
+}
+
+/// Decodes a length prefix of a collection with length type `L`.
+///
+/// Returns [`DecodeError::InsufficientCapacity`] if the length exceeds `cap`.
+#[cfg(any(feature = "arrayvec", feature = "arraystring", feature = "smallvec"))]
+#[inline]
+pub(crate) fn decode_len<L: LengthType>(
+    buf: &[u8],
+    cap: usize,
+) -> Result<(usize, usize), DecodeError> {
+    let w = len_width::<L>();
+    let src = buf.get(..w).ok_or(DecodeError::UnexpectedEnd)?;
+    let mut bytes = [0u8; 8];
+    bytes[..w].copy_from_slice(src);
+    match usize::try_from(u64::from_le_bytes(bytes)) {
+        Ok(len) if len <= cap => Ok((len, w)),
+        _ => Err(DecodeError::InsufficientCapacity),
+    }
+}
+
+/// Encodes a sequence of elements after a length prefix of length type `L`.
+#[cfg(any(feature = "arrayvec", feature = "smallvec"))]
+#[inline]
+pub(crate) fn encode_seq<L: LengthType, T: Encode>(
+    s: &[T],
+    buf: &mut [u8],
+) -> Result<usize, EncodeError> {
+    let mut n = encode_len::<L>(s.len(), buf)?;
+    for e in s {
+        n += e.encode(&mut buf[n..])?;
+    }
+    Ok(n)
+}
+
+/// Returns the encoded length of a sequence of elements with length type `L`.
+#[cfg(any(feature = "arrayvec", feature = "smallvec"))]
+#[inline]
+pub(crate) fn encoded_seq_len<L: LengthType, T: Encode>(s: &[T]) -> usize {
+    s.iter().fold(len_width::<L>(), |n, e| n + e.encoded_len())
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl Encode for $t {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    mem::size_of::<$t>()
+                }
+
+                #[inline]
+                fn encode(&self, buf: &mut [u8]) -> Result<usize, EncodeError> {
+                    const W: usize = mem::size_of::<$t>();
+                    let dst = buf.get_mut(..W).ok_or(EncodeError)?;
+                    dst.copy_from_slice(&self.to_le_bytes());
+                    Ok(W)
+                }
+            }
+
+            impl Decode for $t {
+                #[inline]
+                fn decode(buf: &[u8]) -> Result<(Self, usize), DecodeError> {
+                    const W: usize = mem::size_of::<$t>();
+                    let mut bytes = [0u8; W];
+                    bytes.copy_from_slice(buf.get(..W).ok_or(DecodeError::UnexpectedEnd)?);
+                    Ok((<$t>::from_le_bytes(bytes), W))
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Encode for () {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn encode(&self, _buf: &mut [u8]) -> Result<usize, EncodeError> {
+        Ok(0)
+    }
+}
+
+impl Decode for () {
+    #[inline]
+    fn decode(_buf: &[u8]) -> Result<(Self, usize), DecodeError> {
+        Ok(((), 0))
+    }
+}
+
+impl Encode for bool {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        (*self as u8).encode(buf)
+    }
+}
+
+impl Decode for bool {
+    #[inline]
+    fn decode(buf: &[u8]) -> Result<(Self, usize), DecodeError> {
+        match u8::decode(buf)? {
+            (0, n) => Ok((false, n)),
+            (1, n) => Ok((true, n)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl Encode for char {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        4
+    }
+
+    #[inline]
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        (*self as u32).encode(buf)
+    }
+}
+
+impl Decode for char {
+    #[inline]
+    fn decode(buf: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (u, n) = u32::decode(buf)?;
+        let ch = char::from_u32(u).ok_or(DecodeError::InvalidValue)?;
+        Ok((ch, n))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    #[cfg(any(feature = "arrayvec", feature = "arraystring", feature = "smallvec"))]
+    use crate::len::{U16, U24, U8, U8NZ};
+
+    #[test]
+    #[cfg(any(feature = "arrayvec", feature = "arraystring", feature = "smallvec"))]
+    fn test_len_width() {
+        assert_eq!(len_width::<U8>(), 1);
+        assert_eq!(len_width::<U8NZ>(), 1);
+        assert_eq!(len_width::<U16>(), 2);
+        assert_eq!(len_width::<U24>(), 3);
+    }
+
+    #[test]
+    #[cfg(any(feature = "arrayvec", feature = "arraystring", feature = "smallvec"))]
+    fn test_len() {
+        let mut buf = [0u8; 4];
+        assert_eq!(encode_len::<U24>(0x10203, &mut buf), Ok(3));
+        assert_eq!(buf, [3, 2, 1, 0]);
+        assert_eq!(decode_len::<U24>(&buf, 0x10203), Ok((0x10203, 3)));
+        assert_eq!(
+            decode_len::<U24>(&buf, 0x10202),
+            Err(DecodeError::InsufficientCapacity)
+        );
+        assert_eq!(
+            decode_len::<U24>(&buf[..2], 10),
+            Err(DecodeError::UnexpectedEnd)
+        );
+        assert_eq!(encode_len::<U24>(1, &mut buf[..2]), Err(EncodeError));
+    }
+
+    #[test]
+    fn test_int() {
+        let mut buf = [0u8; 16];
+        assert_eq!(0x1234u16.encode(&mut buf), Ok(2));
+        assert_eq!(buf[..2], [0x34, 0x12]);
+        assert_eq!(u16::decode(&buf), Ok((0x1234, 2)));
+        assert_eq!((-2i32).encode(&mut buf), Ok(4));
+        assert_eq!(i32::decode(&buf), Ok((-2, 4)));
+        assert_eq!(u128::MAX.encoded_len(), 16);
+        assert_eq!(u64::decode(&buf[..7]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(1u32.encode(&mut buf[..3]), Err(EncodeError));
+    }
+
+    #[test]
+    fn test_bool_char() {
+        let mut buf = [0u8; 4];
+        assert_eq!(true.encode(&mut buf), Ok(1));
+        assert_eq!(bool::decode(&buf), Ok((true, 1)));
+        assert_eq!(bool::decode(&[2]), Err(DecodeError::InvalidValue));
+
+        assert_eq!('€'.encode(&mut buf), Ok(4));
+        assert_eq!(char::decode(&buf), Ok(('€', 4)));
+        assert_eq!(
+            char::decode(&0xD800u32.to_le_bytes()),
+            Err(DecodeError::InvalidValue)
+        );
+    }
+}
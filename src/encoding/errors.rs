@@ -0,0 +1,85 @@
+//! Encoding error types.
+
+use core::{
+    clone::Clone,
+    cmp::{Eq, Ord, PartialEq, PartialOrd},
+    fmt::{Debug, Display, Formatter},
+    hash::Hash,
+    marker::Copy,
+};
+
+// ---------------------------------------------------------------------------
+
+/// An error returned when the output buffer is too small to hold the encoding.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EncodeError;
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "encode error: buffer too small")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for EncodeError {}
+
+// ---------------------------------------------------------------------------
+
+/// An error returned when decoding fails.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DecodeError {
+    /// The input ends before the encoded value does.
+    UnexpectedEnd,
+
+    /// The encoded length exceeds the capacity of the collection.
+    InsufficientCapacity,
+
+    /// The encoded string is not valid UTF-8.
+    InvalidUtf8,
+
+    /// The encoded value is invalid for its type, e.g. a `bool` other than `0` or `1`.
+    InvalidValue,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let s = match *self {
+            DecodeError::UnexpectedEnd => "unexpected end of input",
+            DecodeError::InsufficientCapacity => "insufficient capacity",
+            DecodeError::InvalidUtf8 => "invalid utf-8",
+            DecodeError::InvalidValue => "invalid value",
+        };
+        write!(f, "decode error: {s}")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for DecodeError {}
+
+#[cfg(all(test, feature = "std"))]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn encode_error_display() {
+        let e = EncodeError;
+        let s = format!("{e}");
+        assert_eq!(s, "encode error: buffer too small");
+    }
+
+    #[test]
+    fn decode_error_display() {
+        let s = format!("{}", DecodeError::UnexpectedEnd);
+        assert_eq!(s, "decode error: unexpected end of input");
+        let s = format!("{}", DecodeError::InsufficientCapacity);
+        assert_eq!(s, "decode error: insufficient capacity");
+        let s = format!("{}", DecodeError::InvalidUtf8);
+        assert_eq!(s, "decode error: invalid utf-8");
+        let s = format!("{}", DecodeError::InvalidValue);
+        assert_eq!(s, "decode error: invalid value");
+    }
+}
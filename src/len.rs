@@ -3,3 +3,9 @@
 //! See [`LengthType`] for more information.
 mod length_type;
 pub use length_type::*;
+
+#[cfg(feature = "rkyv")]
+mod archive;
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+pub use archive::*;
@@ -0,0 +1,104 @@
+use crate::len::*;
+use rkyv::{traits::NoUndef, Portable};
+
+/// A trait of length types that can be archived with [`rkyv`].
+///
+/// The archived length is a little-endian byte array of the same width as the length type itself.
+/// Hence, an archived collection keeps the compact layout of its length type, and doesn't depend
+/// on the endianness of the target. Note that the width of [`Usize`] and [`UsizeNZ`] depends on the
+/// target's pointer width.
+///
+/// Niche-optimized length types archive the length itself, i.e. without the `+1` bias.
+pub trait ArchiveLength: LengthType {
+    /// The archived form of the length.
+    type Archived: Portable + NoUndef + Copy;
+
+    /// Converts a length to its archived form.
+    fn archive_len(len: usize) -> Self::Archived;
+
+    /// Converts an archived length back to `usize`.
+    fn unarchive_len(archived: &Self::Archived) -> usize;
+}
+
+macro_rules! archive_length {
+    ($(#[$outer:meta])* $N:ident) => {
+        $(#[$outer])*
+        impl ArchiveLength for $N {
+            type Archived = [u8; core::mem::size_of::<$N>()];
+
+            #[inline]
+            fn archive_len(len: usize) -> Self::Archived {
+                let mut a = [0u8; core::mem::size_of::<$N>()];
+                a.copy_from_slice(&(len as u64).to_le_bytes()[..core::mem::size_of::<$N>()]);
+                a
+            }
+
+            #[inline]
+            fn unarchive_len(archived: &Self::Archived) -> usize {
+                let mut b = [0u8; core::mem::size_of::<u64>()];
+                b[..core::mem::size_of::<$N>()].copy_from_slice(archived);
+                u64::from_le_bytes(b) as usize
+            }
+        }
+    };
+}
+
+archive_length!(U8);
+archive_length!(U16);
+archive_length!(U32);
+archive_length!(U64);
+archive_length!(Usize);
+archive_length!(U8NZ);
+archive_length!(U16NZ);
+archive_length!(U32NZ);
+archive_length!(U64NZ);
+archive_length!(UsizeNZ);
+archive_length!(U24);
+archive_length!(
+    #[cfg(target_pointer_width = "64")]
+    U40
+);
+archive_length!(
+    #[cfg(target_pointer_width = "64")]
+    U48
+);
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    fn round_trip<L: ArchiveLength>(len: usize) {
+        let a = L::archive_len(len);
+        assert_eq!(L::unarchive_len(&a), len);
+    }
+
+    #[test]
+    fn test_archive_len_width() {
+        assert_eq!(core::mem::size_of::<<U8 as ArchiveLength>::Archived>(), 1);
+        assert_eq!(
+            core::mem::size_of::<<U16NZ as ArchiveLength>::Archived>(),
+            2
+        );
+        assert_eq!(core::mem::size_of::<<U24 as ArchiveLength>::Archived>(), 3);
+        assert_eq!(core::mem::size_of::<<U64 as ArchiveLength>::Archived>(), 8);
+    }
+
+    #[test]
+    fn test_archive_len_little_endian() {
+        assert_eq!(U16::archive_len(0x0102), [0x02, 0x01]);
+        assert_eq!(U24::archive_len(0x010203), [0x03, 0x02, 0x01]);
+        assert_eq!(U8NZ::archive_len(0), [0]);
+    }
+
+    #[test]
+    fn test_archive_len_round_trip() {
+        round_trip::<U8>(255);
+        round_trip::<U8NZ>(254);
+        round_trip::<U16>(0xFFFF);
+        round_trip::<U24>(0xFF_FFFF);
+        round_trip::<U32NZ>(0x1234_5678);
+        round_trip::<U64>(0);
+        round_trip::<Usize>(17);
+        round_trip::<UsizeNZ>(17);
+    }
+}
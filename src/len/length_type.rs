@@ -1,6 +1,7 @@
 use core::{
     cmp::Ordering,
-    fmt::{self, Display},
+    fmt::{self, Debug, Display},
+    num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize},
     ops::{AddAssign, SubAssign},
 };
 
@@ -26,6 +27,15 @@ mod lt_private {
         /// The maximal length allowed by the underlying type.
         const MAX: usize;
 
+        /// The value added to a length before it is stored in the underlying type.
+        ///
+        /// This is `1` for niche-optimized length types, which reserve the zero value,
+        /// and `0` otherwise.
+        const BIAS: usize;
+
+        /// `true` if the underlying type is stored in little-endian byte order.
+        const LE: bool;
+
         /// The underlying type.
         type U;
 
@@ -63,6 +73,19 @@ use lt_private::LengthTypeBase;
 ///
 /// Currently supported length types are: [`U8`], [`U16`], [`U32`], [`U64`] and [`Usize`].
 ///
+/// In addition, every length type has a *niche-optimized* counterpart: [`U8NZ`], [`U16NZ`],
+/// [`U32NZ`], [`U64NZ`] and [`UsizeNZ`]. These store `len + 1` in a non-zero integer,
+/// which leaves the zero bit-pattern free for the compiler to use as a niche. As a result
+/// `Option` of a collection using such length type costs no extra space. The price is one
+/// less supported capacity: a niche-optimized length-type having `N` bits in the underlying type
+/// supports collections with capacity of up to `2 ^ N - 2` elements.
+///
+/// Finally, there are *packed* length types of odd widths: [`U24`], [`U40`] and [`U48`].
+/// These are stored as little-endian byte arrays with alignment of 1, which allows collections
+/// using them to pack tightly, without padding bytes that a wider aligned integer would require.
+/// [`U40`] and [`U48`] are available on 64-bit targets only (`target_pointer_width = "64"`),
+/// as their maximum doesn't fit in a 32-bit `usize`.
+///
 /// # Examples
 ///
 /// `LengthType` allows creation of very compact collections:
@@ -78,20 +101,48 @@ use lt_private::LengthTypeBase;
 /// # }
 /// ```
 ///
-/// In fixed-capacity collections, the requested capacity may not exceed the length-type's maximal
-/// value:
+/// Packed length types allow tighter layout when an aligned integer is too wide:
+///
+/// ```rust
+/// # #[cfg(feature = "arraystring")] {
+/// use cds::{
+///     arraystring::ArrayString,
+///     len::{U24, U32},
+/// };
+/// use core::mem::size_of;
+/// assert_eq!(size_of::<ArrayString<1000, U32>>(), 1004);
+/// assert_eq!(size_of::<ArrayString<1000, U24>>(), 1003); // 1000 bytes + 3 length bytes
+/// # }
+/// ```
+///
+/// Niche-optimized length types keep `Option` of a collection as compact as the collection itself:
+///
+/// ```rust
+/// # #[cfg(feature = "arraystring")] {
+/// use cds::{
+///     arraystring::ArrayString,
+///     len::{U8, U8NZ},
+/// };
+/// use core::mem::size_of;
+/// assert_eq!(size_of::<Option<ArrayString<15, U8>>>(), 17);   // <-- extra discriminant byte
+/// assert_eq!(size_of::<Option<ArrayString<15, U8NZ>>>(), 16); // <-- no extra space
+/// # }
+/// ```
 ///
-/// ```should_panic
+/// The capacity of a collection (the local capacity in hybrid-capacity collections) may not exceed
+/// the length-type's maximal value. This is verified at compile time:
+///
+/// ```compile_fail
 /// # #[cfg(feature = "arrayvec")] {
 /// use cds::{
 ///     arrayvec::ArrayVec,
 ///     len::U8,
 /// };
 /// type A = ArrayVec<u8, 256, U8>; // U8::MAX(255) < CAPACITY(256)
-/// let a = A::new();   // <-- this panics
+/// let a = A::new();   // <-- this fails to compile
 /// # }
 /// # #[cfg(not(feature = "arrayvec"))]
-/// # panic!("just to fulfill the doc attribute")
+/// # compile_error!("just to fulfill the doc attribute")
 /// ```
 pub trait LengthType: LengthTypeBase {}
 
@@ -120,6 +171,8 @@ macro_rules! length_type {
 
         impl LengthTypeBase for $N {
             const MAX: usize = <$U>::MAX as usize;
+            const BIAS: usize = 0;
+            const LE: bool = cfg!(target_endian = "little");
             type U = $U;
 
             #[inline]
@@ -181,6 +234,310 @@ macro_rules! length_type {
     };
 }
 
+macro_rules! nz_length_type {
+    ($(#[$outer:meta])* $N:ident, $NZ:ty, $U:ty) => {
+        $(#[$outer])*
+        #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+        #[repr(transparent)]
+        pub struct $N($NZ);
+
+        impl PartialEq<usize> for $N {
+            #[inline]
+            fn eq(&self, other: &usize) -> bool {
+                return self.as_usize() == *other
+            }
+        }
+
+        impl PartialOrd<usize> for $N {
+            #[inline]
+            fn partial_cmp(&self, other: &usize) -> Option<Ordering> {
+                self.as_usize().partial_cmp(other)
+            }
+        }
+
+        impl LengthTypeBase for $N {
+            const MAX: usize = <$U>::MAX as usize - 1;
+            const BIAS: usize = 1;
+            const LE: bool = cfg!(target_endian = "little");
+            type U = $NZ;
+
+            #[inline]
+            fn new(val: usize) -> $N {
+                debug_assert!(val <= Self::MAX);
+                // SAFETY: `val <= MAX` hence `val + 1` neither overflows nor equals zero
+                $N(unsafe { <$NZ>::new_unchecked((val as $U).wrapping_add(1)) })
+            }
+
+            #[inline]
+            fn checked_add_usize(&self, rhs: usize) -> Option<Self> {
+                <$U>::try_from(rhs)
+                    .ok()
+                    .and_then(|v| self.0.get().checked_add(v))
+                    // SAFETY: the sum of a non-zero value and a non-negative value is non-zero
+                    .map(|u| $N(unsafe { <$NZ>::new_unchecked(u) }))
+            }
+
+            #[inline]
+            fn next_power_of_two_or_max(&self) -> Self {
+                let v = (self.0.get() - 1)
+                    .checked_next_power_of_two()
+                    .filter(|v| *v as usize <= Self::MAX)
+                    .map_or(Self::MAX, |v| v as usize);
+                Self::new(v)
+            }
+
+            #[inline]
+            fn as_usize(&self) -> usize {
+                (self.0.get() - 1) as usize
+            }
+
+            #[inline]
+            fn set(&mut self, val: usize) {
+                *self = Self::new(val);
+            }
+        }
+
+        impl LengthType for $N {}
+
+        impl AddAssign<usize> for $N {
+            #[inline]
+            fn add_assign(&mut self, rhs: usize) {
+                debug_assert!(matches!(self.as_usize().checked_add(rhs),
+                                       Some(v) if v <= <Self as LengthTypeBase>::MAX));
+                // SAFETY: the sum of a non-zero value and a non-negative value is non-zero
+                self.0 = unsafe { <$NZ>::new_unchecked(self.0.get() + rhs as $U) };
+            }
+        }
+
+        impl SubAssign<usize> for $N {
+            #[inline]
+            fn sub_assign(&mut self, rhs: usize) {
+                debug_assert!(self.as_usize() >= rhs);
+                // SAFETY: `len >= rhs` hence `len + 1 - rhs` is non-zero
+                self.0 = unsafe { <$NZ>::new_unchecked(self.0.get() - rhs as $U) };
+            }
+        }
+
+        impl Display for $N {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+                Display::fmt(&(self.0.get() - 1), f)
+            }
+        }
+
+        impl Debug for $N {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+                f.debug_tuple(stringify!($N)).field(&(self.0.get() - 1)).finish()
+            }
+        }
+    };
+}
+
+macro_rules! packed_length_type {
+    ($(#[$outer:meta])* $N:ident, $B:literal, $W:ty) => {
+        packed_length_type!(@cfg(all()) $(#[$outer])* $N, $B, $W);
+    };
+    (@cfg($cfg:meta) $(#[$outer:meta])* $N:ident, $B:literal, $W:ty) => {
+        #[cfg($cfg)]
+        $(#[$outer])*
+        #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+        #[repr(transparent)]
+        pub struct $N([u8; $B]);
+
+        #[cfg($cfg)]
+        impl $N {
+            #[inline(always)]
+            fn get(&self) -> $W {
+                let mut b = [0u8; core::mem::size_of::<$W>()];
+                b[..$B].copy_from_slice(&self.0);
+                <$W>::from_le_bytes(b)
+            }
+
+            #[inline(always)]
+            fn from_raw(v: $W) -> Self {
+                let b = v.to_le_bytes();
+                let mut a = [0u8; $B];
+                a.copy_from_slice(&b[..$B]);
+                $N(a)
+            }
+        }
+
+        #[cfg($cfg)]
+        impl PartialEq<usize> for $N {
+            #[inline]
+            fn eq(&self, other: &usize) -> bool {
+                return self.get() as usize == *other
+            }
+        }
+
+        #[cfg($cfg)]
+        impl PartialOrd<usize> for $N {
+            #[inline]
+            fn partial_cmp(&self, other: &usize) -> Option<Ordering> {
+                (self.get() as usize).partial_cmp(other)
+            }
+        }
+
+        #[cfg($cfg)]
+        impl PartialOrd for $N {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        #[cfg($cfg)]
+        impl Ord for $N {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        #[cfg($cfg)]
+        impl LengthTypeBase for $N {
+            const MAX: usize = ((1 as $W) << ($B * 8)) as usize - 1;
+            const BIAS: usize = 0;
+            const LE: bool = true;
+            type U = [u8; $B];
+
+            #[inline]
+            fn new(val: usize) -> $N {
+                debug_assert!(val <= Self::MAX);
+                Self::from_raw(val as $W)
+            }
+
+            #[inline]
+            fn checked_add_usize(&self, rhs: usize) -> Option<Self> {
+                (self.get() as usize)
+                    .checked_add(rhs)
+                    .filter(|v| *v <= Self::MAX)
+                    .map(|v| Self::from_raw(v as $W))
+            }
+
+            #[inline]
+            fn next_power_of_two_or_max(&self) -> Self {
+                let v = (self.get() as usize)
+                    .checked_next_power_of_two()
+                    .filter(|v| *v <= Self::MAX)
+                    .unwrap_or(Self::MAX);
+                Self::from_raw(v as $W)
+            }
+
+            #[inline]
+            fn as_usize(&self) -> usize {
+                self.get() as usize
+            }
+
+            #[inline]
+            fn set(&mut self, val: usize) {
+                debug_assert!(val <= Self::MAX);
+                *self = Self::from_raw(val as $W);
+            }
+        }
+
+        #[cfg($cfg)]
+        impl LengthType for $N {}
+
+        #[cfg($cfg)]
+        impl AddAssign<usize> for $N {
+            #[inline]
+            fn add_assign(&mut self, rhs: usize) {
+                debug_assert!(matches!((self.get() as usize).checked_add(rhs),
+                                       Some(v) if v <= <Self as LengthTypeBase>::MAX));
+                *self = Self::from_raw(self.get() + rhs as $W);
+            }
+        }
+
+        #[cfg($cfg)]
+        impl SubAssign<usize> for $N {
+            #[inline]
+            fn sub_assign(&mut self, rhs: usize) {
+                debug_assert!(self.get() as usize >= rhs);
+                *self = Self::from_raw(self.get() - rhs as $W);
+            }
+        }
+
+        #[cfg($cfg)]
+        impl Display for $N {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+                Display::fmt(&self.get(), f)
+            }
+        }
+
+        #[cfg($cfg)]
+        impl Debug for $N {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+                f.debug_tuple(stringify!($N)).field(&self.get()).finish()
+            }
+        }
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+
+/// Converts a length-type value to `usize` in const context.
+///
+/// Trait methods cannot be called in const context, hence the value is decoded bytewise.
+#[cfg(any(feature = "arrayvec", feature = "arraystring"))]
+#[inline]
+pub(crate) const fn const_as_usize<L: LengthType>(l: &L) -> usize {
+    let p = l as *const L as *const u8;
+    let n = core::mem::size_of::<L>();
+    let mut v = 0usize;
+    let mut i = 0;
+    while i < n && i < core::mem::size_of::<usize>() {
+        let idx = if L::LE { i } else { n - 1 - i };
+        // SAFETY: `idx < size_of::<L>()` and every length type is plain bytes
+        v |= (unsafe { *p.add(idx) } as usize) << (i * 8);
+        i += 1;
+    }
+    v - L::BIAS
+}
+
+/// Creates a length-type value from `usize` in const context.
+///
+/// Trait methods cannot be called in const context, hence the value is encoded bytewise.
+#[cfg(any(feature = "arrayvec", feature = "arraystring"))]
+#[inline]
+pub(crate) const fn const_new<L: LengthType>(val: usize) -> L {
+    assert!(val <= L::MAX);
+    let v = val + L::BIAS;
+    let n = core::mem::size_of::<L>();
+    let mut b = [0u8; core::mem::size_of::<u64>()];
+    let mut i = 0;
+    while i < n && i < core::mem::size_of::<usize>() {
+        let idx = if L::LE { i } else { n - 1 - i };
+        b[idx] = (v >> (i * 8)) as u8;
+        i += 1;
+    }
+    // SAFETY: `b` holds a valid encoding of a length-type value of `size_of::<L>()` bytes
+    unsafe { core::ptr::read_unaligned(b.as_ptr() as *const L) }
+}
+
+/// Decodes a length from the raw bytes of a length-type value.
+///
+/// Returns `None` if the bytes don't hold a valid value, i.e. zero of a niche-optimized
+/// length type.
+#[cfg(all(
+    feature = "bytemuck",
+    any(feature = "arrayvec", feature = "arraystring")
+))]
+#[inline]
+pub(crate) fn len_from_raw_bytes<L: LengthType>(b: &[u8]) -> Option<usize> {
+    let n = core::mem::size_of::<L>();
+    debug_assert_eq!(b.len(), n);
+    let mut v = 0u64;
+    for i in 0..n {
+        let idx = if L::LE { i } else { n - 1 - i };
+        v |= (b[idx] as u64) << (i * 8);
+    }
+    usize::try_from(v).ok()?.checked_sub(L::BIAS)
+}
+
 // ------------------------------------------------------------------------------------------------
 
 length_type!(
@@ -213,6 +570,107 @@ length_type!(
     usize
 );
 
+nz_length_type!(
+    /// Niche-optimized length-type with underlying `u8`.
+    ///
+    /// Supports collections with capacity of up to `u8::MAX - 1` elements.
+    U8NZ,
+    NonZeroU8,
+    u8
+);
+
+nz_length_type!(
+    /// Niche-optimized length-type with underlying `u16`.
+    ///
+    /// Supports collections with capacity of up to `u16::MAX - 1` elements.
+    U16NZ,
+    NonZeroU16,
+    u16
+);
+
+nz_length_type!(
+    /// Niche-optimized length-type with underlying `u32`.
+    ///
+    /// Supports collections with capacity of up to `u32::MAX - 1` elements.
+    U32NZ,
+    NonZeroU32,
+    u32
+);
+
+nz_length_type!(
+    /// Niche-optimized length-type with underlying `u64`.
+    ///
+    /// Supports collections with capacity of up to `u64::MAX - 1` elements.
+    U64NZ,
+    NonZeroU64,
+    u64
+);
+
+nz_length_type!(
+    /// Niche-optimized length-type with underlying `usize`.
+    ///
+    /// Supports collections with capacity of up to `usize::MAX - 1` elements.
+    UsizeNZ,
+    NonZeroUsize,
+    usize
+);
+
+packed_length_type!(
+    /// Packed length-type with underlying 24-bit little-endian integer.
+    ///
+    /// Occupies 3 bytes with alignment of 1, and supports collections with capacity of up to
+    /// `2 ^ 24 - 1` elements.
+    U24,
+    3,
+    u32
+);
+
+packed_length_type!(
+    @cfg(target_pointer_width = "64")
+    /// Packed length-type with underlying 40-bit little-endian integer.
+    ///
+    /// Occupies 5 bytes with alignment of 1, and supports collections with capacity of up to
+    /// `2 ^ 40 - 1` elements.
+    ///
+    /// Available on 64-bit targets only (`target_pointer_width = "64"`).
+    #[cfg_attr(docsrs, doc(cfg(target_pointer_width = "64")))]
+    U40,
+    5,
+    u64
+);
+
+packed_length_type!(
+    @cfg(target_pointer_width = "64")
+    /// Packed length-type with underlying 48-bit little-endian integer.
+    ///
+    /// Occupies 6 bytes with alignment of 1, and supports collections with capacity of up to
+    /// `2 ^ 48 - 1` elements.
+    ///
+    /// Available on 64-bit targets only (`target_pointer_width = "64"`).
+    #[cfg_attr(docsrs, doc(cfg(target_pointer_width = "64")))]
+    U48,
+    6,
+    u64
+);
+
+// SAFETY: zero is a valid value of length types which are not niche-optimized
+#[cfg(feature = "bytemuck")]
+mod zeroable {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    unsafe impl Zeroable for U8 {}
+    unsafe impl Zeroable for U16 {}
+    unsafe impl Zeroable for U32 {}
+    unsafe impl Zeroable for U64 {}
+    unsafe impl Zeroable for Usize {}
+    unsafe impl Zeroable for U24 {}
+    #[cfg(target_pointer_width = "64")]
+    unsafe impl Zeroable for U40 {}
+    #[cfg(target_pointer_width = "64")]
+    unsafe impl Zeroable for U48 {}
+}
+
 // ------------------------------------------------------------------------------------------------
 
 #[cfg(all(test, feature = "std"))]
@@ -235,4 +693,257 @@ mod testing {
         check_display!(U64, 7070707);
         check_display!(Usize, 707070707);
     }
+
+    macro_rules! check_nz_display {
+        ($LT:ty, $C:literal) => {
+            let lt = <$LT>::new($C);
+            let s = format!("{}", lt);
+            assert_eq!(s, $C.to_string());
+        };
+    }
+
+    #[test]
+    fn test_nz_display() {
+        check_nz_display!(U8NZ, 0);
+        check_nz_display!(U8NZ, 254);
+        check_nz_display!(U16NZ, 707);
+        check_nz_display!(U32NZ, 70707);
+        check_nz_display!(U64NZ, 7070707);
+        check_nz_display!(UsizeNZ, 707070707);
+    }
+
+    #[test]
+    fn test_nz_debug() {
+        assert_eq!(format!("{:?}", U8NZ::new(0)), "U8NZ(0)");
+        assert_eq!(format!("{:?}", U16NZ::new(7)), "U16NZ(7)");
+    }
+
+    #[test]
+    fn test_nz_max() {
+        assert_eq!(U8NZ::MAX, 254);
+        assert_eq!(U16NZ::MAX, 65534);
+        assert_eq!(U32NZ::MAX, u32::MAX as usize - 1);
+        assert_eq!(U64NZ::MAX, u64::MAX as usize - 1);
+        assert_eq!(UsizeNZ::MAX, usize::MAX - 1);
+    }
+
+    #[test]
+    fn test_nz_niche() {
+        use core::mem::size_of;
+        assert_eq!(size_of::<Option<U8NZ>>(), size_of::<u8>());
+        assert_eq!(size_of::<Option<U16NZ>>(), size_of::<u16>());
+        assert_eq!(size_of::<Option<U32NZ>>(), size_of::<u32>());
+        assert_eq!(size_of::<Option<U64NZ>>(), size_of::<u64>());
+        assert_eq!(size_of::<Option<UsizeNZ>>(), size_of::<usize>());
+    }
+
+    #[test]
+    fn test_nz_arithmetic() {
+        let mut l = U8NZ::new(0);
+        assert_eq!(l, 0);
+        l += 10;
+        assert_eq!(l, 10);
+        l -= 3;
+        assert_eq!(l, 7);
+        assert!(l < 8);
+        assert!(l > 6);
+        l.set(254);
+        assert_eq!(l.as_usize(), 254);
+        assert!(U8NZ::new(3) < U8NZ::new(4));
+    }
+
+    #[test]
+    fn test_nz_checked_add_usize() {
+        let l = U8NZ::new(250);
+        assert_eq!(l.checked_add_usize(4), Some(U8NZ::new(254)));
+        assert_eq!(l.checked_add_usize(5), None);
+        assert_eq!(l.checked_add_usize(256), None);
+        assert_eq!(U8NZ::new(0).checked_add_usize(0), Some(U8NZ::new(0)));
+    }
+
+    #[test]
+    fn test_nz_next_power_of_two_or_max() {
+        assert_eq!(U8NZ::new(0).next_power_of_two_or_max(), 1);
+        assert_eq!(U8NZ::new(5).next_power_of_two_or_max(), 8);
+        assert_eq!(U8NZ::new(128).next_power_of_two_or_max(), 128);
+        assert_eq!(U8NZ::new(129).next_power_of_two_or_max(), 254);
+        assert_eq!(U8NZ::new(254).next_power_of_two_or_max(), 254);
+        assert_eq!(U16NZ::new(40000).next_power_of_two_or_max(), 65534);
+    }
+
+    macro_rules! check_packed_display {
+        ($LT:ty, $C:expr) => {
+            let lt = <$LT>::new($C);
+            let s = format!("{}", lt);
+            assert_eq!(s, $C.to_string());
+        };
+    }
+
+    #[test]
+    fn test_packed_display() {
+        check_packed_display!(U24, 0);
+        check_packed_display!(U24, 16777215);
+        assert_eq!(format!("{:?}", U24::new(7)), "U24(7)");
+        #[cfg(target_pointer_width = "64")]
+        {
+            check_packed_display!(U40, 1099511627775_usize);
+            check_packed_display!(U48, 281474976710655_usize);
+        }
+    }
+
+    #[test]
+    fn test_packed_layout() {
+        use core::mem::{align_of, size_of};
+        assert_eq!(size_of::<U24>(), 3);
+        assert_eq!(align_of::<U24>(), 1);
+        assert_eq!(U24::MAX, (1 << 24) - 1);
+
+        // little-endian byte order
+        assert_eq!(U24::new(0x030201).0, [1, 2, 3]);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(size_of::<U40>(), 5);
+            assert_eq!(size_of::<U48>(), 6);
+            assert_eq!(align_of::<U40>(), 1);
+            assert_eq!(align_of::<U48>(), 1);
+            assert_eq!(U40::MAX, (1 << 40) - 1);
+            assert_eq!(U48::MAX, (1 << 48) - 1);
+            assert_eq!(U48::new(0x060504030201).0, [1, 2, 3, 4, 5, 6]);
+        }
+    }
+
+    #[test]
+    fn test_packed_arithmetic() {
+        let mut l = U24::new(0);
+        l += 0x10000;
+        assert_eq!(l, 0x10000);
+        l -= 1;
+        assert_eq!(l, 0xFFFF);
+        l.set(U24::MAX);
+        assert_eq!(l.as_usize(), U24::MAX);
+        assert!(l > 0xFFFF);
+        assert!(U24::new(0x0100) > U24::new(0xFF));
+        #[cfg(target_pointer_width = "64")]
+        assert!(U40::new(0x01_0000_0000) > U40::new(0xFFFF_FFFF));
+    }
+
+    #[test]
+    fn test_packed_checked_add_usize() {
+        let l = U24::new(U24::MAX - 5);
+        assert_eq!(l.checked_add_usize(5), Some(U24::new(U24::MAX)));
+        assert_eq!(l.checked_add_usize(6), None);
+        assert_eq!(l.checked_add_usize(usize::MAX), None);
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(
+                U48::new(0).checked_add_usize(U48::MAX),
+                Some(U48::new(U48::MAX))
+            );
+            assert_eq!(U48::new(1).checked_add_usize(U48::MAX), None);
+        }
+    }
+
+    #[test]
+    fn test_packed_next_power_of_two_or_max() {
+        assert_eq!(U24::new(0).next_power_of_two_or_max(), 1);
+        assert_eq!(U24::new(300).next_power_of_two_or_max(), 512);
+        assert_eq!(U24::new(1 << 23).next_power_of_two_or_max(), 1 << 23);
+        assert_eq!(U24::new((1 << 23) + 1).next_power_of_two_or_max(), U24::MAX);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(U40::new(5).next_power_of_two_or_max(), 8);
+    }
+
+    #[test]
+    fn test_bias_and_byte_order() {
+        fn check<L: LengthType>(bias: usize, le: bool) {
+            assert_eq!(L::BIAS, bias);
+            assert_eq!(L::LE, le);
+        }
+        let native_le = cfg!(target_endian = "little");
+        check::<U8>(0, native_le);
+        check::<U16>(0, native_le);
+        check::<U32>(0, native_le);
+        check::<U64>(0, native_le);
+        check::<Usize>(0, native_le);
+        check::<U8NZ>(1, native_le);
+        check::<U16NZ>(1, native_le);
+        check::<U32NZ>(1, native_le);
+        check::<U64NZ>(1, native_le);
+        check::<UsizeNZ>(1, native_le);
+        check::<U24>(0, true);
+        #[cfg(target_pointer_width = "64")]
+        {
+            check::<U40>(0, true);
+            check::<U48>(0, true);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "arrayvec", feature = "arraystring"))]
+    fn test_const_conversions() {
+        macro_rules! check_const {
+            ($LT:ty, $($V:expr),+) => {
+                $(
+                    let l: $LT = const_new($V);
+                    assert_eq!(l, <$LT>::new($V));
+                    assert_eq!(const_as_usize(&<$LT>::new($V)), $V);
+                )+
+            };
+        }
+        check_const!(U8, 0, 1, 0xAB, U8::MAX);
+        check_const!(U16, 0, 0x1234, U16::MAX);
+        check_const!(U32, 0, 0x12345678, U32::MAX);
+        check_const!(Usize, 0, 0x12345678, Usize::MAX);
+        check_const!(U8NZ, 0, 0xAB, U8NZ::MAX);
+        check_const!(U16NZ, 0, 0x1234, U16NZ::MAX);
+        check_const!(UsizeNZ, 0, 0x12345678, UsizeNZ::MAX);
+        check_const!(U64, 0, 0x12345678, U64::MAX);
+        check_const!(U64NZ, 0, 0x12345678, U64NZ::MAX);
+        check_const!(U24, 0, 0x123456, U24::MAX);
+        #[cfg(target_pointer_width = "64")]
+        {
+            check_const!(U40, 0, 0x1234567890, U40::MAX);
+            check_const!(U48, 0, 0x123456789012, U48::MAX);
+        }
+
+        const L: U16NZ = const_new(300);
+        assert_eq!(L, 300);
+    }
+
+    #[cfg(all(
+        feature = "bytemuck",
+        any(feature = "arrayvec", feature = "arraystring")
+    ))]
+    #[test]
+    fn test_len_from_raw_bytes() {
+        fn raw<L: LengthType>(l: L) -> Vec<u8> {
+            let p = &l as *const L as *const u8;
+            unsafe { core::slice::from_raw_parts(p, core::mem::size_of::<L>()) }.to_vec()
+        }
+
+        assert_eq!(len_from_raw_bytes::<U8>(&raw(U8::new(7))), Some(7));
+        assert_eq!(
+            len_from_raw_bytes::<U16>(&raw(U16::new(0x1234))),
+            Some(0x1234)
+        );
+        assert_eq!(
+            len_from_raw_bytes::<U24>(&raw(U24::new(0x12_3456))),
+            Some(0x12_3456)
+        );
+        assert_eq!(len_from_raw_bytes::<U8NZ>(&raw(U8NZ::new(0))), Some(0));
+        assert_eq!(len_from_raw_bytes::<U32NZ>(&raw(U32NZ::new(17))), Some(17));
+        assert_eq!(len_from_raw_bytes::<U8NZ>(&[0]), None);
+        assert_eq!(
+            len_from_raw_bytes::<UsizeNZ>(&[0; core::mem::size_of::<usize>()]),
+            None
+        );
+
+        // a `U64` length that doesn't fit in `usize` is rejected rather than truncated
+        let big = (1u64 << 32).to_ne_bytes();
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(len_from_raw_bytes::<U64>(&big), Some(1 << 32));
+        #[cfg(not(target_pointer_width = "64"))]
+        assert_eq!(len_from_raw_bytes::<U64>(&big), None);
+    }
 }
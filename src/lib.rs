@@ -20,6 +20,7 @@
 //!
 //! * [`ArrayVec`] - a vector-like array
 //! * [`ArrayString`] - a string-like array
+//! * [`ArrayCString`] - a NUL-terminated string-like array, for passing strings to C APIs
 //!
 //!
 //! # Hybrid-Capacity Data Structures
@@ -40,18 +41,50 @@
 //!   in [`core`]. Without this feature the library is [`no_std`].
 //! * `arrayvec` - enables [`ArrayVec`]
 //! * `arraystring` - enables [`ArrayString`]
+//! * `arraycstring` - implies `arrayvec` and enables [`ArrayCString`]
 //! * `smallvec` - implies `alloc` and enables [`SmallVec`]
-//!
-//! By default, all optional features are enabled. To build in `no_std` environment, or to avoid
-//! compilation of unneeded functionality, disable default features and cherry pick the required
-//! features explicitly.
+//! * `encoding` - enables [`encoding`], a compact binary encoding of the collections
+//! * `rkyv` - enables zero-copy archival of [`ArrayVec`] and [`ArrayString`] with [`rkyv`],
+//!   see `ArchivedArrayVec` and `ArchivedArrayString`
+//! * `bytemuck` - enables [`bytemuck`] `Zeroable`, and validated zero-copy `from_bytes` of
+//!   [`ArrayVec`] and [`ArrayString`] with `Zeroed` spare memory policy, and single-call
+//!   `hash_bytes` of [`ArrayVec`] and [`SmallVec`]
+//! * `arbitrary` - implies `std` and enables [`arbitrary`] `Arbitrary` of the collections
+//! * `proptest` - implies `std` and enables [`proptest`] `Arbitrary` of the collections, and
+//!   `strategy`, a module of strategies generating collections with a length in a chosen range
+//! * `defmt` - enables [`defmt`] `Format` of the collections and the error types
+//! * `ufmt` - enables [`ufmt`] `uDisplay` and `uDebug` of the collections, `uWrite` of
+//!   [`ArrayString`], and `ulformat!`, a `ufmt`-based variant of `lformat!`
+//!
+//! By default, all optional features are enabled, except for `arraycstring`, `encoding`, `rkyv`,
+//! `bytemuck`, `arbitrary`, `proptest`, `defmt` and `ufmt`. To build in `no_std` environment, or to
+//! avoid compilation of unneeded functionality, disable default features and cherry pick the
+//! required features explicitly.
 //!
 //! [`ArrayVec`]: crate::arrayvec::ArrayVec
 //! [`ArrayString`]: crate::arraystring::ArrayString
 //! [`SmallVec`]: crate::smallvec::SmallVec
+//! [`rkyv`]: https://docs.rs/rkyv
+//! [`bytemuck`]: https://docs.rs/bytemuck
+//! [`arbitrary`]: https://docs.rs/arbitrary
+//! [`proptest`]: https://docs.rs/proptest
+//! [`defmt`]: https://docs.rs/defmt
+//! [`ufmt`]: https://docs.rs/ufmt
 //! [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
 //!
-
+#![cfg_attr(
+    feature = "arraycstring",
+    doc = "[`ArrayCString`]: crate::arraycstring::ArrayCString"
+)]
+#![cfg_attr(
+    not(feature = "arraycstring"),
+    doc = "[`ArrayCString`]: https://docs.rs/cds/latest/cds/arraycstring/struct.ArrayCString.html"
+)]
+#![cfg_attr(feature = "encoding", doc = "[`encoding`]: crate::encoding")]
+#![cfg_attr(
+    not(feature = "encoding"),
+    doc = "[`encoding`]: https://docs.rs/cds/latest/cds/encoding/index.html"
+)]
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -67,10 +100,22 @@ pub mod arrayvec;
 #[cfg_attr(docsrs, doc(cfg(feature = "arraystring")))]
 pub mod arraystring;
 
+#[cfg(feature = "arraycstring")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arraycstring")))]
+pub mod arraycstring;
+
 #[cfg(feature = "smallvec")]
 #[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
 pub mod smallvec;
 
+#[cfg(feature = "encoding")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encoding")))]
+pub mod encoding;
+
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod strategy;
+
 pub mod len;
 pub mod mem;
 
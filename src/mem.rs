@@ -3,6 +3,12 @@
 mod policy;
 pub use policy::*;
 
+#[cfg(feature = "smallvec")]
+mod growth;
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+pub use growth::*;
+
 #[cfg(feature = "smallvec")]
 pub(crate) mod alloc;
 
@@ -5,6 +5,8 @@ use core::{
 
 /// An error returned when capacity reservation fails.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum ReservationError {
     /// Collection capacity overflow.
     ///
@@ -24,6 +26,16 @@ pub enum ReservationError {
         /// The layout passed to the underlying allocator.
         layout: Layout,
     },
+
+    /// Spilling to a heap buffer is disabled.
+    ///
+    /// Is returned when a collection's local capacity is insufficient, and its growth policy
+    /// doesn't allow spilling to a heap buffer.
+    ///
+    /// See [`GrowthPolicy::SPILL`] for more information.
+    ///
+    /// [`GrowthPolicy::SPILL`]: crate::mem::GrowthPolicy::SPILL
+    SpillDisabled,
 }
 
 impl Display for ReservationError {
@@ -40,6 +52,9 @@ impl Display for ReservationError {
                     layout.align()
                 )
             }
+            ReservationError::SpillDisabled => {
+                write!(f, "memory reservation error: spill disabled")
+            }
         }
     }
 }
@@ -62,6 +77,9 @@ mod testing {
         assert_eq!(
             e.to_string(),
             "memory reservation error: alloc error. layout { size: 100, align: 8 }"
-        )
+        );
+
+        let e = ReservationError::SpillDisabled;
+        assert_eq!(e.to_string(), "memory reservation error: spill disabled");
     }
 }
@@ -0,0 +1,356 @@
+use core::{
+    any::type_name,
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+};
+
+/// A trait of custom growth policies.
+///
+/// A growth policy defines the capacity a growable *cds* collection (e.g. `SmallVec`) requests
+/// when its current capacity is insufficient, and the collection needs to (re)allocate a heap
+/// buffer.
+///
+/// Growth policy affects only the methods that may reserve more memory than strictly necessary,
+/// e.g. `reserve`, `push`, `insert` and `extend`. Methods like `reserve_exact` always request the
+/// minimal capacity required.
+///
+/// Currently the following policies are supported:
+///
+/// - [`PowerOfTwo`] grows to the next power of two of the required capacity (the default)
+/// - [`OneAndHalf`] grows by a factor of 1.5 of the current capacity
+/// - [`Exact`] grows to the exact required capacity
+/// - [`Chunked`] grows to the next multiple of a fixed number of elements
+/// - [`NoSpill`] never grows, and handles overflow of the local capacity with an [`OverflowHook`]
+///
+/// The capacity returned by a growth policy is clamped by the collection to the maximal value
+/// supported by its length type.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "smallvec")] {
+/// use cds::{
+///     len::U8,
+///     mem::{Chunked, Exact, OneAndHalf, PowerOfTwo, Uninitialized},
+///     smallvec::SmallVec,
+/// };
+///
+/// let mut v = SmallVec::<u64, 2, U8, Uninitialized, PowerOfTwo>::new();
+/// (0..5).for_each(|e| v.push(e));
+/// assert_eq!(v.capacity(), 8);
+///
+/// let mut v = SmallVec::<u64, 2, U8, Uninitialized, OneAndHalf>::new();
+/// (0..5).for_each(|e| v.push(e));
+/// assert_eq!(v.capacity(), 6);
+///
+/// let mut v = SmallVec::<u64, 2, U8, Uninitialized, Exact>::new();
+/// (0..5).for_each(|e| v.push(e));
+/// assert_eq!(v.capacity(), 5);
+///
+/// let mut v = SmallVec::<u64, 2, U8, Uninitialized, Chunked<16>>::new();
+/// (0..5).for_each(|e| v.push(e));
+/// assert_eq!(v.capacity(), 16);
+/// # }
+/// ```
+pub trait GrowthPolicy {
+    /// Returns the capacity to grow a buffer of capacity `capacity` to, so that it can hold
+    /// at least `required` elements.
+    ///
+    /// `required` is always greater than `capacity`. A value lower than `required` is treated as
+    /// `required`.
+    fn grow(capacity: usize, required: usize) -> usize;
+
+    /// Specifies whether a collection may spill its elements to a heap buffer.
+    ///
+    /// When `false`, the collection never allocates, and capacity reservation beyond the local
+    /// capacity fails with [`ReservationError::SpillDisabled`]. In that case [`grow`] is never
+    /// called, and [`overflow`] decides how to handle an element that doesn't fit.
+    ///
+    /// The default is `true`.
+    ///
+    /// [`grow`]: GrowthPolicy::grow
+    /// [`overflow`]: GrowthPolicy::overflow
+    /// [`ReservationError::SpillDisabled`]: super::errors::ReservationError::SpillDisabled
+    const SPILL: bool = true;
+
+    /// Returns the action to take when an element is appended to a collection with a full local
+    /// buffer of capacity `capacity`, and [`SPILL`] is `false`.
+    ///
+    /// The default is [`Overflow::Reject`].
+    ///
+    /// [`SPILL`]: GrowthPolicy::SPILL
+    #[inline]
+    fn overflow(capacity: usize) -> Overflow {
+        let _ = capacity;
+        Overflow::Reject
+    }
+}
+
+/// An action taken when an element doesn't fit in a collection which doesn't spill to heap.
+///
+/// See [`NoSpill`] and [`OverflowHook`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Overflow {
+    /// The element is rejected, and the operation fails with
+    /// [`ReservationError::SpillDisabled`].
+    ///
+    /// [`ReservationError::SpillDisabled`]: super::errors::ReservationError::SpillDisabled
+    Reject,
+
+    /// The element is dropped, and the operation succeeds.
+    Discard,
+
+    /// The first element of the collection is dropped, the rest are shifted to the front, and
+    /// the element is appended. The collection keeps its most recent elements.
+    Overwrite,
+}
+
+/// A user hook called when an element doesn't fit in the local buffer of a [`NoSpill`]
+/// collection.
+///
+/// The hook decides the [`Overflow`] action, and may have side effects, e.g. counting the dropped
+/// elements. It must not append to the collection that overflows.
+///
+/// Currently the following hooks are provided:
+///
+/// - [`Reject`] rejects the element with an error (the default)
+/// - [`Discard`] drops the element
+/// - [`Overwrite`] drops the first element and appends the new one
+pub trait OverflowHook {
+    /// Returns the action to take when an element doesn't fit in a local buffer of capacity
+    /// `capacity`.
+    fn overflow(capacity: usize) -> Overflow;
+}
+
+/// Overflow hook which rejects the element. See [`Overflow::Reject`].
+#[derive(Debug)]
+pub struct Reject;
+
+/// Overflow hook which drops the element. See [`Overflow::Discard`].
+#[derive(Debug)]
+pub struct Discard;
+
+/// Overflow hook which overwrites the first element. See [`Overflow::Overwrite`].
+#[derive(Debug)]
+pub struct Overwrite;
+
+impl OverflowHook for Reject {
+    #[inline]
+    fn overflow(_capacity: usize) -> Overflow {
+        Overflow::Reject
+    }
+}
+
+impl OverflowHook for Discard {
+    #[inline]
+    fn overflow(_capacity: usize) -> Overflow {
+        Overflow::Discard
+    }
+}
+
+impl OverflowHook for Overwrite {
+    #[inline]
+    fn overflow(_capacity: usize) -> Overflow {
+        Overflow::Overwrite
+    }
+}
+
+/// Inline-only growth policy.
+///
+/// Written as `NoSpill<H>`, inline-only growth policy never spills to a heap buffer. A
+/// collection using it never allocates, which makes it usable where allocation is not allowed,
+/// e.g. in interrupt handlers, while keeping the API of a growable collection.
+///
+/// Capacity reservation beyond the local capacity fails with
+/// [`ReservationError::SpillDisabled`]. When an element is appended to a full collection, e.g.
+/// with `push` or `extend`, the [`OverflowHook`] `H` decides whether the element is rejected
+/// (the default), discarded, or overwrites the first element.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "smallvec")] {
+/// use cds::{
+///     len::U8,
+///     mem::{errors::ReservationError, Discard, NoSpill, Overwrite, Uninitialized},
+///     smallvec::SmallVec,
+/// };
+///
+/// let mut v = SmallVec::<u64, 2, U8, Uninitialized, NoSpill>::new();
+/// v.push(1);
+/// v.push(2);
+/// assert_eq!(v.try_push(3), Err(ReservationError::SpillDisabled));
+/// assert_eq!(v, [1, 2]);
+///
+/// let mut v = SmallVec::<u64, 2, U8, Uninitialized, NoSpill<Discard>>::new();
+/// v.extend(0..5);
+/// assert_eq!(v, [0, 1]);
+///
+/// let mut v = SmallVec::<u64, 2, U8, Uninitialized, NoSpill<Overwrite>>::new();
+/// v.extend(0..5);
+/// assert_eq!(v, [3, 4]);
+/// assert!(v.is_local());
+/// # }
+/// ```
+///
+/// A user hook may choose the action, and count the overflows:
+///
+/// ```rust
+/// # #[cfg(feature = "smallvec")] {
+/// use cds::{
+///     len::U8,
+///     mem::{NoSpill, Overflow, OverflowHook, Uninitialized},
+///     smallvec::SmallVec,
+/// };
+/// use core::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static DROPPED: AtomicUsize = AtomicUsize::new(0);
+///
+/// struct CountDropped;
+///
+/// impl OverflowHook for CountDropped {
+///     fn overflow(_capacity: usize) -> Overflow {
+///         DROPPED.fetch_add(1, Ordering::Relaxed);
+///         Overflow::Discard
+///     }
+/// }
+///
+/// let mut v = SmallVec::<u8, 4, U8, Uninitialized, NoSpill<CountDropped>>::new();
+/// v.extend(0..10);
+/// assert_eq!(v, [0, 1, 2, 3]);
+/// assert_eq!(DROPPED.load(Ordering::Relaxed), 6);
+/// # }
+/// ```
+///
+/// [`ReservationError::SpillDisabled`]: super::errors::ReservationError::SpillDisabled
+pub struct NoSpill<H = Reject>(PhantomData<H>);
+
+impl<H> Debug for NoSpill<H> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NoSpill<{}>", type_name::<H>())
+    }
+}
+
+/// Power-of-two growth policy.
+///
+/// Grows to the next power of two of the required capacity. This is the default growth policy,
+/// which provides amortized constant time of element insertion.
+#[derive(Debug)]
+pub struct PowerOfTwo;
+
+/// One-and-half growth policy.
+///
+/// Grows by a factor of 1.5 of the current capacity, or to the required capacity if that is
+/// greater. This provides amortized constant time of element insertion, while wasting less memory
+/// than [`PowerOfTwo`] for large buffers.
+#[derive(Debug)]
+pub struct OneAndHalf;
+
+/// Exact growth policy.
+///
+/// Grows to the exact required capacity. This minimizes memory usage, but makes a sequence
+/// of insertions reallocate on every insertion.
+#[derive(Debug)]
+pub struct Exact;
+
+/// Chunked growth policy.
+///
+/// Written as `Chunked<N>`, chunked growth policy grows to the next multiple of `N` of the
+/// required capacity. `Chunked<0>` behaves like [`Exact`].
+#[derive(Debug)]
+pub struct Chunked<const N: usize>;
+
+impl GrowthPolicy for PowerOfTwo {
+    #[inline]
+    fn grow(_capacity: usize, required: usize) -> usize {
+        required.checked_next_power_of_two().unwrap_or(usize::MAX)
+    }
+}
+
+impl GrowthPolicy for OneAndHalf {
+    #[inline]
+    fn grow(capacity: usize, required: usize) -> usize {
+        capacity.saturating_add(capacity / 2).max(required)
+    }
+}
+
+impl GrowthPolicy for Exact {
+    #[inline]
+    fn grow(_capacity: usize, required: usize) -> usize {
+        required
+    }
+}
+
+impl<H: OverflowHook> GrowthPolicy for NoSpill<H> {
+    const SPILL: bool = false;
+
+    #[inline]
+    fn grow(_capacity: usize, required: usize) -> usize {
+        required
+    }
+
+    #[inline]
+    fn overflow(capacity: usize) -> Overflow {
+        H::overflow(capacity)
+    }
+}
+
+impl<const N: usize> GrowthPolicy for Chunked<N> {
+    #[inline]
+    fn grow(_capacity: usize, required: usize) -> usize {
+        if N == 0 {
+            return required;
+        }
+        match required % N {
+            0 => required,
+            r => required.saturating_add(N - r),
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn test_power_of_two() {
+        assert_eq!(PowerOfTwo::grow(0, 1), 1);
+        assert_eq!(PowerOfTwo::grow(4, 5), 8);
+        assert_eq!(PowerOfTwo::grow(4, 8), 8);
+        assert_eq!(PowerOfTwo::grow(4, usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn test_one_and_half() {
+        assert_eq!(OneAndHalf::grow(0, 1), 1);
+        assert_eq!(OneAndHalf::grow(4, 5), 6);
+        assert_eq!(OneAndHalf::grow(4, 10), 10);
+        assert_eq!(OneAndHalf::grow(100, 101), 150);
+        assert_eq!(OneAndHalf::grow(usize::MAX - 1, usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn test_exact() {
+        assert_eq!(Exact::grow(0, 1), 1);
+        assert_eq!(Exact::grow(4, 5), 5);
+    }
+
+    #[test]
+    fn test_chunked() {
+        assert_eq!(Chunked::<16>::grow(0, 1), 16);
+        assert_eq!(Chunked::<16>::grow(16, 17), 32);
+        assert_eq!(Chunked::<16>::grow(16, 32), 32);
+        assert_eq!(Chunked::<16>::grow(16, usize::MAX), usize::MAX);
+        assert_eq!(Chunked::<0>::grow(4, 5), 5);
+    }
+
+    #[test]
+    fn test_no_spill() {
+        const { assert!(PowerOfTwo::SPILL) };
+        assert_eq!(PowerOfTwo::overflow(4), Overflow::Reject);
+        const { assert!(!NoSpill::<Reject>::SPILL) };
+        assert_eq!(NoSpill::<Reject>::overflow(4), Overflow::Reject);
+        assert_eq!(NoSpill::<Discard>::overflow(4), Overflow::Discard);
+        assert_eq!(NoSpill::<Overwrite>::overflow(4), Overflow::Overwrite);
+    }
+}
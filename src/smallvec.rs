@@ -5,7 +5,7 @@ use crate::{
     mem::{
         alloc::{alloc_buffer, realloc_buffer, DOHAE, NOHAE},
         errors::ReservationError,
-        SpareMemoryPolicy, Uninitialized,
+        GrowthPolicy, Overflow, PowerOfTwo, SpareMemoryPolicy, Uninitialized,
     },
 };
 
@@ -24,9 +24,9 @@ use retain::RetainGuard;
 
 /// A continuous growable array with "small size" optimization.
 ///
-/// Written as `SmallVec<T, C, L, SM>`, small vector stores elements of type `T`, has local
+/// Written as `SmallVec<T, C, L, SM, G>`, small vector stores elements of type `T`, has local
 /// capacity to store up to `C` elements without allocating a heap buffer, uses `L` as
-/// [`length type`], and `SM` as [`spare memory policy`].
+/// [`length type`], `SM` as [`spare memory policy`], and `G` as [`growth policy`].
 ///
 /// `SmallVec` is mostly compatible with the standard [`Vec`] interface. Moreover, `SmallVec`
 /// provides non-panic versions of various methods that in [`Vec`] may panic. In particular,
@@ -52,8 +52,16 @@ use retain::RetainGuard;
 /// panic. The non-panic methods always return an appropriate `Result`, and [`handle_alloc_error`]
 /// is not called.
 ///
+/// With the [`NoSpill`] growth policy `SmallVec` never spills to a heap buffer, and never
+/// allocates. This allows the same code to run where allocation is not allowed, e.g. in interrupt
+/// handlers. An element appended to a full small-vector is then handled by the policy's
+/// [`OverflowHook`].
+///
 /// [`spare memory policy`]: SpareMemoryPolicy
+/// [`NoSpill`]: crate::mem::NoSpill
+/// [`OverflowHook`]: crate::mem::OverflowHook
 /// [`length type`]: LengthType
+/// [`growth policy`]: GrowthPolicy
 /// [`alloc`]: ::alloc::alloc
 /// [`realloc`]: ::alloc::alloc::realloc
 /// [`Allocator`]: ::alloc::alloc::Allocator
@@ -64,7 +72,7 @@ use retain::RetainGuard;
 /// ```rust
 /// # use cds::{smallvec::SmallVec, len::U8};
 /// let mut v = SmallVec::<u64, 4, U8>::new();
-/// assert_eq!(v, []);
+/// assert_eq!(v, [] as [u64; 0]);
 /// assert!(v.is_local());
 ///
 /// v.push(1);
@@ -84,10 +92,11 @@ use retain::RetainGuard;
 ///     println!("{}", e);
 /// }
 /// ```
-pub struct SmallVec<T, const C: usize, L = Usize, SM = Uninitialized>
+pub struct SmallVec<T, const C: usize, L = Usize, SM = Uninitialized, G = PowerOfTwo>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     /// The buffer
     buf: buffer::Buffer<T, C, L, SM>,
@@ -95,13 +104,14 @@ where
     /// The length of small-vec when local; the capacity of the buffer when on heap
     capacity: L,
 
-    phantom: PhantomData<T>,
+    phantom: PhantomData<(T, G)>,
 }
 
-impl<T, const C: usize, L, SM> SmallVec<T, C, L, SM>
+impl<T, const C: usize, L, SM, G> SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     /// Checks if small-vector uses a heap buffer.
     ///
@@ -137,10 +147,16 @@ where
         (mem::size_of::<T>() == 0) || (self.capacity.as_usize() <= C)
     }
 
+    // Fails compilation of any `SmallVec` type whose local capacity exceeds `L::MAX`
+    const CAPACITY_CHECK: () = assert!(C <= L::MAX, "SmallVec local capacity exceeds L::MAX");
+
     /// Creates a new empty small-vector.
     ///
     /// Small-vector doesn't allocate until required capacity exceeds `C`.
     ///
+    /// Local capacity `C` may not exceed the maximal value that can be stored in
+    /// length type `L`. This is verified at compile time.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -149,8 +165,28 @@ where
     /// assert!(sv.is_empty());
     /// assert_eq!(sv.capacity(), 5);
     /// ```
+    ///
+    /// A `SmallVec` whose local capacity exceeds `L::MAX` fails to compile:
+    ///
+    /// ```compile_fail
+    /// # #[cfg(feature = "smallvec")] {
+    /// # use cds::{smallvec::SmallVec, len::U8};
+    /// let sv = SmallVec::<u8, 256, U8>::new(); // U8::MAX(255) < C(256)
+    /// # }
+    /// ```
+    ///
+    /// The same holds at the niche boundary of niche-optimized length types:
+    ///
+    /// ```compile_fail
+    /// # #[cfg(feature = "smallvec")] {
+    /// # use cds::{smallvec::SmallVec, len::U8NZ};
+    /// let sv = SmallVec::<u8, 255, U8NZ>::new(); // U8NZ::MAX(254) < C(255)
+    /// # }
+    /// ```
     #[inline]
     pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_CHECK;
         Self {
             buf: buffer::Buffer::new(),
             capacity: L::new(0),
@@ -622,10 +658,13 @@ where
         &mut self,
         additional: usize,
     ) -> Result<(&mut L, *mut T), ReservationError> {
+        let cap = self.capacity();
         self.reserve_core::<_, HAE>(additional, |l, a| {
-            Ok(l.checked_add_usize(a)
+            let required = l
+                .checked_add_usize(a)
                 .ok_or(ReservationError::CapacityOverflow)?
-                .next_power_of_two_or_max())
+                .as_usize();
+            Ok(L::new(G::grow(cap, required).clamp(required, L::MAX)))
         })
     }
 
@@ -697,6 +736,9 @@ where
             if cap - len >= additional {
                 return Ok((&mut self.capacity, self.buf.local_mut_ptr()));
             }
+            if !G::SPILL {
+                return Err(ReservationError::SpillDisabled);
+            }
 
             let new_cap = nc(self.capacity, additional)?;
             debug_assert!(new_cap > C);
@@ -754,7 +796,7 @@ where
     /// ```rust
     /// # use cds::small_vec;
     /// let mut v = small_vec![2; u64];
-    /// assert_eq!(v, []);
+    /// assert_eq!(v, [] as [u64; 0]);
     /// v.push(1);
     /// v.push(2);
     /// assert_eq!(v, [1, 2]);
@@ -778,7 +820,7 @@ where
     /// # use cds::{small_vec, mem::errors::ReservationError};
     /// # fn foo() -> Result<(), ReservationError> {
     /// let mut v = small_vec![2; u64];
-    /// assert_eq!(v, []);
+    /// assert_eq!(v, [] as [u64; 0]);
     /// v.try_push(10)?;
     /// v.try_push(20)?;
     /// assert_eq!(v, [10, 20]);
@@ -820,7 +862,9 @@ where
                 p = self.buf.heap_mut_ptr();
                 self.buf.heap_len_add_assign(1);
             } else {
-                self.try_reserve_impl::<HAE>(1)?;
+                if let Err(err) = self.try_reserve_impl::<HAE>(1) {
+                    return self.overflow(e, err);
+                }
                 p = self.buf.heap_mut_ptr();
                 len = self.buf.heap_len().as_usize();
                 self.buf.heap_len_add_assign(1);
@@ -832,6 +876,33 @@ where
         }
     }
 
+    // Handles an element that doesn't fit in a full local buffer, when the growth policy doesn't
+    // allow spilling to heap. Other reservation errors are returned as is.
+    #[cold]
+    fn overflow(&mut self, e: T, err: ReservationError) -> Result<(), ReservationError> {
+        if G::SPILL || err != ReservationError::SpillDisabled {
+            return Err(err);
+        }
+        debug_assert_eq!(self.capacity.as_usize(), C);
+        match G::overflow(C) {
+            Overflow::Reject => Err(err),
+            Overflow::Discard => Ok(()),
+            Overflow::Overwrite => {
+                if C > 0 {
+                    unsafe {
+                        let p = self.buf.local_mut_ptr();
+                        let first = p.read();
+                        ptr::copy(p.add(1), p, C - 1);
+                        p.add(C - 1).write(e);
+                        // drop the overwritten element after the small-vector is consistent
+                        drop(first);
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
     /// Removes the last element from a small-vector and returns it, or [`None`] if it is empty.
     ///
     /// # Examples
@@ -842,7 +913,7 @@ where
     /// assert_eq!(v.pop(), Some(13));
     /// assert_eq!(v.pop(), Some(10));
     /// assert_eq!(v.pop(), None);
-    /// assert_eq!(v, []);
+    /// assert_eq!(v, [] as [i32; 0]);
     /// ```
     #[inline]
     #[allow(clippy::comparison_chain)]
@@ -943,7 +1014,7 @@ where
     /// let mut v = small_vec![16; 1, 2, 3];
     /// assert_eq!(v, [1, 2, 3]);
     /// v.clear();
-    /// assert_eq!(v, []);
+    /// assert_eq!(v, [] as [i32; 0]);
     /// ```
     #[inline]
     pub fn clear(&mut self) {
@@ -987,6 +1058,49 @@ where
         Ok(tmp)
     }
 
+    /// Tries to extend the small-vector with the contents of an iterator.
+    ///
+    /// This is a non-panic version of [`extend`].
+    ///
+    /// Returns [`ReservationError`] if capacity reservation fails. In that case the small-vector is
+    /// rolled back to its original length, i.e. the elements appended by this call are dropped.
+    /// Note that capacity reserved before the failure is retained.
+    ///
+    /// With a [`NoSpill`] growth policy, elements that don't fit in the local capacity are handled
+    /// by its [`OverflowHook`] and don't fail this method. In particular, elements shifted out by
+    /// [`Overwrite`] are dropped immediately and are not restored by a rollback. As
+    /// [`NoSpill<Overwrite>`] never fails reservation of non-zero-sized elements, a rollback can
+    /// only occur for zero-sized elements exceeding `L::MAX`, which are never shifted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{small_vec, smallvec::SmallVec, len::U8, mem::errors::ReservationError};
+    /// let mut v = small_vec![2; u64; 1];
+    /// assert!(v.try_extend(2..5).is_ok());
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    ///
+    /// let mut v = SmallVec::<u64, 2, U8>::new();
+    /// v.push(1);
+    /// assert!(matches!(v.try_extend(0..255), Err(ReservationError::CapacityOverflow)));
+    /// assert_eq!(v, [1]);
+    /// ```
+    ///
+    /// [`extend`]: SmallVec::extend
+    /// [`NoSpill`]: crate::mem::NoSpill
+    /// [`NoSpill<Overwrite>`]: crate::mem::NoSpill
+    /// [`OverflowHook`]: crate::mem::OverflowHook
+    /// [`Overwrite`]: crate::mem::Overwrite
+    #[inline]
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), ReservationError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let len = self.len();
+        self.try_extend_impl::<I, NOHAE>(iter)
+            .inspect_err(|_| self.truncate(len))
+    }
+
     #[inline]
     fn try_extend_impl<I, const HAE: bool>(&mut self, iter: I) -> Result<(), ReservationError>
     where
@@ -998,7 +1112,9 @@ where
 
         let len = self.len();
         let mut g = SetLenOnDrop::new(self, len);
-        g.sv.try_reserve_impl::<HAE>(cap)?;
+        if G::SPILL {
+            g.sv.try_reserve_impl::<HAE>(cap)?;
+        }
 
         let mut cap = g.sv.capacity();
         let mut p = unsafe { g.sv.as_mut_ptr().add(len) };
@@ -1007,8 +1123,13 @@ where
             unsafe {
                 if g.len >= cap {
                     g.sv.set_len(g.len);
-                    let (_, tmp_p) = g.sv.try_reserve_impl::<HAE>(1)?;
-                    p = tmp_p.add(g.len);
+                    match g.sv.try_reserve_impl::<HAE>(1) {
+                        Ok((_, tmp_p)) => p = tmp_p.add(g.len),
+                        Err(err) => {
+                            g.sv.overflow(e, err)?;
+                            continue;
+                        }
+                    }
                     cap = g.sv.capacity();
                 }
                 p.write(e);
@@ -1424,6 +1545,296 @@ where
         }
     }
 
+    /// Creates an iterator which uses a predicate to determine if an element in the specified
+    /// range should be removed.
+    ///
+    /// If the predicate returns `true`, the element is removed from the small-vector and yielded
+    /// by the iterator. If the predicate returns `false`, the element remains in the small-vector.
+    /// The predicate receives a mutable reference to every element, which allows modifying
+    /// the retained elements as well. Elements outside of `range` are never examined.
+    ///
+    /// When the iterator is dropped, the remaining elements are shifted to preserve
+    /// the small-vector's contiguity, and the freed slots are handled by the spare memory policy.
+    /// Elements in the range that were not examined, because the iterator was not fully consumed,
+    /// are retained. This holds even if the predicate panics.
+    ///
+    /// If the iterator is not dropped (with [`mem::forget`] for example), it is unspecified how
+    /// many elements are removed.
+    ///
+    /// Note that this method never reallocates the small-vector, even if it becomes small enough
+    /// to fit in the local buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the small-vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{small_vec, smallvec::SmallVec};
+    /// let mut v = small_vec![4; 1, 2, 3, 4, 5, 6, 7, 8];
+    /// let evens = v.extract_if(.., |e| *e % 2 == 0).collect::<SmallVec<_, 4>>();
+    /// assert_eq!(evens, [2, 4, 6, 8]);
+    /// assert_eq!(v, [1, 3, 5, 7]);
+    ///
+    /// let big = v.extract_if(1..3, |e| *e > 2).collect::<SmallVec<_, 4>>();
+    /// assert_eq!(big, [3, 5]);
+    /// assert_eq!(v, [1, 7]);
+    /// ```
+    ///
+    /// [`mem::forget`]: core::mem::forget
+    #[inline]
+    pub fn extract_if<R, F>(&mut self, range: R, pred: F) -> ExtractIf<'_, T, L, SM, F, C, G>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut T) -> bool,
+    {
+        let old_len = self.len();
+
+        let end = match range.end_bound() {
+            Bound::Included(e) => e
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("end bound overflows")),
+            Bound::Excluded(e) => *e,
+            Bound::Unbounded => old_len,
+        };
+
+        if end > old_len {
+            panic!("invalid end bound");
+        }
+
+        let start = match range.start_bound() {
+            Bound::Included(s) => *s,
+            Bound::Excluded(s) => s
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("start bound overflows")),
+            Bound::Unbounded => 0,
+        };
+
+        if start > end {
+            panic!("invalid range");
+        }
+
+        let p = if mem::size_of::<T>() == 0 || self.capacity.as_usize() <= C {
+            self.buf.local_mut_ptr()
+        } else {
+            self.buf.heap_mut_ptr()
+        };
+
+        // set `len` to reflect the head only, to avoid double-drop of extracted elements
+        // if the iterator is leaked. `len` is restored by `ExtractIf`.
+        unsafe { self.set_len(start) };
+
+        ExtractIf {
+            sv: self,
+            p,
+            idx: start,
+            end,
+            del: 0,
+            old_len,
+            pred,
+        }
+    }
+
+    /// Inserts an element into a sorted small-vector, keeping it sorted, and returns the index
+    /// the element was inserted at.
+    ///
+    /// The element is inserted after any equal elements, so that the order of insertion of equal
+    /// elements is preserved. The small-vector must be sorted in ascending order, otherwise the
+    /// insertion position is unspecified.
+    ///
+    /// This method uses binary search to find the insertion position, but still has a worst-case
+    /// performance of O(n), as elements may be shifted right.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if capacity reservation fails. See [`reserve`] for more information.
+    ///
+    /// See [`try_insert_sorted_val`] for a method that returns the element back instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::small_vec;
+    /// let mut v = small_vec![2; 1, 3, 5];
+    /// assert_eq!(v.insert_sorted(4), 2);
+    /// assert_eq!(v.insert_sorted(0), 0);
+    /// assert_eq!(v, [0, 1, 3, 4, 5]);
+    /// ```
+    ///
+    /// [`reserve`]: SmallVec::reserve
+    /// [`try_insert_sorted_val`]: SmallVec::try_insert_sorted_val
+    #[inline]
+    pub fn insert_sorted(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        let index = self.partition_point(|e| *e <= value);
+        self.insert(index, value);
+        index
+    }
+
+    /// Tries to insert an element into a sorted small-vector, keeping it sorted, and returns the
+    /// index the element was inserted at.
+    ///
+    /// Returns [`ReservationErrorVal`] with the element if capacity reservation fails.
+    /// See [`try_reserve`] for more information.
+    ///
+    /// This is a non-panic version of [`insert_sorted`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{len::U8, mem::errors::ReservationError, smallvec::{errors::*, SmallVec}};
+    /// let mut v = SmallVec::<(), 2, U8>::new();
+    /// for _ in 0..255 {
+    ///     assert!(v.try_insert_sorted_val(()).is_ok());
+    /// }
+    /// assert!(matches!(
+    ///     v.try_insert_sorted_val(()),
+    ///     Err(ReservationErrorVal(ReservationError::CapacityOverflow, ()))
+    /// ));
+    /// ```
+    ///
+    /// [`try_reserve`]: SmallVec::try_reserve
+    /// [`insert_sorted`]: SmallVec::insert_sorted
+    #[inline]
+    pub fn try_insert_sorted_val(&mut self, value: T) -> Result<usize, ReservationErrorVal<T>>
+    where
+        T: Ord,
+    {
+        if let Err(e) = self.try_reserve(1) {
+            return Err(ReservationErrorVal(e, value));
+        }
+        let index = self.partition_point(|e| *e <= value);
+        // cannot fail, as the capacity is reserved and the index is in bounds
+        self.insert(index, value);
+        Ok(index)
+    }
+
+    /// Inserts an element into a small-vector sorted by a key extraction function, keeping it
+    /// sorted, and returns the index the element was inserted at.
+    ///
+    /// The element is inserted after any elements with an equal key. The small-vector must be
+    /// sorted by the key in ascending order, otherwise the insertion position is unspecified.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if capacity reservation fails. See [`reserve`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::small_vec;
+    /// let mut v = small_vec![2; (u8, char); (1, 'a'), (3, 'b')];
+    /// assert_eq!(v.insert_sorted_by_key((1, 'c'), |e| e.0), 1);
+    /// assert_eq!(v.insert_sorted_by_key((5, 'd'), |e| e.0), 3);
+    /// assert_eq!(v, [(1, 'a'), (1, 'c'), (3, 'b'), (5, 'd')]);
+    /// ```
+    ///
+    /// [`reserve`]: SmallVec::reserve
+    #[inline]
+    pub fn insert_sorted_by_key<K, F>(&mut self, value: T, mut f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let key = f(&value);
+        let index = self.partition_point(|e| f(e) <= key);
+        self.insert(index, value);
+        index
+    }
+
+    /// Removes an element equal to `value` from a sorted small-vector, and returns it.
+    ///
+    /// Returns `None` if there is no such element. If there are multiple equal elements, any one
+    /// of them may be removed. The small-vector must be sorted in ascending order, otherwise
+    /// the result is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::small_vec;
+    /// let mut v = small_vec![4; 1, 2, 3];
+    /// assert_eq!(v.remove_sorted(&2), Some(2));
+    /// assert_eq!(v.remove_sorted(&2), None);
+    /// assert_eq!(v, [1, 3]);
+    /// ```
+    #[inline]
+    pub fn remove_sorted(&mut self, value: &T) -> Option<T>
+    where
+        T: Ord,
+    {
+        let index = self.binary_search(value).ok()?;
+        self.try_remove(index)
+    }
+
+    /// Removes consecutive repeated elements from a sorted small-vector.
+    ///
+    /// If the small-vector is sorted, this removes all duplicates. The order of the remaining
+    /// elements is preserved, and the freed slots are handled by the spare memory policy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::small_vec;
+    /// let mut v = small_vec![4; 1, 1, 2, 3, 3, 3, 4];
+    /// v.dedup_sorted();
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn dedup_sorted(&mut self)
+    where
+        T: PartialEq,
+    {
+        if self.len() <= 1 {
+            return;
+        }
+
+        let mut g = RetainGuard {
+            sv: self,
+            len: 0,
+            deleted: 0,
+            processed: 0,
+        };
+
+        let cap = g.sv.capacity.as_usize();
+
+        // set `len` to zero, to avoid double-drop of deleted items.
+        // `len` is restored by RetainGuard.
+        let (len, p) = if mem::size_of::<T>() == 0 || cap <= C {
+            g.sv.capacity.set(0);
+            (cap, g.sv.buf.local_mut_ptr())
+        } else {
+            let (l, p) = g.sv.buf.heap_len_mut_p();
+            g.sv.buf.set_heap_len(L::new(0));
+            (l.as_usize(), p)
+        };
+
+        g.len = len;
+        // the first element is always retained
+        g.processed = 1;
+
+        unsafe {
+            while g.processed < len {
+                let cur = p.add(g.processed);
+                let last = p.add(g.processed - g.deleted - 1);
+                if *cur == *last {
+                    // update counters before drop_in_place, as it may panic
+                    g.processed += 1;
+                    g.deleted += 1;
+                    ptr::drop_in_place(cur);
+                } else {
+                    if g.deleted > 0 {
+                        ptr::copy_nonoverlapping(cur, last.add(1), 1);
+                    }
+                    g.processed += 1;
+                }
+            }
+        }
+    }
+
     /// Resizes the small-vector in-place so that `len` is equal to `new_len`.
     ///
     /// If `new_len` is greater than `len`, the small-vector is extended by the difference,
@@ -1480,7 +1891,7 @@ where
     /// # use cds::{small_vec, mem::errors::ReservationError};
     /// # fn foo() -> Result<(), ReservationError> {
     /// let mut v = small_vec![8; u64];
-    /// assert_eq!(v, []);
+    /// assert_eq!(v, [] as [u64; 0]);
     /// v.try_resize_with(5, || 5)?;
     /// assert_eq!(v, [5, 5, 5, 5, 5]);
     /// v.try_resize_with(5, || 0)?;
@@ -1590,7 +2001,7 @@ where
     ///
     /// [`mem::forget`]: core::mem::forget
     #[inline]
-    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, L, SM, C>
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, L, SM, C, G>
     where
         R: RangeBounds<usize>,
     {
@@ -1644,12 +2055,76 @@ where
     }
 }
 
-impl<T, const C: usize, L, SM> SmallVec<T, C, L, SM>
+impl<T, const C: usize, L, SM, G> SmallVec<T, C, L, SM, G>
 where
     T: Clone,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
+    /// Tries to clone the small-vector.
+    ///
+    /// This is a non-panic version of [`clone`].
+    ///
+    /// Returns [`ReservationError`] if reservation of the heap buffer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{small_vec, mem::errors::ReservationError};
+    /// # fn foo() -> Result<(), ReservationError> {
+    /// let v = small_vec![2; u64; 1, 2, 3];
+    /// let w = v.try_clone()?;
+    /// assert_eq!(v, w);
+    /// # Ok(())
+    /// # }
+    /// # foo().unwrap();
+    /// ```
+    ///
+    /// [`clone`]: Clone::clone
+    #[inline]
+    pub fn try_clone(&self) -> Result<Self, ReservationError> {
+        let src = self.as_slice();
+        let src_len = src.len();
+        let mut tmp = Self::new();
+        let (len, p) = if mem::size_of::<T>() == 0 || src_len <= C {
+            (&mut tmp.capacity, tmp.buf.local_mut_ptr())
+        } else {
+            tmp.try_reserve_exact_impl::<NOHAE>(src_len)?
+        };
+        unsafe {
+            clone_from_slice_unchecked(src, len, p);
+        }
+        Ok(tmp)
+    }
+
+    /// Tries to extend the small-vector by cloning elements from a slice.
+    ///
+    /// Returns [`ReservationError`] if capacity reservation fails. In that case the small-vector is
+    /// left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::{small_vec, mem::errors::ReservationError};
+    /// # fn foo() -> Result<(), ReservationError> {
+    /// let mut v = small_vec![2; String; "a".into()];
+    /// v.try_extend_from_slice(&["b".into(), "c".into()])?;
+    /// assert_eq!(v, ["a", "b", "c"]);
+    /// # Ok(())
+    /// # }
+    /// # foo().unwrap();
+    /// ```
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, s: &[T]) -> Result<(), ReservationError> {
+        let cur_len = self.len();
+        let (len, p) = self.try_reserve_impl::<NOHAE>(s.len())?;
+        unsafe {
+            clone_from_slice_unchecked(s, len, p.add(cur_len));
+        }
+        Ok(())
+    }
+
     /// Resizes the small-vector in-place so that `len` is equal to `new_len`.
     ///
     /// If `new_len` is greater than `len`, the small-vector is extended by the difference,
@@ -1794,11 +2269,12 @@ where
     }
 }
 
-impl<T, const C: usize, L, SM> SmallVec<T, C, L, SM>
+impl<T, const C: usize, L, SM, G> SmallVec<T, C, L, SM, G>
 where
     T: Copy,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     /// Extends the small-vector by copying elements from a slice.
     ///
@@ -1890,6 +2366,35 @@ where
     }
 }
 
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+impl<T, const C: usize, L, SM, G> SmallVec<T, C, L, SM, G>
+where
+    T: bytemuck::NoUninit,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    /// Feeds the bytes of the elements into the given [`Hasher`] in a single call.
+    ///
+    /// This hashes the length as `usize`, followed by the elements as one byte slice, regardless
+    /// of where the elements are stored. A single [`write`] of the whole content is considerably
+    /// faster than [`Hash`] of compound elements with word-oriented hashers like *FxHash*.
+    ///
+    /// For primitive integer elements the result is the same as that of [`Hash`]. In general,
+    /// however, it differs, and two small-vectors hash equally only if their elements are equal
+    /// byte-wise.
+    ///
+    /// [`Hasher`]: core::hash::Hasher
+    /// [`Hash`]: core::hash::Hash
+    /// [`write`]: core::hash::Hasher::write
+    #[inline]
+    pub fn hash_bytes<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        state.write(bytemuck::cast_slice(self.as_slice()));
+    }
+}
+
 #[inline]
 unsafe fn clone_from_slice_unchecked<T, L>(s: &[T], len: &mut L, mut p: *mut T)
 where
@@ -1912,7 +2417,20 @@ use errors::*;
 mod drain;
 pub use drain::*;
 
+mod extract_if;
+pub use extract_if::*;
+
+mod format;
+pub use format::*;
+
+mod into_iter;
+pub use into_iter::*;
+
 mod macros;
+
+mod small_string;
+pub use small_string::*;
+
 mod traits;
 
 #[cfg(all(test, feature = "std"))]
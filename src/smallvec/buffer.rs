@@ -1,4 +1,8 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::{
     marker::PhantomData,
     mem::{ManuallyDrop, MaybeUninit},
@@ -129,23 +133,25 @@ where
 }
 
 #[derive(Debug)]
-pub struct SetLenOnDrop<'a, T, const C: usize, L, SM>
+pub struct SetLenOnDrop<'a, T, const C: usize, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     pub len: usize,
-    pub sv: &'a mut SmallVec<T, C, L, SM>,
+    pub sv: &'a mut SmallVec<T, C, L, SM, G>,
     pub armed: bool,
 }
 
-impl<'a, T, const C: usize, L, SM> SetLenOnDrop<'a, T, C, L, SM>
+impl<'a, T, const C: usize, L, SM, G> SetLenOnDrop<'a, T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
-    pub fn new(sv: &'a mut SmallVec<T, C, L, SM>, len: usize) -> Self {
+    pub fn new(sv: &'a mut SmallVec<T, C, L, SM, G>, len: usize) -> Self {
         Self {
             len,
             sv,
@@ -154,7 +160,7 @@ where
     }
 
     #[inline]
-    pub fn unarmed(sv: &'a mut SmallVec<T, C, L, SM>, len: usize) -> Self {
+    pub fn unarmed(sv: &'a mut SmallVec<T, C, L, SM, G>, len: usize) -> Self {
         Self {
             len,
             sv,
@@ -163,10 +169,11 @@ where
     }
 }
 
-impl<'a, T, const C: usize, L, SM> Drop for SetLenOnDrop<'a, T, C, L, SM>
+impl<'a, T, const C: usize, L, SM, G> Drop for SetLenOnDrop<'a, T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn drop(&mut self) {
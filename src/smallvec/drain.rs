@@ -1,4 +1,8 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, PowerOfTwo, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::{
     fmt::{Debug, Formatter},
     iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator},
@@ -11,14 +15,15 @@ use core::{
 ///
 /// See [`SmallVec::drain`] for more information.
 #[allow(dead_code)]
-pub struct Drain<'a, T, L, SM, const C: usize>
+pub struct Drain<'a, T, L, SM, const C: usize, G = PowerOfTwo>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
     // the owner SmallVec
-    pub(super) sv: ptr::NonNull<SmallVec<T, C, L, SM>>,
+    pub(super) sv: ptr::NonNull<SmallVec<T, C, L, SM, G>>,
     // an iterator over the slice to be drained
     pub(super) iter: slice::Iter<'a, T>,
     // the index of the first element past the drained range; or L::MAX for empty drained range
@@ -27,16 +32,18 @@ where
     pub(super) tail_len: L,
 }
 
-struct DropGuard<'s, 'a, T, L, SM, const C: usize>(&'s mut Drain<'a, T, L, SM, C>)
+struct DropGuard<'s, 'a, T, L, SM, G, const C: usize>(&'s mut Drain<'a, T, L, SM, C, G>)
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a;
 
-impl<'a, T, L, SM, const C: usize> Drain<'a, T, L, SM, C>
+impl<'a, T, L, SM, G, const C: usize> Drain<'a, T, L, SM, C, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
     /// Returns the remaining items of this iterator as a slice.
@@ -46,10 +53,11 @@ where
     }
 }
 
-impl<'a, T, L, SM, const C: usize> Debug for Drain<'a, T, L, SM, C>
+impl<'a, T, L, SM, G, const C: usize> Debug for Drain<'a, T, L, SM, C, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
@@ -66,10 +74,11 @@ where
     }
 }
 
-impl<'a, T, L, SM, const C: usize> AsRef<[T]> for Drain<'a, T, L, SM, C>
+impl<'a, T, L, SM, G, const C: usize> AsRef<[T]> for Drain<'a, T, L, SM, C, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
     #[inline]
@@ -78,10 +87,11 @@ where
     }
 }
 
-impl<'a, T, L, SM, const C: usize> Iterator for Drain<'a, T, L, SM, C>
+impl<'a, T, L, SM, G, const C: usize> Iterator for Drain<'a, T, L, SM, C, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
     type Item = T;
@@ -99,10 +109,11 @@ where
     }
 }
 
-impl<'a, T, L, SM, const C: usize> DoubleEndedIterator for Drain<'a, T, L, SM, C>
+impl<'a, T, L, SM, G, const C: usize> DoubleEndedIterator for Drain<'a, T, L, SM, C, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
     #[inline]
@@ -113,10 +124,11 @@ where
     }
 }
 
-impl<'a, T, L, SM, const C: usize> ExactSizeIterator for Drain<'a, T, L, SM, C>
+impl<'a, T, L, SM, G, const C: usize> ExactSizeIterator for Drain<'a, T, L, SM, C, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
     #[inline]
@@ -125,18 +137,20 @@ where
     }
 }
 
-impl<'a, T, L, SM, const C: usize> FusedIterator for Drain<'a, T, L, SM, C>
+impl<'a, T, L, SM, G, const C: usize> FusedIterator for Drain<'a, T, L, SM, C, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
 }
 
-impl<'s, 'a, T, L, SM, const C: usize> Drop for DropGuard<'s, 'a, T, L, SM, C>
+impl<'s, 'a, T, L, SM, G, const C: usize> Drop for DropGuard<'s, 'a, T, L, SM, G, C>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
     #[inline]
@@ -169,10 +183,11 @@ where
     }
 }
 
-impl<'a, T, L, SM, const C: usize> Drop for Drain<'a, T, L, SM, C>
+impl<'a, T, L, SM, G, const C: usize> Drop for Drain<'a, T, L, SM, C, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
     #[inline]
@@ -2,7 +2,10 @@
 
 use crate::mem::errors::ReservationError;
 
-use core::fmt::{Debug, Display, Formatter};
+use core::{
+    any::type_name,
+    fmt::{Debug, Display, Formatter},
+};
 
 // ----------------------------------------------------------------------------
 
@@ -10,6 +13,7 @@ use core::fmt::{Debug, Display, Formatter};
 ///
 /// [`try_insert`]: super::SmallVec::try_insert
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum InsertError {
     /// Requested index is out of bounds.
     InvalidIndex,
@@ -36,6 +40,9 @@ impl Display for InsertError {
                         layout.align()
                     )
                 }
+                ReservationError::SpillDisabled => {
+                    write!(f, "smallvec insert error: spill disabled")
+                }
             },
         }
     }
@@ -47,6 +54,50 @@ impl std::error::Error for InsertError {}
 
 // ----------------------------------------------------------------------------
 
+/// An error returned with a value when capacity reservation fails.
+///
+/// This error is returned from methods like [`try_insert_sorted_val`] that hand the value back to
+/// the caller in case of an error.
+///
+/// [`try_insert_sorted_val`]: super::SmallVec::try_insert_sorted_val
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ReservationErrorVal<T>(pub ReservationError, pub T);
+
+impl<T> Display for ReservationErrorVal<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "smallvec {}", self.0)
+    }
+}
+
+impl<T> Debug for ReservationErrorVal<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "smallvec::ReservationErrorVal<{}>({:?})",
+            type_name::<T>(),
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for ReservationErrorVal<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "smallvec::ReservationErrorVal<{=str}>({})",
+            type_name::<T>(),
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T> std::error::Error for ReservationErrorVal<T> {}
+
+// ----------------------------------------------------------------------------
+
 #[cfg(all(test, feature = "std"))]
 mod testing {
     use super::*;
@@ -67,5 +118,22 @@ mod testing {
             format!("{}", e),
             "smallvec insert error: alloc error. layout { size: 2, align: 4 }"
         );
+
+        let e = InsertError::ReservationError(ReservationError::SpillDisabled);
+        assert_eq!(format!("{}", e), "smallvec insert error: spill disabled");
+    }
+
+    #[test]
+    fn test_reservation_error_val() {
+        let e = ReservationErrorVal(ReservationError::CapacityOverflow, 7u8);
+        assert_eq!(
+            format!("{}", e),
+            "smallvec memory reservation error: capacity overflow"
+        );
+        assert_eq!(
+            format!("{:?}", e),
+            "smallvec::ReservationErrorVal<u8>(CapacityOverflow)"
+        );
+        assert_eq!(e.1, 7);
     }
 }
@@ -0,0 +1,87 @@
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallString,
+};
+use core::fmt::{self, Arguments, Write};
+
+/// Formats a [`SmallString`].
+///
+/// This function allows formatting a string, similar to the standard [`format`] function,
+/// but with [`SmallString`] as the resulting type. The formatted string is stored locally, and
+/// spills to the heap only when it exceeds the local capacity.
+///
+/// Formatting may fail if dynamic memory allocation fails, or if the string length exceeds the
+/// maximal value supported by the length type. Thus, unlike the standard function,
+/// this function returns `Result<SmallString>`.
+///
+/// The [`Arguments`] instance can be created with the [`format_args!`] macro.
+/// See the [`sformat!`] macro for a convenience wrapper of this function.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::{smallvec::{format, SmallString}, len::U8};
+/// # use core::format_args;
+/// # fn foo() -> core::fmt::Result {
+/// type S = SmallString<16, U8>;
+/// let s: S = format(format_args!("Hello, {}!", "world"))?;
+/// assert_eq!(s, "Hello, world!");
+/// assert!(s.is_local());
+///
+/// let s: S = format(format_args!("{}", "Hello, world! Hello, world!"))?;
+/// assert_eq!(s, "Hello, world! Hello, world!");
+/// assert!(s.is_heap());
+/// # Ok(())
+/// # }
+/// # foo().unwrap();
+/// ```
+///
+/// [`format`]: std::fmt::format
+/// [`format_args!`]: core::format_args
+/// [`sformat!`]: crate::sformat
+#[inline]
+pub fn format<const C: usize, L, SM, G>(
+    args: Arguments<'_>,
+) -> Result<SmallString<C, L, SM, G>, fmt::Error>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    let mut s = SmallString::<C, L, SM, G>::new();
+    s.write_fmt(args)?;
+    Ok(s)
+}
+
+#[cfg(test)]
+mod testing {
+    use crate as cds;
+    use cds::{len::U8, mem::Exact, smallvec::SmallString};
+
+    #[test]
+    fn test_sformat() {
+        let s = cds::sformat!(16, "Hello, {}!", "world").unwrap();
+        assert_eq!(s, "Hello, world!");
+        assert!(s.is_local());
+
+        let s = cds::sformat!(4, "{}", 123456).unwrap();
+        assert_eq!(s, "123456");
+        assert!(s.is_heap());
+
+        let s = cds::sformat!(0, "").unwrap();
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_sformat_type() {
+        type S = SmallString<4, U8, cds::mem::Uninitialized, Exact>;
+        let s = cds::sformat!(S, "{}-{}", "abc", 'd').unwrap();
+        assert_eq!(s, "abc-d");
+        assert_eq!(s.capacity(), 5);
+
+        let long = [b'a'; 256];
+        let long = core::str::from_utf8(&long).unwrap();
+        assert!(cds::sformat!(S, "{}", long).is_err());
+    }
+}
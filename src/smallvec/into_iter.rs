@@ -0,0 +1,291 @@
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, PowerOfTwo, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
+use core::{
+    fmt::{Debug, Formatter},
+    iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator},
+    ops::Drop,
+    ptr, slice,
+};
+
+/// An iterator that moves out of [`SmallVec`].
+///
+/// This struct is created by the `into_iter` method on [`SmallVec`]
+/// (provided by the [`IntoIterator`] trait).
+///
+/// Spare memory policy `SM` is applied to the memory of every element moved out of the iterator.
+pub struct IntoIter<T, L, SM, const C: usize, G = PowerOfTwo>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    // the owned small-vector; its length marks the end of the remaining elements
+    pub(super) sv: SmallVec<T, C, L, SM, G>,
+    // the index of the first remaining element
+    pub(super) start: L,
+}
+
+impl<T, L, SM, G, const C: usize> IntoIter<T, L, SM, C, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    /// Returns the remaining items of this iterator as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::small_vec;
+    /// let v = small_vec![3; u64; 1, 2, 3];
+    /// let mut iter = v.into_iter();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.as_slice(), &[2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        let start = self.start.as_usize();
+        unsafe { slice::from_raw_parts(self.sv.as_ptr().add(start), self.sv.len() - start) }
+    }
+
+    /// Returns the remaining items of this iterator as a mutable slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::small_vec;
+    /// let v = small_vec![3; u64; 1, 2, 3];
+    /// let mut iter = v.into_iter();
+    /// iter.as_mut_slice()[2] = 5;
+    /// assert_eq!(iter.next_back(), Some(5));
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let start = self.start.as_usize();
+        let len = self.sv.len();
+        unsafe { slice::from_raw_parts_mut(self.sv.as_mut_ptr().add(start), len - start) }
+    }
+}
+
+impl<T, L, SM, G, const C: usize> Debug for IntoIter<T, L, SM, C, G>
+where
+    T: Debug,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T, L, SM, G, const C: usize> AsRef<[T]> for IntoIter<T, L, SM, C, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, L, SM, G, const C: usize> Iterator for IntoIter<T, L, SM, C, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.start.as_usize();
+        if start >= self.sv.len() {
+            return None;
+        }
+        unsafe {
+            let p = self.sv.as_mut_ptr().add(start);
+            let e = p.read();
+            SM::init(p, 1);
+            self.start += 1;
+            Some(e)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, L, SM, G, const C: usize> DoubleEndedIterator for IntoIter<T, L, SM, C, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.sv.len();
+        if self.start.as_usize() >= len {
+            return None;
+        }
+        unsafe {
+            let new_len = len - 1;
+            self.sv.set_len(new_len);
+            let p = self.sv.as_mut_ptr().add(new_len);
+            let e = p.read();
+            SM::init(p, 1);
+            Some(e)
+        }
+    }
+}
+
+impl<T, L, SM, G, const C: usize> ExactSizeIterator for IntoIter<T, L, SM, C, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.sv.len() - self.start.as_usize()
+    }
+}
+
+impl<T, L, SM, G, const C: usize> FusedIterator for IntoIter<T, L, SM, C, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+}
+
+impl<T, L, SM, G, const C: usize> Drop for IntoIter<T, L, SM, C, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn drop(&mut self) {
+        let start = self.start.as_usize();
+        let len = self.sv.len();
+        unsafe {
+            // the moved out elements must not be dropped by the small-vector,
+            // even if one of the remaining elements panics while dropped
+            self.sv.set_len(0);
+            let p = self.sv.as_mut_ptr().add(start);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(p, len - start));
+            SM::init(p, len - start);
+        }
+        // the heap buffer, if any, is freed by the small-vector
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate as cds;
+    use cds::{
+        len::U8,
+        mem::Pattern,
+        small_vec,
+        smallvec::SmallVec,
+        testing::dropped::{Dropped, Track},
+    };
+
+    #[test]
+    fn test_into_iter() {
+        let v = small_vec![5; u64; 1, 2, 3];
+        let mut iter = v.into_iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.as_ref(), [2]);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn test_into_iter_zst() {
+        let v = small_vec![5; (); (), (), ()];
+        let mut iter = v.into_iter();
+        assert_eq!(iter.next(), Some(()));
+        assert_eq!(iter.next_back(), Some(()));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.count(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_into_iter_debug() {
+        let mut iter = small_vec![3; u64; 1, 2].into_iter();
+        iter.next();
+        assert_eq!(format!("{:?}", iter), "IntoIter([2])");
+    }
+
+    #[test]
+    fn test_into_iter_spare_memory_policy() {
+        let mut v = SmallVec::<u16, 4, U8, Pattern<0xAB>>::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+        let mut iter = v.into_iter();
+        let p = iter.as_slice().as_ptr();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        // the iterator owns the buffer, hence its memory is still accessible
+        unsafe {
+            assert_eq!(p.read(), 0xABAB);
+            assert_eq!(p.add(1).read(), 2);
+            assert_eq!(p.add(2).read(), 0xABAB);
+        }
+    }
+
+    #[test]
+    fn test_into_iter_drop() {
+        type V<'a> = SmallVec<Dropped<'a, 8>, 4, U8, Pattern<0xAB>>;
+        let t = Track::<8>::new();
+        let mut v = V::new();
+        for _ in 0..6 {
+            v.push(t.alloc());
+        }
+        let mut iter = v.into_iter();
+        let first = iter.next().unwrap();
+        let last = iter.next_back().unwrap();
+        assert_eq!(first.idx(), 0);
+        assert_eq!(last.idx(), 5);
+        assert_eq!(t.n_allocated(), 6);
+
+        drop(iter);
+        assert_eq!(t.n_allocated(), 2);
+        assert!(t.dropped_range(1..5));
+
+        drop(first);
+        drop(last);
+        assert_eq!(t.n_allocated(), 0);
+    }
+
+    #[test]
+    fn test_into_iter_for_loop() {
+        let v = small_vec![3; u64; 1, 2, 3];
+        let mut sum = 0;
+        for e in v {
+            sum += e;
+        }
+        assert_eq!(sum, 6);
+
+        let w = small_vec![3; u64; 1, 2, 3];
+        let doubled: SmallVec<u64, 3> = w.into_iter().map(|e| e * 2).collect();
+        assert_eq!(doubled, [2, 4, 6]);
+    }
+}
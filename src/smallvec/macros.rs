@@ -75,3 +75,68 @@ macro_rules! small_vec {
             .expect("small_vec! failed")
     }};
 }
+
+/// Formats a [`SmallString`] spilling to the heap only when needed.
+///
+/// This macro, similar to the standard [`std::format!`], formats a string yielding
+/// `Result<SmallString>`. The formatted string is stored locally, without memory allocation,
+/// as long as it fits in the local capacity, and spills to the heap otherwise.
+///
+/// This is a hybrid of [`aformat!`], which fails when the formatted string exceeds the capacity,
+/// and [`lformat!`], which truncates it. Note that, unlike the standard macro, this macro is
+/// fallible, as dynamic memory allocation may fail, and the length type may not be able to
+/// represent the string length.
+///
+/// This macro is a convenience wrapper of the [`format`] function.
+///
+/// # Examples
+///
+/// Format a `SmallString` specifying the local capacity only. The resulting type uses default
+/// length type, spare memory policy and growth policy.
+///
+/// ```rust
+/// # use cds::sformat;
+/// # fn foo() -> core::fmt::Result {
+/// let s = sformat!(16, "Hello, {}!", "world")?;
+/// assert_eq!(s, "Hello, world!");
+/// assert!(s.is_local());
+///
+/// let s = sformat!(4, "Hello, {}!", "world")?;
+/// assert_eq!(s.as_str(), "Hello, world!");
+/// assert!(s.is_heap());
+/// # Ok(())
+/// # }
+/// # foo().unwrap()
+/// ```
+///
+/// Format a `SmallString` specifying the small-string type.
+/// This allows customization of length type, spare memory policy and growth policy.
+///
+/// ```rust
+/// # use cds::{sformat, len::U8, mem::Zeroed, smallvec::SmallString};
+/// # fn foo() -> core::fmt::Result {
+/// type S = SmallString<16, U8, Zeroed>;
+/// let s = sformat!(S, "{}", 42)?;
+/// assert_eq!(s, "42");
+/// # Ok(())
+/// # }
+/// # foo().unwrap()
+/// ```
+///
+/// [`SmallString`]: crate::smallvec::SmallString
+/// [`format`]: crate::smallvec::format
+/// [`aformat!`]: crate::aformat
+/// [`lformat!`]: crate::lformat
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+#[macro_export]
+macro_rules! sformat {
+    ($c:literal, $($arg:tt)*) => {{
+        let res: Result<cds::smallvec::SmallString<$c>, core::fmt::Error> =
+            cds::smallvec::format(core::format_args!($($arg)*));
+        res
+    }};
+    ($s:ty, $($arg:tt)*) => {{
+        let res: Result<$s, core::fmt::Error> = cds::smallvec::format(core::format_args!($($arg)*));
+        res
+    }};
+}
@@ -1,22 +1,28 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::ptr;
 
-pub struct RetainGuard<'a, T, const C: usize, L, SM>
+pub struct RetainGuard<'a, T, const C: usize, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
-    pub(super) sv: &'a mut SmallVec<T, C, L, SM>,
+    pub(super) sv: &'a mut SmallVec<T, C, L, SM, G>,
     pub(super) len: usize,
     pub(super) processed: usize,
     pub(super) deleted: usize,
 }
 
-impl<'a, T, L, SM, const C: usize> Drop for RetainGuard<'a, T, C, L, SM>
+impl<'a, T, L, SM, G, const C: usize> Drop for RetainGuard<'a, T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
     T: 'a,
 {
     fn drop(&mut self) {
@@ -0,0 +1,471 @@
+use crate::{
+    len::{LengthType, Usize},
+    mem::{GrowthPolicy, PowerOfTwo, SpareMemoryPolicy, Uninitialized},
+    smallvec::SmallVec,
+};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug, Display, Formatter, Write},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    str,
+};
+
+/// A UTF-8 string with "small size" optimization.
+///
+/// `SmallString` wraps a [`SmallVec<u8>`], which is guaranteed to hold valid UTF-8.
+/// The string is stored locally, as long as it fits in the local capacity `C`, and spills to
+/// the heap otherwise. It is the result type of the [`format`] function and the [`sformat!`]
+/// macro.
+///
+/// `SmallString` dereferences to `str`, so all the `str` methods are available.
+/// The underlying bytes are accessible with [`as_bytes`] and [`into_bytes`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::sformat;
+/// # fn foo() -> core::fmt::Result {
+/// let s = sformat!(16, "Hello, {}!", "world")?;
+/// assert_eq!(s, "Hello, world!");
+/// assert!(s.starts_with("Hello"));
+/// assert!(s.is_local());
+/// # Ok(())
+/// # }
+/// # foo().unwrap();
+/// ```
+///
+/// [`SmallVec<u8>`]: SmallVec
+/// [`format`]: crate::smallvec::format
+/// [`sformat!`]: crate::sformat
+/// [`as_bytes`]: SmallString::as_bytes
+/// [`into_bytes`]: SmallString::into_bytes
+pub struct SmallString<const C: usize, L = Usize, SM = Uninitialized, G = PowerOfTwo>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    vec: SmallVec<u8, C, L, SM, G>,
+}
+
+impl<const C: usize, L, SM, G> SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    /// Creates a new empty small-string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::smallvec::SmallString;
+    /// let s = SmallString::<16>::new();
+    /// assert_eq!(s, "");
+    /// assert!(s.is_local());
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            vec: SmallVec::new(),
+        }
+    }
+
+    /// Returns a string slice containing the entire small-string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::sformat;
+    /// let s = sformat!(8, "{}", 42).unwrap();
+    /// assert_eq!(s.as_str(), "42");
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `vec` holds valid UTF-8 by construction
+        unsafe { str::from_utf8_unchecked(self.vec.as_slice()) }
+    }
+
+    /// Returns a byte slice of the small-string contents.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::sformat;
+    /// let s = sformat!(8, "{}", 42).unwrap();
+    /// assert_eq!(s.as_bytes(), b"42");
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.vec.as_slice()
+    }
+
+    /// Converts the small-string into the underlying small-vector of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::sformat;
+    /// let s = sformat!(8, "{}", 42).unwrap();
+    /// let v = s.into_bytes();
+    /// assert_eq!(v, *b"42");
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> SmallVec<u8, C, L, SM, G> {
+        self.vec
+    }
+
+    /// Returns the length of the small-string in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::sformat;
+    /// let s = sformat!(8, "€").unwrap();
+    /// assert_eq!(s.len(), 3);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Checks if the small-string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::smallvec::SmallString;
+    /// let s = SmallString::<8>::new();
+    /// assert!(s.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Returns the capacity of the small-string in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::smallvec::SmallString;
+    /// let s = SmallString::<8>::new();
+    /// assert_eq!(s.capacity(), 8);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Checks if the small-string is stored locally.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::sformat;
+    /// let s = sformat!(8, "{}", 42).unwrap();
+    /// assert!(s.is_local());
+    /// ```
+    #[inline]
+    pub fn is_local(&self) -> bool {
+        self.vec.is_local()
+    }
+
+    /// Checks if the small-string uses a heap buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::sformat;
+    /// let s = sformat!(2, "{}", 12345).unwrap();
+    /// assert!(s.is_heap());
+    /// ```
+    #[inline]
+    pub fn is_heap(&self) -> bool {
+        self.vec.is_heap()
+    }
+
+    /// Truncates the small-string to zero length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cds::sformat;
+    /// let mut s = sformat!(8, "{}", 42).unwrap();
+    /// s.clear();
+    /// assert!(s.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear()
+    }
+}
+
+impl<const C: usize, L, SM, G> Default for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const C: usize, L, SM, G> Clone for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec.clone(),
+        }
+    }
+}
+
+impl<const C: usize, L, SM, G> Deref for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const C: usize, L, SM, G> AsRef<str> for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const C: usize, L, SM, G> AsRef<[u8]> for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const C: usize, L, SM, G> Borrow<str> for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const C: usize, L, SM, G> Display for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const C: usize, L, SM, G> Debug for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const C: usize, L, SM, G> Hash for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self.as_str(), state)
+    }
+}
+
+/// Implementation of [`Write`] for `SmallString`.
+///
+/// The formatted string is appended, spilling to the heap when local capacity is exhausted.
+/// These methods fail if a (re)allocation fails, or if the required capacity exceeds the maximal
+/// value supported by the length type.
+impl<const C: usize, L, SM, G> Write for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.vec.write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.vec.write_char(c)
+    }
+}
+
+impl<const C: usize, L, SM, G, const C2: usize, L2, SM2, G2> PartialEq<SmallString<C2, L2, SM2, G2>>
+    for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+    L2: LengthType,
+    SM2: SpareMemoryPolicy<u8>,
+    G2: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &SmallString<C2, L2, SM2, G2>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const C: usize, L, SM, G> Eq for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+}
+
+impl<const C: usize, L, SM, G> PartialEq<str> for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const C: usize, L, SM, G> PartialEq<&str> for SmallString<C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const C: usize, L, SM, G> PartialEq<SmallString<C, L, SM, G>> for str
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &SmallString<C, L, SM, G>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<const C: usize, L, SM, G> PartialEq<SmallString<C, L, SM, G>> for &str
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &SmallString<C, L, SM, G>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod testing {
+    use crate as cds;
+    use cds::{len::U8, mem::Zeroed, smallvec::SmallString};
+    use core::fmt::Write;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_small_string_write() {
+        type S = SmallString<4, U8, Zeroed>;
+        let mut s = S::new();
+        assert!(core::write!(&mut s, "abc").is_ok());
+        assert!(s.is_local());
+        let (d, euro) = ('d', '€');
+        assert!(core::write!(&mut s, "{d}{euro}").is_ok());
+        assert!(s.is_heap());
+        assert_eq!(s, "abcd€");
+        assert_eq!(s.len(), 7);
+        assert_eq!(s.as_bytes(), "abcd€".as_bytes());
+        assert_eq!(s.into_bytes(), *"abcd€".as_bytes());
+    }
+
+    #[test]
+    fn test_small_string_write_fails() {
+        type S = SmallString<16, U8>;
+        let mut s = S::new();
+        for _ in 0..255 {
+            assert!(core::write!(&mut s, "a").is_ok());
+        }
+        assert!(core::write!(&mut s, "a").is_err());
+        assert_eq!(s.len(), 255);
+    }
+
+    #[test]
+    fn test_small_string_traits() {
+        let s = cds::sformat!(8, "{}", "cds").unwrap();
+        let t = s.clone();
+        assert_eq!(s, t);
+        assert_eq!(s, *"cds");
+        assert_eq!("cds", s);
+        assert_eq!(*"cds", s);
+        assert_eq!(format!("{}", s), "cds");
+        assert_eq!(format!("{:?}", s), "\"cds\"");
+        assert_eq!(s.to_uppercase(), "CDS");
+
+        let r: &str = s.as_ref();
+        assert_eq!(r, "cds");
+        let b: &[u8] = s.as_ref();
+        assert_eq!(b, b"cds");
+
+        let set: HashSet<SmallString<8>> = [s].into_iter().collect();
+        assert!(set.contains("cds"));
+
+        let mut d = SmallString::<8>::default();
+        assert!(d.is_empty());
+        assert_eq!(d.capacity(), 8);
+        d.write_str("x").unwrap();
+        d.clear();
+        assert_eq!(d, "");
+    }
+}
@@ -2,9 +2,15 @@ use crate as cds;
 use cds::{
     gen_dropped_zst,
     len::{LengthType, Usize, U8},
-    mem::{errors::ReservationError, Pattern, SpareMemoryPolicy, Uninitialized},
+    mem::{
+        errors::ReservationError, Chunked, Discard, Exact, GrowthPolicy, NoSpill, OneAndHalf,
+        Overwrite, Pattern, PowerOfTwo, SpareMemoryPolicy, Uninitialized,
+    },
     small_vec,
-    smallvec::{errors::InsertError, Drain, SmallVec},
+    smallvec::{
+        errors::{InsertError, ReservationErrorVal},
+        Drain, SmallVec,
+    },
     testing::{
         dropped::{Dropped, Track},
         dropped_zst::{counters, Counters},
@@ -1628,7 +1634,7 @@ fn test_resize_with_local() {
     type SV = SmallVec<u64, 8, U8, Pattern<P>>;
     let mut v = SV::new();
     let mut cnt = 0;
-    assert_eq!(v, []);
+    assert_eq!(v, [] as [u64; 0]);
     check_spare_memory(&v, P);
 
     v.resize_with(4, || {
@@ -2653,3 +2659,403 @@ fn test_small_vec_drain_covariance() {
         d
     }
 }
+
+fn push_capacities<G: GrowthPolicy>(n: usize) -> SmallVec<usize, 16> {
+    let mut v = SmallVec::<u64, 2, U8, Uninitialized, G>::new();
+    let mut caps = SmallVec::new();
+    for i in 0..n {
+        v.push(i as u64);
+        if caps.last() != Some(&v.capacity()) {
+            caps.push(v.capacity());
+        }
+    }
+    assert!(v.iter().copied().eq(0..n as u64));
+    caps
+}
+
+#[test]
+fn test_growth_policy_push() {
+    assert_eq!(push_capacities::<PowerOfTwo>(20), [2, 4, 8, 16, 32]);
+    assert_eq!(
+        push_capacities::<OneAndHalf>(20),
+        [2, 3, 4, 6, 9, 13, 19, 28]
+    );
+    assert_eq!(push_capacities::<Exact>(5), [2, 3, 4, 5]);
+    assert_eq!(push_capacities::<Chunked<8>>(20), [2, 8, 16, 24]);
+}
+
+#[test]
+fn test_growth_policy_clamped_to_max() {
+    assert_eq!(*push_capacities::<PowerOfTwo>(255).last().unwrap(), 255);
+    assert_eq!(*push_capacities::<Chunked<100>>(255).last().unwrap(), 255);
+
+    let mut v = SmallVec::<u64, 2, U8, Uninitialized, Chunked<100>>::new();
+    v.extend(0..255);
+    assert_eq!(v.try_push(0), Err(ReservationError::CapacityOverflow));
+}
+
+#[test]
+fn test_growth_policy_reserve() {
+    let mut v = SmallVec::<u64, 2, Usize, Uninitialized, Chunked<10>>::new();
+    v.reserve(3);
+    assert_eq!(v.capacity(), 10);
+    v.reserve_exact(11);
+    assert_eq!(v.capacity(), 11);
+    v.extend(0..12);
+    assert_eq!(v.capacity(), 20);
+
+    let mut v = SmallVec::<u64, 2, Usize, Uninitialized, OneAndHalf>::new();
+    v.reserve(10);
+    assert_eq!(v.capacity(), 10);
+    v.extend(0..11);
+    assert_eq!(v.capacity(), 15);
+}
+
+#[test]
+fn test_no_spill_reject() {
+    type SV = SmallVec<u64, 2, U8, Uninitialized, NoSpill>;
+    let mut v = SV::new();
+    v.push(1);
+    v.push(2);
+    assert_eq!(v.try_push(3), Err(ReservationError::SpillDisabled));
+    assert_eq!(v.try_reserve(1), Err(ReservationError::SpillDisabled));
+    assert_eq!(v.try_reserve_exact(1), Err(ReservationError::SpillDisabled));
+    assert_eq!(
+        v.try_insert(0, 3),
+        Err(InsertError::ReservationError(
+            ReservationError::SpillDisabled
+        ))
+    );
+    assert_eq!(v.try_extend(3..5), Err(ReservationError::SpillDisabled));
+    assert_eq!(v, [1, 2]);
+    assert!(v.is_local());
+    assert_eq!(v.capacity(), 2);
+
+    v.pop();
+    assert!(v.try_reserve(1).is_ok());
+    assert!(v.try_extend(3..4).is_ok());
+    assert_eq!(v, [1, 3]);
+    assert_eq!(
+        SV::try_from_iter(0..3).err(),
+        Some(ReservationError::SpillDisabled)
+    );
+}
+
+#[test]
+#[should_panic(expected = "smallvec push failed")]
+fn test_no_spill_push_panics() {
+    let mut v = SmallVec::<u64, 1, U8, Uninitialized, NoSpill>::new();
+    v.push(1);
+    v.push(2);
+}
+
+#[test]
+fn test_no_spill_discard() {
+    type SV<'a> = SmallVec<Dropped<'a, 8>, 3, U8, Pattern<0xAB>, NoSpill<Discard>>;
+    let t = Track::<8>::new();
+    let mut v = SV::new();
+    v.push(t.alloc());
+    v.extend(t.take(4));
+    assert!(v.iter().map(|e| e.idx()).eq(0..3));
+    assert!(t.dropped_indices(&[3, 4]));
+    assert!(v.try_push(t.alloc()).is_ok());
+    assert!(t.dropped_indices(&[3, 4, 5]));
+    assert!(v.is_local());
+    drop(v);
+    assert!(t.dropped_range(0..6));
+}
+
+#[test]
+fn test_no_spill_overwrite() {
+    type SV<'a> = SmallVec<Dropped<'a, 8>, 3, U8, Pattern<0xAB>, NoSpill<Overwrite>>;
+    let t = Track::<8>::new();
+    let mut v = SV::new();
+    v.extend(t.take(5));
+    assert!(v.iter().map(|e| e.idx()).eq(2..5));
+    assert!(t.dropped_indices(&[0, 1]));
+    v.push(t.alloc());
+    assert!(v.iter().map(|e| e.idx()).eq(3..6));
+    assert!(t.dropped_indices(&[0, 1, 2]));
+    assert!(v.is_local());
+    drop(v);
+    assert!(t.dropped_range(0..6));
+
+    let mut v = SmallVec::<u64, 0, U8, Uninitialized, NoSpill<Overwrite>>::new();
+    v.push(1);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_no_spill_zst() {
+    let mut v = SmallVec::<(), 2, U8, Uninitialized, NoSpill>::new();
+    v.extend(core::iter::repeat_n((), 255));
+    assert_eq!(v.len(), 255);
+    assert_eq!(v.try_push(()), Err(ReservationError::CapacityOverflow));
+}
+
+#[test]
+fn test_try_extend_rollback() {
+    type SV<'a> = SmallVec<Dropped<'a, 300>, 4, U8, Pattern<0xAB>>;
+    let t = Track::<300>::new();
+    let mut v = SV::new();
+    v.try_extend(t.take(3)).unwrap();
+    assert_eq!(v.len(), 3);
+
+    // an iterator without size hint overflows U8 one element at a time
+    let mut it = t.take(300);
+    let e = v.try_extend(core::iter::from_fn(|| it.next()));
+    assert_eq!(e, Err(ReservationError::CapacityOverflow));
+    assert_eq!(v.len(), 3);
+    assert_eq!(t.n_allocated(), 3);
+    assert!(v.iter().enumerate().all(|(i, d)| d.idx() == i));
+
+    drop(v);
+    assert_eq!(t.n_allocated(), 0);
+}
+
+#[test]
+fn test_try_extend_no_spill_overwrite() {
+    type SV<'a> = SmallVec<Dropped<'a, 8>, 2, U8, Uninitialized, NoSpill<Overwrite>>;
+    let t = Track::<8>::new();
+    let mut v = SV::new();
+    v.try_extend(t.take(5)).unwrap();
+    // overflowing elements overwrite the oldest ones, which are dropped immediately
+    assert_eq!(v.len(), 2);
+    assert!(v.iter().map(|d| d.idx()).eq([3, 4]));
+    assert!(t.dropped_range(0..=2));
+    assert!(v.is_local());
+
+    // zero-sized elements are never shifted, hence exceeding `L::MAX` rolls back
+    let mut z = SmallVec::<(), 2, U8, Uninitialized, NoSpill<Overwrite>>::new();
+    z.push(());
+    let e = z.try_extend(core::iter::repeat_n((), 255));
+    assert_eq!(e, Err(ReservationError::CapacityOverflow));
+    assert_eq!(z.len(), 1);
+}
+
+#[test]
+fn test_try_extend_from_slice() {
+    let mut v = SmallVec::<u64, 2, U8, Pattern<0xAB>>::new();
+    v.try_extend_from_slice(&[1]).unwrap();
+    v.try_extend_from_slice(&[2, 3, 4]).unwrap();
+    assert_eq!(v, [1, 2, 3, 4]);
+    assert!(v.is_heap());
+
+    let big = [0; 255];
+    assert_eq!(
+        v.try_extend_from_slice(&big),
+        Err(ReservationError::CapacityOverflow)
+    );
+    assert_eq!(v, [1, 2, 3, 4]);
+
+    let mut z = SmallVec::<(), 2, U8>::new();
+    z.try_extend_from_slice(&[(); 10]).unwrap();
+    assert_eq!(z.len(), 10);
+}
+
+#[test]
+fn test_try_clone() {
+    let v = SmallVec::<String, 2, U8>::try_from_iter(["a", "b", "c"].map(String::from)).unwrap();
+    let w = v.try_clone().unwrap();
+    assert_eq!(v, w);
+    assert!(w.is_heap());
+
+    let l = small_vec![3; u64; 1, 2];
+    assert_eq!(l.try_clone().unwrap(), [1, 2]);
+}
+
+#[test]
+fn test_insert_sorted() {
+    let mut v = SmallVec::<u8, 2, U8, Pattern<0xBA>>::new();
+    assert_eq!(v.insert_sorted(3), 0);
+    assert_eq!(v.insert_sorted(1), 0);
+    assert!(v.is_local());
+    assert_eq!(v.insert_sorted(3), 2);
+    assert!(v.is_heap());
+    assert_eq!(v.insert_sorted(5), 3);
+    assert_eq!(v.insert_sorted(2), 1);
+    assert_eq!(v, [1, 2, 3, 3, 5]);
+    assert!(matches!(v.try_insert_sorted_val(4), Ok(4)));
+    assert_eq!(v, [1, 2, 3, 3, 4, 5]);
+    check_spare_memory(&v, 0xBA);
+}
+
+#[test]
+fn test_try_insert_sorted_val_fails() {
+    let mut v = SmallVec::<u8, 2, U8>::new();
+    for i in 0..255 {
+        assert!(v.try_insert_sorted_val(i).is_ok());
+    }
+    assert!(matches!(
+        v.try_insert_sorted_val(7),
+        Err(ReservationErrorVal(ReservationError::CapacityOverflow, 7))
+    ));
+    assert_eq!(v.len(), 255);
+}
+
+#[test]
+fn test_insert_sorted_by_key() {
+    let mut v = SmallVec::<(u8, char), 2>::new();
+    assert_eq!(v.insert_sorted_by_key((2, 'a'), |e| e.0), 0);
+    assert_eq!(v.insert_sorted_by_key((1, 'b'), |e| e.0), 0);
+    assert_eq!(v.insert_sorted_by_key((2, 'c'), |e| e.0), 2);
+    assert_eq!(v.insert_sorted_by_key((0, 'd'), |e| e.0), 0);
+    assert_eq!(v, [(0, 'd'), (1, 'b'), (2, 'a'), (2, 'c')]);
+}
+
+#[test]
+fn test_remove_sorted() {
+    let mut v = SmallVec::<u8, 2, U8, Pattern<0xBA>>::try_from([1, 2, 3]).unwrap();
+    assert_eq!(v.remove_sorted(&0), None);
+    assert_eq!(v.remove_sorted(&2), Some(2));
+    assert_eq!(v, [1, 3]);
+    check_spare_memory(&v, 0xBA);
+    assert_eq!(v.remove_sorted(&3), Some(3));
+    assert_eq!(v.remove_sorted(&1), Some(1));
+    assert_eq!(v.remove_sorted(&1), None);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_dedup_sorted() {
+    let mut v = SmallVec::<u8, 8, U8, Pattern<0xBA>>::try_from([1, 1, 2, 3, 3, 3, 4, 4]).unwrap();
+    assert!(v.is_local());
+    v.dedup_sorted();
+    assert_eq!(v, [1, 2, 3, 4]);
+    check_spare_memory(&v, 0xBA);
+
+    let mut v = SmallVec::<u8, 2, U8, Pattern<0xBA>>::try_from([7; 8]).unwrap();
+    assert!(v.is_heap());
+    v.dedup_sorted();
+    assert_eq!(v, [7]);
+    check_spare_memory(&v, 0xBA);
+
+    let mut v = SmallVec::<u8, 2>::new();
+    v.dedup_sorted();
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_dedup_sorted_dropped() {
+    struct K<'a>(u8, Dropped<'a, 16>);
+    impl PartialEq for K<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    type SV<'a> = SmallVec<K<'a>, 2, U8, Pattern<0xBA>>;
+    let t = Track::<16>::new();
+    let mut v = SV::from_iter(
+        [0, 0, 1, 2, 2, 2, 3, 3]
+            .into_iter()
+            .map(|k| K(k, t.alloc())),
+    );
+    v.dedup_sorted();
+    assert!(t.dropped_indices(&[1, 4, 5, 7]));
+    assert!(v.iter().map(|k| k.1.idx()).eq([0, 2, 3, 6]));
+    check_spare_memory(&v, 0xBA);
+    drop(v);
+    assert!(t.dropped_range(0..8));
+}
+
+#[test]
+fn test_dedup_sorted_zst() {
+    let mut v = SmallVec::<(), 2>::try_from([(); 5]).unwrap();
+    v.dedup_sorted();
+    assert_eq!(v.len(), 1);
+}
+
+#[test]
+fn test_extract_if() {
+    let mut v = SmallVec::<u8, 4, U8, Pattern<0xBA>>::try_from([1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+    assert!(v.is_heap());
+    assert!(v.extract_if(.., |e| *e % 3 == 0).eq([3, 6]));
+    assert_eq!(v, [1, 2, 4, 5, 7, 8]);
+    check_spare_memory(&v, 0xBA);
+
+    assert!(v.extract_if(2..=3, |_| true).eq([4, 5]));
+    assert_eq!(v, [1, 2, 7, 8]);
+    assert!(v.is_heap());
+    check_spare_memory(&v, 0xBA);
+
+    let mut v = SmallVec::<u8, 8, U8, Pattern<0xBA>>::try_from([1, 2, 3, 4]).unwrap();
+    assert!(v.is_local());
+    assert!(v
+        .extract_if(1.., |e| {
+            *e *= 2;
+            *e > 5
+        })
+        .eq([6, 8]));
+    assert_eq!(v, [1, 4]);
+    check_spare_memory(&v, 0xBA);
+}
+
+#[test]
+fn test_extract_if_partially_consumed() {
+    let mut v = SmallVec::<u8, 2, U8, Pattern<0xBA>>::try_from([1, 2, 3, 4, 5, 6]).unwrap();
+    let mut it = v.extract_if(..5, |e| *e % 2 == 0);
+    assert_eq!(it.next(), Some(2));
+    drop(it);
+    assert_eq!(v, [1, 3, 4, 5, 6]);
+    check_spare_memory(&v, 0xBA);
+}
+
+#[test]
+fn test_extract_if_dropped() {
+    type SV<'a> = SmallVec<Dropped<'a, 16>, 2, U8, Pattern<0xBA>>;
+    let t = Track::<16>::new();
+    let mut v = SV::from_iter(t.take(8));
+    v.extract_if(1..7, |e| e.idx() % 2 == 1).for_each(drop);
+    assert!(t.dropped_indices(&[1, 3, 5]));
+    assert!(v.iter().map(|e| e.idx()).eq([0, 2, 4, 6, 7]));
+    check_spare_memory(&v, 0xBA);
+    drop(v);
+    assert!(t.dropped_range(0..8));
+}
+
+#[test]
+fn test_extract_if_zst() {
+    let mut v = SmallVec::<(), 2>::try_from([(); 5]).unwrap();
+    let mut n = 0;
+    assert_eq!(
+        v.extract_if(1.., |_| {
+            n += 1;
+            n % 2 == 0
+        })
+        .count(),
+        2
+    );
+    assert_eq!(v.len(), 3);
+}
+
+#[test]
+fn test_extract_if_forget() {
+    let mut v = small_vec![2; 1, 2, 3, 4];
+    let mut it = v.extract_if(1.., |_| true);
+    assert_eq!(it.next(), Some(2));
+    core::mem::forget(it);
+    assert_eq!(v, [1]);
+}
+
+#[test]
+fn test_extract_if_pred_panics() {
+    type SV<'a> = SmallVec<Dropped<'a, 16>, 2, U8, Pattern<0xBA>>;
+    let t = Track::<16>::new();
+    let mut v = SV::from_iter(t.take(8));
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        v.extract_if(.., |e| {
+            if e.idx() == 4 {
+                panic!("predicate panics");
+            }
+            e.idx() % 2 == 0
+        })
+        .for_each(drop);
+    }));
+    assert!(res.is_err());
+    assert!(t.dropped_indices(&[0, 2]));
+    assert!(v.iter().map(|e| e.idx()).eq([1, 3, 4, 5, 6, 7]));
+    check_spare_memory(&v, 0xBA);
+    drop(v);
+    assert!(t.dropped_range(0..8));
+}
@@ -0,0 +1,91 @@
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+impl<'a, T, L, SM, G, const C: usize> Arbitrary<'a> for SmallVec<T, C, L, SM, G>
+where
+    T: Arbitrary<'a>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut v = Self::new();
+        for e in u.arbitrary_iter()? {
+            if v.len() == max_len::<C, L, G>() {
+                break;
+            }
+            v.try_push(e?).map_err(|_| Error::IncorrectFormat)?;
+        }
+        Ok(v)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let mut v = Self::new();
+        for e in u.arbitrary_take_rest_iter()? {
+            if v.len() == max_len::<C, L, G>() {
+                break;
+            }
+            v.try_push(e?).map_err(|_| Error::IncorrectFormat)?;
+        }
+        Ok(v)
+    }
+}
+
+// The maximal length of a generated small-vector: the local capacity when the growth policy
+// doesn't spill to the heap, and `L::MAX` otherwise.
+#[inline]
+fn max_len<const C: usize, L: LengthType, G: GrowthPolicy>() -> usize {
+    if G::SPILL {
+        L::MAX
+    } else {
+        C
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{
+        len::U8,
+        mem::{Discard, NoSpill, Reject, Uninitialized},
+        smallvec::SmallVec,
+    };
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn test_arbitrary_spills() {
+        type S = SmallVec<u8, 2, U8>;
+        let data = [1, 10, 1, 11, 1, 12, 0];
+        let s = S::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(s, [10, 11, 12]);
+        assert!(s.is_heap());
+    }
+
+    #[test]
+    fn test_arbitrary_respects_len_max() {
+        type S = SmallVec<u8, 2, U8>;
+        let data = [1u8; 600];
+        let s = S::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        assert_eq!(s.len(), 255);
+    }
+
+    #[test]
+    fn test_arbitrary_no_spill() {
+        type S = SmallVec<u8, 2, U8, Uninitialized, NoSpill<Reject>>;
+        let data = [1, 10, 1, 11, 1, 12, 0];
+        let s = S::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(s, [10, 11]);
+        assert!(s.is_local());
+
+        let data = [1u8; 600];
+        let s = S::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        assert_eq!(s, [1, 1]);
+
+        type D = SmallVec<u8, 2, U8, Uninitialized, NoSpill<Discard>>;
+        let s = D::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        assert_eq!(s.len(), 2);
+    }
+}
@@ -1,10 +1,15 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::convert::AsMut;
 
-impl<T, L, SM, const C: usize> AsMut<[T]> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> AsMut<[T]> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn as_mut(&mut self) -> &mut [T] {
@@ -12,13 +17,14 @@ where
     }
 }
 
-impl<T, L, SM, const C: usize> AsMut<SmallVec<T, C, L, SM>> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> AsMut<SmallVec<T, C, L, SM, G>> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
-    fn as_mut(&mut self) -> &mut SmallVec<T, C, L, SM> {
+    fn as_mut(&mut self) -> &mut SmallVec<T, C, L, SM, G> {
         self
     }
 }
@@ -1,10 +1,15 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::convert::AsRef;
 
-impl<T, L, SM, const C: usize> AsRef<[T]> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> AsRef<[T]> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn as_ref(&self) -> &[T] {
@@ -12,10 +17,11 @@ where
     }
 }
 
-impl<T, L, SM, const C: usize> AsRef<SmallVec<T, C, L, SM>> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> AsRef<SmallVec<T, C, L, SM, G>> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn as_ref(&self) -> &Self {
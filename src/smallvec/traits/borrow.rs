@@ -1,10 +1,15 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::borrow::{Borrow, BorrowMut};
 
-impl<T, L, SM, const C: usize> Borrow<[T]> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> Borrow<[T]> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn borrow(&self) -> &[T] {
@@ -12,10 +17,11 @@ where
     }
 }
 
-impl<T, L, SM, const C: usize> BorrowMut<[T]> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> BorrowMut<[T]> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn borrow_mut(&mut self) -> &mut [T] {
@@ -1,15 +1,16 @@
 use crate::{
     len::LengthType,
-    mem::SpareMemoryPolicy,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
     smallvec::{clone_from_slice_unchecked, SmallVec, DOHAE},
 };
 use core::{clone::Clone, mem};
 
-impl<T, L, SM, const C: usize> Clone for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> Clone for SmallVec<T, C, L, SM, G>
 where
     T: Clone,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn clone(&self) -> Self {
@@ -1,11 +1,32 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::fmt::{Debug, Formatter, Result};
 
-impl<T, L, SM, const C: usize> Debug for SmallVec<T, C, L, SM>
+/// Formats the elements of a small-vector as a list, like a slice.
+///
+/// The growth policy `G` is part of the type and doesn't appear in the output, so that it matches
+/// the output of [`Vec`] and slices. Use [`type_name`] to report the effective policy:
+///
+/// ```rust
+/// # use cds::{smallvec::SmallVec, mem::{Exact, Uninitialized}, len::U8};
+/// # use core::any::type_name;
+/// let mut v = SmallVec::<u8, 2, U8, Uninitialized, Exact>::new();
+/// v.extend([1, 2, 3]);
+/// assert_eq!(format!("{:?}", v), "[1, 2, 3]");
+/// assert!(type_name::<Exact>().ends_with("Exact"));
+/// ```
+///
+/// [`Vec`]: alloc::vec::Vec
+/// [`type_name`]: core::any::type_name
+impl<T, L, SM, G, const C: usize> Debug for SmallVec<T, C, L, SM, G>
 where
     T: Debug,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
@@ -28,4 +49,23 @@ mod testing {
         let s = format!("{:?}", v);
         assert_eq!(s, "[\"Hello\", \", \", \"world!\"]");
     }
+
+    #[test]
+    fn test_debug_independent_of_growth_policy() {
+        use cds::{
+            len::U8,
+            mem::{Chunked, Exact, NoSpill, OneAndHalf, PowerOfTwo, Uninitialized},
+            smallvec::SmallVec,
+        };
+        fn debug<G: cds::mem::GrowthPolicy>() -> String {
+            let mut v = SmallVec::<u8, 2, U8, Uninitialized, G>::new();
+            v.extend([1, 2]);
+            format!("{:?}", v)
+        }
+        assert_eq!(debug::<PowerOfTwo>(), "[1, 2]");
+        assert_eq!(debug::<OneAndHalf>(), "[1, 2]");
+        assert_eq!(debug::<Exact>(), "[1, 2]");
+        assert_eq!(debug::<Chunked<4>>(), "[1, 2]");
+        assert_eq!(debug::<NoSpill>(), "[1, 2]");
+    }
 }
@@ -1,10 +1,15 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::default::Default;
 
-impl<T, const C: usize, L, SM> Default for SmallVec<T, C, L, SM>
+impl<T, const C: usize, L, SM, G> Default for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn default() -> Self {
@@ -0,0 +1,42 @@
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
+use defmt::{Format, Formatter};
+
+/// Implementation of [`Format`] for [`SmallVec`].
+///
+/// A small-vector is formatted as a slice of its elements, regardless of where they are stored.
+impl<T, L, SM, G, const C: usize> Format for SmallVec<T, C, L, SM, G>
+where
+    T: Format,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn format(&self, f: Formatter<'_>) {
+        Format::format(self.as_slice(), f)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{
+        len::U8,
+        mem::errors::ReservationError,
+        smallvec::{errors::*, SmallVec},
+    };
+    use defmt::Format;
+
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn test_format() {
+        assert_format::<SmallVec<u32, 4, U8>>();
+        assert_format::<InsertError>();
+        assert_format::<ReservationError>();
+        assert_format::<ReservationErrorVal<u32>>();
+    }
+}
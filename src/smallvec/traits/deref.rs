@@ -1,10 +1,15 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::ops::{Deref, DerefMut};
 
-impl<T, L, SM, const C: usize> Deref for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> Deref for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     type Target = [T];
 
@@ -14,10 +19,11 @@ where
     }
 }
 
-impl<T, L, SM, const C: usize> DerefMut for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> DerefMut for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
@@ -1,14 +1,15 @@
 use crate::{
     len::LengthType,
-    mem::{alloc::dealloc_buffer, SpareMemoryPolicy},
+    mem::{alloc::dealloc_buffer, GrowthPolicy, SpareMemoryPolicy},
     smallvec::SmallVec,
 };
 use core::mem;
 
-impl<T, const C: usize, L, SM> Drop for SmallVec<T, C, L, SM>
+impl<T, const C: usize, L, SM, G> Drop for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     fn drop(&mut self) {
         self.truncate(0);
@@ -0,0 +1,87 @@
+use crate::{
+    encoding::{decode_len, encode_seq, encoded_seq_len, errors::*, Decode, Encode},
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
+
+impl<T, L, SM, G, const C: usize> Encode for SmallVec<T, C, L, SM, G>
+where
+    T: Encode,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        encoded_seq_len::<L, T>(self)
+    }
+
+    #[inline]
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        encode_seq::<L, T>(self, buf)
+    }
+}
+
+impl<T, L, SM, G, const C: usize> Decode for SmallVec<T, C, L, SM, G>
+where
+    T: Decode,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn decode(buf: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (len, mut n) = decode_len::<L>(buf, L::MAX)?;
+        let mut tmp = Self::new();
+        // don't trust the encoded length with a large allocation up front
+        tmp.try_reserve_exact(len.min(buf.len() - n))
+            .map_err(|_| DecodeError::InsufficientCapacity)?;
+        for _ in 0..len {
+            let (e, m) = T::decode(&buf[n..])?;
+            n += m;
+            tmp.try_push(e)
+                .map_err(|_| DecodeError::InsufficientCapacity)?;
+        }
+        Ok((tmp, n))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate as cds;
+    use cds::{
+        encoding::{errors::*, Decode, Encode},
+        len::{U16, U8},
+        smallvec::SmallVec,
+    };
+
+    #[test]
+    fn test_encode() {
+        let v = SmallVec::<u8, 2, U8>::try_from([1, 2, 3]).unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(v.encoded_len(), 4);
+        assert_eq!(v.encode(&mut buf), Ok(4));
+        assert_eq!(buf[..4], [3, 1, 2, 3]);
+        assert_eq!(v.encode(&mut buf[..3]), Err(EncodeError));
+    }
+
+    #[test]
+    fn test_decode() {
+        type V = SmallVec<u8, 2, U16>;
+        let (v, n) = V::decode(&[3, 0, 7, 8, 9]).unwrap();
+        assert_eq!(v, [7, 8, 9]);
+        assert_eq!(n, 5);
+        assert!(v.is_heap());
+        assert_eq!(V::decode(&[1]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(
+            V::decode(&[0xFF, 0xFF, 1, 2]),
+            Err(DecodeError::UnexpectedEnd)
+        );
+
+        type W = SmallVec<(), 2, U8>;
+        let (w, n) = W::decode(&[0xFF]).unwrap();
+        assert_eq!(w.len(), 255);
+        assert_eq!(n, 1);
+    }
+}
@@ -1,11 +1,21 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
+use alloc::{borrow::Cow, collections::VecDeque, vec::Vec};
 use core::cmp::{Eq, PartialEq};
 
-impl<T, L, U, SM, const C: usize, const N: usize> PartialEq<&'_ [U; N]> for SmallVec<T, C, L, SM>
+#[cfg(feature = "arrayvec")]
+use crate::arrayvec::ArrayVec;
+
+impl<T, L, U, SM, G, const C: usize, const N: usize> PartialEq<&'_ [U; N]>
+    for SmallVec<T, C, L, SM, G>
 where
     T: PartialEq<U>,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn eq(&self, other: &&'_ [U; N]) -> bool {
@@ -13,11 +23,12 @@ where
     }
 }
 
-impl<T, L, U, SM, const C: usize, const N: usize> PartialEq<[U; N]> for SmallVec<T, C, L, SM>
+impl<T, L, U, SM, G, const C: usize, const N: usize> PartialEq<[U; N]> for SmallVec<T, C, L, SM, G>
 where
     T: PartialEq<U>,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn eq(&self, other: &[U; N]) -> bool {
@@ -25,11 +36,12 @@ where
     }
 }
 
-impl<T, L, U, SM, const C: usize, const N: usize> PartialEq<[U; N]> for &SmallVec<T, C, L, SM>
+impl<T, L, U, SM, G, const C: usize, const N: usize> PartialEq<[U; N]> for &SmallVec<T, C, L, SM, G>
 where
     T: PartialEq<U>,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn eq(&self, other: &[U; N]) -> bool {
@@ -37,11 +49,13 @@ where
     }
 }
 
-impl<T, L, U, SM, const C: usize, const N: usize> PartialEq<[U; N]> for &mut SmallVec<T, C, L, SM>
+impl<T, L, U, SM, G, const C: usize, const N: usize> PartialEq<[U; N]>
+    for &mut SmallVec<T, C, L, SM, G>
 where
     T: PartialEq<U>,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn eq(&self, other: &[U; N]) -> bool {
@@ -49,11 +63,12 @@ where
     }
 }
 
-impl<T, L, U, SM, const C: usize> PartialEq<&'_ [U]> for SmallVec<T, C, L, SM>
+impl<T, L, U, SM, G, const C: usize> PartialEq<&'_ [U]> for SmallVec<T, C, L, SM, G>
 where
     T: PartialEq<U>,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn eq(&self, other: &&'_ [U]) -> bool {
@@ -61,11 +76,12 @@ where
     }
 }
 
-impl<T, L, U, SM, const C: usize> PartialEq<[U]> for SmallVec<T, C, L, SM>
+impl<T, L, U, SM, G, const C: usize> PartialEq<[U]> for SmallVec<T, C, L, SM, G>
 where
     T: PartialEq<U>,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn eq(&self, other: &[U]) -> bool {
@@ -73,41 +89,164 @@ where
     }
 }
 
-impl<T, U, LT, LU, SMT, SMU, const C: usize, const N: usize> PartialEq<SmallVec<U, N, LU, SMU>>
-    for SmallVec<T, C, LT, SMT>
+impl<T, U, LT, LU, SMT, SMU, GT, GU, const C: usize, const N: usize>
+    PartialEq<SmallVec<U, N, LU, SMU, GU>> for SmallVec<T, C, LT, SMT, GT>
+where
+    T: PartialEq<U>,
+    LT: LengthType,
+    LU: LengthType,
+    SMT: SpareMemoryPolicy<T>,
+    SMU: SpareMemoryPolicy<U>,
+    GT: GrowthPolicy,
+    GU: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &SmallVec<U, N, LU, SMU, GU>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, U, LT, LU, SMT, SMU, GT, GU, const C: usize, const N: usize>
+    PartialEq<&'_ SmallVec<U, N, LU, SMU, GU>> for SmallVec<T, C, LT, SMT, GT>
+where
+    T: PartialEq<U>,
+    LT: LengthType,
+    LU: LengthType,
+    SMT: SpareMemoryPolicy<T>,
+    SMU: SpareMemoryPolicy<U>,
+    GT: GrowthPolicy,
+    GU: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &&'_ SmallVec<U, N, LU, SMU, GU>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, L, U, SM, G, const C: usize> PartialEq<Vec<U>> for SmallVec<T, C, L, SM, G>
+where
+    T: PartialEq<U>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, L, U, SM, G, const C: usize> PartialEq<SmallVec<U, C, L, SM, G>> for Vec<T>
+where
+    T: PartialEq<U>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<U>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &SmallVec<U, C, L, SM, G>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, L, U, SM, G, const C: usize> PartialEq<VecDeque<U>> for SmallVec<T, C, L, SM, G>
+where
+    T: PartialEq<U>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &VecDeque<U>) -> bool {
+        let (a, b) = other.as_slices();
+        self.len() == other.len() && self[..a.len()] == *a && self[a.len()..] == *b
+    }
+}
+
+impl<T, L, U, SM, G, const C: usize> PartialEq<SmallVec<U, C, L, SM, G>> for VecDeque<T>
+where
+    T: PartialEq<U>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<U>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &SmallVec<U, C, L, SM, G>) -> bool {
+        let (a, b) = self.as_slices();
+        self.len() == other.len() && *a == other[..a.len()] && *b == other[a.len()..]
+    }
+}
+
+impl<T, L, U, SM, G, const C: usize> PartialEq<Cow<'_, [U]>> for SmallVec<T, C, L, SM, G>
+where
+    T: PartialEq<U>,
+    U: Clone,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &Cow<'_, [U]>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, L, U, SM, G, const C: usize> PartialEq<SmallVec<U, C, L, SM, G>> for Cow<'_, [T]>
+where
+    T: PartialEq<U> + Clone,
+    L: LengthType,
+    SM: SpareMemoryPolicy<U>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn eq(&self, other: &SmallVec<U, C, L, SM, G>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, U, LT, LU, SMT, SMU, G, const C: usize, const N: usize> PartialEq<ArrayVec<U, N, LU, SMU>>
+    for SmallVec<T, C, LT, SMT, G>
 where
     T: PartialEq<U>,
     LT: LengthType,
     LU: LengthType,
     SMT: SpareMemoryPolicy<T>,
     SMU: SpareMemoryPolicy<U>,
+    G: GrowthPolicy,
 {
     #[inline]
-    fn eq(&self, other: &SmallVec<U, N, LU, SMU>) -> bool {
+    fn eq(&self, other: &ArrayVec<U, N, LU, SMU>) -> bool {
         self[..] == other[..]
     }
 }
 
-impl<T, U, LT, LU, SMT, SMU, const C: usize, const N: usize> PartialEq<&'_ SmallVec<U, N, LU, SMU>>
-    for SmallVec<T, C, LT, SMT>
+#[cfg(feature = "arrayvec")]
+impl<T, U, LT, LU, SMT, SMU, G, const C: usize, const N: usize>
+    PartialEq<SmallVec<U, N, LU, SMU, G>> for ArrayVec<T, C, LT, SMT>
 where
     T: PartialEq<U>,
     LT: LengthType,
     LU: LengthType,
     SMT: SpareMemoryPolicy<T>,
     SMU: SpareMemoryPolicy<U>,
+    G: GrowthPolicy,
 {
     #[inline]
-    fn eq(&self, other: &&'_ SmallVec<U, N, LU, SMU>) -> bool {
+    fn eq(&self, other: &SmallVec<U, N, LU, SMU, G>) -> bool {
         self[..] == other[..]
     }
 }
 
-impl<T: Eq, L: LengthType, SM: SpareMemoryPolicy<T>, const C: usize> Eq for SmallVec<T, C, L, SM> {}
+impl<T: Eq, L: LengthType, SM: SpareMemoryPolicy<T>, G: GrowthPolicy, const C: usize> Eq
+    for SmallVec<T, C, L, SM, G>
+{
+}
 
 #[cfg(test)]
 mod testing {
     use crate as cds;
+    use alloc::{borrow::Cow, collections::VecDeque, vec, vec::Vec};
     use cds::{small_vec, smallvec::SmallVec};
 
     type SV = SmallVec<u64, 7>;
@@ -169,4 +308,53 @@ mod testing {
         assert!(a == b);
         assert!(a != small_vec![7; u64]);
     }
+
+    #[test]
+    fn test_eq_vec() {
+        let v = SV::from_iter(0..3);
+        let w: Vec<u64> = vec![0, 1, 2];
+        assert!(v == w);
+        assert!(w == v);
+        assert!(v != vec![0, 1]);
+        assert!(vec![0, 1] != v);
+    }
+
+    #[test]
+    fn test_eq_vec_deque() {
+        let v = SV::from_iter(0..4);
+        let mut d = VecDeque::from(vec![2u64, 3]);
+        d.push_front(1);
+        d.push_front(0);
+        assert!(v == d);
+        assert!(d == v);
+        d.pop_back();
+        assert!(v != d);
+        assert!(d != v);
+        d.push_back(4);
+        assert!(v != d);
+        assert!(d != v);
+    }
+
+    #[test]
+    fn test_eq_cow() {
+        let v = SV::from_iter(0..3);
+        let c: Cow<'_, [u64]> = Cow::Borrowed(&[0, 1, 2]);
+        assert!(v == c);
+        assert!(c == v);
+        let c: Cow<'_, [u64]> = Cow::Owned(vec![0, 1]);
+        assert!(v != c);
+        assert!(c != v);
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn test_eq_array_vec() {
+        let v = SV::from_iter(0..3);
+        let a = cds::array_vec![3; u64; 0, 1, 2];
+        assert!(v == a);
+        assert!(a == v);
+        let a = cds::array_vec![3; u64; 0, 1];
+        assert!(v != a);
+        assert!(a != v);
+    }
 }
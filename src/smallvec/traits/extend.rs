@@ -1,14 +1,15 @@
 use crate::{
     len::LengthType,
-    mem::SpareMemoryPolicy,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
     smallvec::{SmallVec, DOHAE},
 };
 use core::iter::Extend;
 
-impl<T, L, SM, const C: usize> Extend<T> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> Extend<T> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     /// Extend the small-vector with the contents of an iterator.
     ///
@@ -0,0 +1,76 @@
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::{SmallVec, NOHAE},
+};
+use core::fmt::{Error, Result, Write};
+
+/// Implementation of [`Write`] for `SmallVec<u8>`.
+///
+/// The formatted string is appended as UTF-8 encoded bytes, spilling to the heap when local
+/// capacity is exhausted. These methods fail if a (re)allocation fails, or if the required
+/// capacity exceeds the maximal value supported by the length type.
+///
+/// See [`sformat!`] for formatting a new [`SmallString`].
+///
+/// [`sformat!`]: crate::sformat
+/// [`SmallString`]: crate::smallvec::SmallString
+impl<L, SM, G, const C: usize> Write for SmallVec<u8, C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result {
+        self.try_copy_from_slice_impl::<NOHAE>(s.as_bytes())
+            .map_err(|_| Error {})
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> Result {
+        self.write_str(c.encode_utf8(&mut [0; 4]))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{len::U8, smallvec::SmallVec};
+    use core::fmt::Write;
+
+    #[test]
+    fn test_write_str() {
+        type SV = SmallVec<u8, 4>;
+        let mut v = SV::new();
+        assert!(core::write!(&mut v, "abc").is_ok());
+        assert!(v.is_local());
+        let def = "def";
+        assert!(core::write!(&mut v, "{def}!").is_ok());
+        assert!(v.is_heap());
+        assert_eq!(v, b"abcdef!");
+    }
+
+    #[test]
+    fn test_write_char() {
+        type SV = SmallVec<u8, 2>;
+        let mut v = SV::new();
+        let (a, euro) = ('a', '€');
+        assert!(core::write!(&mut v, "{a}{euro}").is_ok());
+        assert_eq!(v, "a€".as_bytes());
+    }
+
+    #[test]
+    fn test_write_str_fails() {
+        type SV = SmallVec<u8, 16, U8>;
+        let mut v = SV::new();
+        for _ in 0..255 {
+            assert!(core::write!(&mut v, "a").is_ok());
+        }
+        assert!(matches!(core::write!(&mut v, "a"), Err(core::fmt::Error)));
+        assert!(matches!(
+            core::write!(&mut v, "{}", 'a'),
+            Err(core::fmt::Error)
+        ));
+        assert_eq!(v.len(), 255);
+    }
+}
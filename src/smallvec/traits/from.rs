@@ -0,0 +1,90 @@
+#![cfg(feature = "arrayvec")]
+
+use crate::{
+    arrayvec::ArrayVec,
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
+use core::ptr;
+
+/// Moves the elements of an [`ArrayVec`] into the local buffer of a [`SmallVec`].
+///
+/// The array-vector capacity `N` may not exceed the local capacity `C`. This is verified at
+/// compile time. Spare memory policy `SMA` of the array-vector is applied to the memory of the
+/// moved out elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cds::{array_vec, smallvec::SmallVec};
+/// let a = array_vec![3; u64; 1, 2];
+/// let v = SmallVec::<u64, 4>::from(a);
+/// assert_eq!(v, [1, 2]);
+/// assert!(v.is_local());
+/// ```
+///
+/// An array-vector with capacity exceeding the local capacity fails to compile:
+///
+/// ```compile_fail
+/// # use cds::{array_vec, smallvec::SmallVec};
+/// let v = SmallVec::<u64, 2>::from(array_vec![3; u64; 1, 2]);
+/// ```
+impl<T, L, LA, SM, SMA, G, const C: usize, const N: usize> From<ArrayVec<T, N, LA, SMA>>
+    for SmallVec<T, C, L, SM, G>
+where
+    L: LengthType,
+    LA: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    SMA: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn from(mut a: ArrayVec<T, N, LA, SMA>) -> Self {
+        const { assert!(N <= C, "ArrayVec capacity exceeds SmallVec local capacity") };
+        let len = a.len();
+        let mut tmp = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(a.as_ptr(), tmp.as_mut_ptr(), len);
+            tmp.set_len(len);
+            a.set_len(0);
+            SMA::init(a.as_mut_ptr(), len);
+        }
+        tmp
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate as cds;
+    use cds::{
+        array_vec,
+        arrayvec::ArrayVec,
+        len::U8,
+        mem::Pattern,
+        smallvec::SmallVec,
+        testing::dropped::{Dropped, Track},
+    };
+
+    #[test]
+    fn test_from_array_vec() {
+        let a = array_vec![4; u64; 1, 2, 3];
+        let v = SmallVec::<u64, 4, U8>::from(a);
+        assert_eq!(v, [1, 2, 3]);
+        assert!(v.is_local());
+
+        let v = SmallVec::<u64, 4>::from(array_vec![0; u64]);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn test_from_array_vec_dropped() {
+        let t = Track::<8>::new();
+        let a = ArrayVec::<Dropped<'_, 8>, 4, U8, Pattern<0xBA>>::from_iter(t.take(3));
+        let v = SmallVec::<Dropped<'_, 8>, 8>::from(a);
+        assert!(t.dropped_indices(&[]));
+        assert!(v.iter().map(|e| e.idx()).eq(0..3));
+        drop(v);
+        assert!(t.dropped_range(0..3));
+    }
+}
@@ -1,14 +1,15 @@
 use crate::{
     len::LengthType,
-    mem::SpareMemoryPolicy,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
     smallvec::{SmallVec, DOHAE},
 };
 use core::iter::{FromIterator, IntoIterator};
 
-impl<T, L, SM, const C: usize> FromIterator<T> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> FromIterator<T> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     /// Creates a `SmallVec` from an iterator.
     ///
@@ -16,7 +17,15 @@ where
     ///
     /// This method panics on reservation errors. See [`reserve`] for more information.
     ///
+    /// With a growth policy that doesn't spill to the heap, elements beyond the local capacity
+    /// are handled by its [`OverflowHook`]: [`Discard`] and [`Overwrite`] keep the first or the last
+    /// `C` elements respectively, and [`Reject`] panics.
+    ///
     /// [`reserve`]: SmallVec::reserve
+    /// [`OverflowHook`]: crate::mem::OverflowHook
+    /// [`Discard`]: crate::mem::Discard
+    /// [`Overwrite`]: crate::mem::Overwrite
+    /// [`Reject`]: crate::mem::Reject
     #[inline]
     fn from_iter<I>(i: I) -> Self
     where
@@ -30,6 +39,8 @@ where
 mod testing {
     use crate as cds;
     use cds::{
+        len::U8,
+        mem::{Discard, NoSpill, Overwrite, Reject, Uninitialized},
         smallvec::SmallVec,
         testing::dropped::{Dropped, Track},
     };
@@ -53,4 +64,27 @@ mod testing {
         let v = SV::from_iter(0..5);
         assert_eq!(v, [0, 1, 2, 3, 4]);
     }
+
+    #[test]
+    fn test_from_iter_no_spill() {
+        type D = SmallVec<u8, 2, U8, Uninitialized, NoSpill<Discard>>;
+        let v = D::from_iter(1..6);
+        assert_eq!(v, [1, 2]);
+        assert!(v.is_local());
+
+        type O = SmallVec<u8, 2, U8, Uninitialized, NoSpill<Overwrite>>;
+        let v = O::from_iter(1..6);
+        assert_eq!(v, [4, 5]);
+
+        type R = SmallVec<u8, 2, U8, Uninitialized, NoSpill<Reject>>;
+        let v = R::from_iter(1..3);
+        assert_eq!(v, [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "smallvec from_iter failed")]
+    fn test_from_iter_no_spill_reject_panics() {
+        type R = SmallVec<u8, 2, U8, Uninitialized, NoSpill<Reject>>;
+        let _ = R::from_iter(1..4);
+    }
 }
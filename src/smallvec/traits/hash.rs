@@ -1,11 +1,16 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::hash::{Hash, Hasher};
 
-impl<T, L, SM, const C: usize> Hash for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> Hash for SmallVec<T, C, L, SM, G>
 where
     T: Hash,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -32,4 +37,52 @@ mod testing {
 
         assert_eq!(hasher1.finish(), hasher2.finish());
     }
+
+    #[test]
+    fn test_hash_same_as_slice() {
+        use cds::{len::U8, smallvec::SmallVec, testing::hasher::record};
+        let s: &[u32] = &[3, 1, 2];
+        let mut v = SmallVec::<u32, 4, U8>::try_from(s).unwrap();
+        assert!(v.is_local());
+        assert_eq!(record(&v), record(s));
+
+        v.extend([4, 5]);
+        assert!(v.is_heap());
+        assert_eq!(record(&v), record(&[3u32, 1, 2, 4, 5][..]));
+    }
+
+    #[test]
+    fn test_hash_map_lookup_by_slice() {
+        use cds::{len::U8, smallvec::SmallVec};
+        use std::collections::HashMap;
+
+        type V = SmallVec<u16, 2, U8>;
+        let mut m = HashMap::<V, usize>::new();
+        m.insert(V::try_from([1, 2]).unwrap(), 12);
+        m.insert(V::try_from([1, 2, 3]).unwrap(), 123);
+        assert_eq!(m.get(&[1u16, 2][..]), Some(&12));
+        assert_eq!(m.get(&[1u16, 2, 3][..]), Some(&123));
+        assert_eq!(m.get(&[1u16][..]), None);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_hash_bytes() {
+        use cds::{len::U8, smallvec::SmallVec, testing::hasher::RecordingHasher};
+
+        let mut v = SmallVec::<[u16; 2], 1, U8>::new();
+        v.push([1, 2]);
+        v.push([3, 4]);
+        assert!(v.is_heap());
+        let mut h = RecordingHasher::default();
+        v.hash_bytes(&mut h);
+        assert_eq!(h.0.len(), 2);
+        assert_eq!(h.0[0], 2usize.to_ne_bytes());
+        assert_eq!(h.0[1], bytemuck::bytes_of(&[[1u16, 2], [3, 4]]));
+
+        let v = small_vec![4; u32; 7, 8, 9];
+        let mut h = RecordingHasher::default();
+        v.hash_bytes(&mut h);
+        assert_eq!(h.0, cds::testing::hasher::record(&v));
+    }
 }
@@ -1,13 +1,18 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
 use core::{
     ops::{Index, IndexMut},
     slice::SliceIndex,
 };
 
-impl<T, L, SM, I: SliceIndex<[T]>, const C: usize> Index<I> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, I: SliceIndex<[T]>, const C: usize> Index<I> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     type Output = I::Output;
 
@@ -17,10 +22,11 @@ where
     }
 }
 
-impl<T, L, SM, I: SliceIndex<[T]>, const C: usize> IndexMut<I> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, I: SliceIndex<[T]>, const C: usize> IndexMut<I> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
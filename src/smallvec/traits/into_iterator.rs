@@ -1,10 +1,33 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::{IntoIter, SmallVec},
+};
 use core::{iter::IntoIterator, slice};
 
-impl<'a, T, L, SM, const C: usize> IntoIterator for &'a SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> IntoIterator for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T, L, SM, C, G>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            sv: self,
+            start: L::new(0),
+        }
+    }
+}
+
+impl<'a, T, L, SM, G, const C: usize> IntoIterator for &'a SmallVec<T, C, L, SM, G>
+where
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     type Item = &'a T;
     type IntoIter = slice::Iter<'a, T>;
@@ -15,10 +38,11 @@ where
     }
 }
 
-impl<'a, T, L, SM, const C: usize> IntoIterator for &'a mut SmallVec<T, C, L, SM>
+impl<'a, T, L, SM, G, const C: usize> IntoIterator for &'a mut SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     type Item = &'a mut T;
     type IntoIter = slice::IterMut<'a, T>;
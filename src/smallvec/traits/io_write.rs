@@ -1,14 +1,15 @@
 use crate::{
     len::LengthType,
-    mem::SpareMemoryPolicy,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
     smallvec::{SmallVec, NOHAE},
 };
 
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-impl<L, SM, const C: usize> std::io::Write for SmallVec<u8, C, L, SM>
+impl<L, SM, G, const C: usize> std::io::Write for SmallVec<u8, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<u8>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
@@ -1,11 +1,20 @@
-use crate::{len::LengthType, mem::SpareMemoryPolicy, smallvec::SmallVec};
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
+use alloc::{borrow::Cow, collections::VecDeque, vec::Vec};
 use core::cmp::{Ord, Ordering, PartialOrd};
 
-impl<T, L, SM, const C: usize> PartialOrd for SmallVec<T, C, L, SM>
+#[cfg(feature = "arrayvec")]
+use crate::arrayvec::ArrayVec;
+
+impl<T, L, SM, G, const C: usize> PartialOrd for SmallVec<T, C, L, SM, G>
 where
     T: PartialOrd,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -13,11 +22,12 @@ where
     }
 }
 
-impl<T, L, SM, const C: usize> Ord for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> Ord for SmallVec<T, C, L, SM, G>
 where
     T: Ord,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
@@ -25,9 +35,122 @@ where
     }
 }
 
+impl<T, L, SM, G, const C: usize> PartialOrd<Vec<T>> for SmallVec<T, C, L, SM, G>
+where
+    T: PartialOrd,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Vec<T>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+impl<T, L, SM, G, const C: usize> PartialOrd<SmallVec<T, C, L, SM, G>> for Vec<T>
+where
+    T: PartialOrd,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &SmallVec<T, C, L, SM, G>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+impl<T, L, SM, G, const C: usize> PartialOrd<VecDeque<T>> for SmallVec<T, C, L, SM, G>
+where
+    T: PartialOrd,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &VecDeque<T>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T, L, SM, G, const C: usize> PartialOrd<SmallVec<T, C, L, SM, G>> for VecDeque<T>
+where
+    T: PartialOrd,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &SmallVec<T, C, L, SM, G>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T, L, SM, G, const C: usize> PartialOrd<Cow<'_, [T]>> for SmallVec<T, C, L, SM, G>
+where
+    T: PartialOrd + Clone,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Cow<'_, [T]>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+impl<T, L, SM, G, const C: usize> PartialOrd<SmallVec<T, C, L, SM, G>> for Cow<'_, [T]>
+where
+    T: PartialOrd + Clone,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &SmallVec<T, C, L, SM, G>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, LT, LU, SMT, SMU, G, const C: usize, const N: usize> PartialOrd<ArrayVec<T, N, LU, SMU>>
+    for SmallVec<T, C, LT, SMT, G>
+where
+    T: PartialOrd,
+    LT: LengthType,
+    LU: LengthType,
+    SMT: SpareMemoryPolicy<T>,
+    SMU: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &ArrayVec<T, N, LU, SMU>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, LT, LU, SMT, SMU, G, const C: usize, const N: usize> PartialOrd<SmallVec<T, N, LU, SMU, G>>
+    for ArrayVec<T, C, LT, SMT>
+where
+    T: PartialOrd,
+    LT: LengthType,
+    LU: LengthType,
+    SMT: SpareMemoryPolicy<T>,
+    SMU: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &SmallVec<T, N, LU, SMU, G>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
 #[cfg(test)]
 mod testing {
     use crate as cds;
+    use alloc::{borrow::Cow, collections::VecDeque, vec};
     use cds::small_vec;
     use core::cmp::Ordering;
 
@@ -57,4 +180,46 @@ mod testing {
         assert_eq!(b.cmp(&a), Ordering::Greater);
         assert_eq!(a.cmp(&a), Ordering::Equal);
     }
+
+    #[test]
+    fn test_partial_ord_vec() {
+        let v = small_vec![3; u64; 1, 2, 3];
+        assert!(v < vec![2, 2, 3]);
+        assert!(vec![2, 2, 3] > v);
+        assert!(v > vec![1, 2]);
+        assert!(vec![1, 2] < v);
+        assert_eq!(v.partial_cmp(&vec![1, 2, 3]), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn test_partial_ord_vec_deque() {
+        let v = small_vec![3; u64; 1, 2, 3];
+        let mut d = VecDeque::from(vec![2u64, 3]);
+        d.push_front(1);
+        assert_eq!(v.partial_cmp(&d), Some(Ordering::Equal));
+        assert_eq!(d.partial_cmp(&v), Some(Ordering::Equal));
+        d.push_front(0);
+        assert!(v > d);
+        assert!(d < v);
+    }
+
+    #[test]
+    fn test_partial_ord_cow() {
+        let v = small_vec![3; u64; 1, 2, 3];
+        let c: Cow<'_, [u64]> = Cow::Borrowed(&[1, 3]);
+        assert!(v < c);
+        assert!(c > v);
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn test_partial_ord_array_vec() {
+        let v = small_vec![3; u64; 1, 2, 3];
+        let a = cds::array_vec![3; u64; 1, 3];
+        assert!(v < a);
+        assert!(a > v);
+        let a = cds::array_vec![3; u64; 1, 2, 3];
+        assert_eq!(v.partial_cmp(&a), Some(Ordering::Equal));
+        assert_eq!(a.partial_cmp(&v), Some(Ordering::Equal));
+    }
 }
@@ -0,0 +1,58 @@
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+    strategy::{small_vec, SmallVecStrategy},
+};
+use proptest::{
+    arbitrary::{any_with, Arbitrary},
+    collection::SizeRange,
+};
+
+impl<T, L, SM, G, const C: usize> Arbitrary for SmallVec<T, C, L, SM, G>
+where
+    T: Arbitrary,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = SmallVecStrategy<T::Strategy, C, L, SM, G>;
+
+    #[inline]
+    fn arbitrary_with((size, args): Self::Parameters) -> Self::Strategy {
+        small_vec(any_with::<T>(args), size)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::{
+        len::U8,
+        mem::{NoSpill, Uninitialized},
+        smallvec::SmallVec,
+    };
+    use proptest::prelude::*;
+
+    type S = SmallVec<u16, 4, U8>;
+    type N = SmallVec<u16, 4, U8, Uninitialized, NoSpill>;
+
+    proptest! {
+        #[test]
+        fn test_any_with_spill_point(v in any_with::<S>(((3..=5).into(), ()))) {
+            prop_assert!((3..=5).contains(&v.len()));
+            prop_assert_eq!(v.is_local(), v.len() <= 4);
+        }
+
+        #[test]
+        fn test_any_respects_len_max(v in any_with::<SmallVec<u8, 2, U8>>(((250..300).into(), ()))) {
+            prop_assert!((250..=255).contains(&v.len()));
+        }
+
+        #[test]
+        fn test_any_no_spill_respects_capacity(v in any_with::<N>(((2..10).into(), ()))) {
+            prop_assert!((2..=4).contains(&v.len()));
+            prop_assert!(v.is_local());
+        }
+    }
+}
@@ -1,15 +1,16 @@
 use crate::{
     len::LengthType,
-    mem::{errors::ReservationError, SpareMemoryPolicy},
+    mem::{errors::ReservationError, GrowthPolicy, SpareMemoryPolicy},
     smallvec::{clone_from_slice_unchecked, SmallVec, NOHAE},
 };
 use core::{convert::TryFrom, mem, ptr};
 
-impl<T, L, SM, const C: usize> TryFrom<&[T]> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> TryFrom<&[T]> for SmallVec<T, C, L, SM, G>
 where
     T: Clone,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     type Error = ReservationError;
 
@@ -24,11 +25,12 @@ where
     }
 }
 
-impl<T, L, SM, const C: usize> TryFrom<&mut [T]> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize> TryFrom<&mut [T]> for SmallVec<T, C, L, SM, G>
 where
     T: Clone,
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     type Error = ReservationError;
 
@@ -43,10 +45,11 @@ where
     }
 }
 
-impl<T, L, SM, const C: usize, const N: usize> TryFrom<[T; N]> for SmallVec<T, C, L, SM>
+impl<T, L, SM, G, const C: usize, const N: usize> TryFrom<[T; N]> for SmallVec<T, C, L, SM, G>
 where
     L: LengthType,
     SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
 {
     type Error = ReservationError;
 
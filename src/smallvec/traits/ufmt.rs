@@ -0,0 +1,43 @@
+use crate::{
+    len::LengthType,
+    mem::{GrowthPolicy, SpareMemoryPolicy},
+    smallvec::SmallVec,
+};
+use ufmt::{uDebug, uWrite, Formatter};
+
+impl<T, L, SM, G, const C: usize> uDebug for SmallVec<T, C, L, SM, G>
+where
+    T: uDebug,
+    L: LengthType,
+    SM: SpareMemoryPolicy<T>,
+    G: GrowthPolicy,
+{
+    #[inline]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        uDebug::fmt(self.as_slice(), f)
+    }
+}
+
+#[cfg(all(test, feature = "arraystring"))]
+mod testing {
+    use crate as cds;
+    use cds::{array_str, small_vec};
+    use ufmt::uwrite;
+
+    #[test]
+    fn test_debug() {
+        let mut a = small_vec![2; u16; 1, 20];
+        let mut s = array_str![16;];
+        uwrite!(s, "{:?}", a).unwrap();
+        assert_eq!(s, "[1, 20]");
+
+        a.push(300);
+        assert!(a.is_heap());
+        s.clear();
+        uwrite!(s, "{:?}", a).unwrap();
+        assert_eq!(s, "[1, 20, 300]");
+    }
+}
@@ -0,0 +1,210 @@
+//! [`proptest`] strategies for *cds* collections.
+//!
+//! The strategies generate collections with a length in a chosen range, and shrink like the
+//! [`Vec`] strategy they are built upon. The size range is clamped to the maximal length of a
+//! collection, i.e. the capacity `C` of [`ArrayVec`] and [`ArrayString`], and `L::MAX` of
+//! [`SmallVec`] (or its local capacity `C` with a growth policy that doesn't spill).
+//!
+//! In addition, the collections implement [`Arbitrary`] of `proptest`, with parameters similar to
+//! those of [`Vec`].
+//!
+//! # Examples
+//!
+//! A range around the local capacity of a [`SmallVec`] exercises its spill point:
+//!
+//! ```rust
+//! # #[cfg(feature = "smallvec")] {
+//! use cds::{len::U8, smallvec::SmallVec};
+//! use proptest::{collection::SizeRange, prelude::*};
+//!
+//! type S = SmallVec<u16, 4, U8>;
+//!
+//! proptest! {
+//!     fn spill(v in any_with::<S>((SizeRange::from(2..=6), ()))) {
+//!         prop_assert_eq!(v.is_heap(), v.len() > 4);
+//!     }
+//! }
+//! # spill();
+//! # }
+//! ```
+//!
+//! [`ArrayVec`]: crate::arrayvec::ArrayVec
+//! [`ArrayString`]: crate::arraystring::ArrayString
+//! [`SmallVec`]: crate::smallvec::SmallVec
+//! [`Arbitrary`]: proptest::arbitrary::Arbitrary
+
+#[cfg(feature = "arraystring")]
+use crate::arraystring::ArrayString;
+#[cfg(feature = "arrayvec")]
+use crate::arrayvec::ArrayVec;
+#[cfg(any(feature = "arrayvec", feature = "arraystring", feature = "smallvec"))]
+use crate::{len::LengthType, mem::SpareMemoryPolicy};
+#[cfg(feature = "smallvec")]
+use crate::{mem::GrowthPolicy, smallvec::SmallVec};
+#[cfg(any(feature = "arrayvec", feature = "arraystring", feature = "smallvec"))]
+use proptest::{
+    collection::{vec, SizeRange, VecStrategy},
+    strategy::{Map, Strategy},
+};
+
+/// Strategy to create [`ArrayVec`]s with a length in a certain range.
+///
+/// Created by [`array_vec`].
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+pub type ArrayVecStrategy<S, const C: usize, L, SM> = Map<
+    VecStrategy<S>,
+    fn(Vec<<S as Strategy>::Value>) -> ArrayVec<<S as Strategy>::Value, C, L, SM>,
+>;
+
+/// Strategy to create [`ArrayString`]s with a number of characters in a certain range.
+///
+/// Created by [`array_string`].
+#[cfg(feature = "arraystring")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arraystring")))]
+pub type ArrayStringStrategy<S, const C: usize, L, SM> =
+    Map<VecStrategy<S>, fn(Vec<char>) -> ArrayString<C, L, SM>>;
+
+/// Strategy to create [`SmallVec`]s with a length in a certain range.
+///
+/// Created by [`small_vec`].
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+pub type SmallVecStrategy<S, const C: usize, L, SM, G> = Map<
+    VecStrategy<S>,
+    fn(Vec<<S as Strategy>::Value>) -> SmallVec<<S as Strategy>::Value, C, L, SM, G>,
+>;
+
+/// Creates a strategy to generate [`ArrayVec`]s with elements drawn from `element`, and a length
+/// within `size` clamped to the capacity `C`.
+///
+/// # Examples
+///
+/// ```rust
+/// use cds::{arrayvec::ArrayVec, len::U8, mem::Uninitialized, strategy::array_vec};
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn len(v in array_vec::<_, U8, Uninitialized, 4>(any::<u8>(), 1..10)) {
+///         prop_assert!((1..=4).contains(&v.len()));
+///     }
+/// }
+/// # len();
+/// ```
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+pub fn array_vec<S, L, SM, const C: usize>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> ArrayVecStrategy<S, C, L, SM>
+where
+    S: Strategy,
+    L: LengthType,
+    SM: SpareMemoryPolicy<S::Value>,
+{
+    vec(element, clamp(size.into(), C)).prop_map(collect as fn(_) -> _)
+}
+
+/// Creates a strategy to generate [`ArrayString`]s with characters drawn from `element`, and a
+/// number of characters within `size` clamped to the capacity `C`.
+///
+/// Characters which don't fit in the remaining capacity are skipped. Hence, a generated string
+/// may contain fewer characters than the lower bound of `size`, when multibyte characters are
+/// drawn.
+///
+/// # Examples
+///
+/// ```rust
+/// use cds::{arraystring::ArrayString, len::U8, mem::Uninitialized, strategy::array_string};
+/// use proptest::{char::range, prelude::*};
+///
+/// proptest! {
+///     fn ascii(s in array_string::<_, U8, Uninitialized, 8>(range('a', 'z'), 1..=8)) {
+///         prop_assert!((1..=8).contains(&s.len()));
+///     }
+/// }
+/// # ascii();
+/// ```
+#[cfg(feature = "arraystring")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arraystring")))]
+pub fn array_string<S, L, SM, const C: usize>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> ArrayStringStrategy<S, C, L, SM>
+where
+    S: Strategy<Value = char>,
+    L: LengthType,
+    SM: SpareMemoryPolicy<u8>,
+{
+    let f: fn(Vec<char>) -> ArrayString<C, L, SM> = |v| {
+        let mut s = ArrayString::new();
+        for ch in v {
+            let _ = s.try_push(ch);
+        }
+        s
+    };
+    vec(element, clamp(size.into(), C)).prop_map(f)
+}
+
+/// Creates a strategy to generate [`SmallVec`]s with elements drawn from `element`, and a length
+/// within `size` clamped to `L::MAX`, or to the local capacity `C` if the growth policy doesn't
+/// spill to the heap (see [`GrowthPolicy::SPILL`]).
+///
+/// A range around the local capacity `C` generates both local and spilled small-vectors.
+///
+/// # Examples
+///
+/// ```rust
+/// use cds::{
+///     len::U8,
+///     mem::{PowerOfTwo, Uninitialized},
+///     smallvec::SmallVec,
+///     strategy::small_vec,
+/// };
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn spill(v in small_vec::<_, U8, Uninitialized, PowerOfTwo, 4>(any::<u8>(), 3..=5)) {
+///         prop_assert_eq!(v.is_local(), v.len() <= 4);
+///     }
+/// }
+/// # spill();
+/// ```
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+pub fn small_vec<S, L, SM, G, const C: usize>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> SmallVecStrategy<S, C, L, SM, G>
+where
+    S: Strategy,
+    L: LengthType,
+    SM: SpareMemoryPolicy<S::Value>,
+    G: GrowthPolicy,
+{
+    let max = if G::SPILL { L::MAX } else { C };
+    vec(element, clamp(size.into(), max)).prop_map(collect as fn(_) -> _)
+}
+
+#[cfg(any(feature = "arrayvec", feature = "smallvec"))]
+fn collect<T, B: FromIterator<T>>(v: Vec<T>) -> B {
+    v.into_iter().collect()
+}
+
+#[cfg(any(feature = "arrayvec", feature = "arraystring", feature = "smallvec"))]
+fn clamp(size: SizeRange, max: usize) -> SizeRange {
+    let (start, end) = size.start_end_incl();
+    (start.min(max)..=end.min(max)).into()
+}
+
+#[cfg(all(test, feature = "arrayvec"))]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(clamp((0..100).into(), 8).start_end_incl(), (0, 8));
+        assert_eq!(clamp((10..100).into(), 8).start_end_incl(), (8, 8));
+        assert_eq!(clamp((1..=3).into(), 8).start_end_incl(), (1, 3));
+    }
+}
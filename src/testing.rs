@@ -1,3 +1,5 @@
 pub(crate) mod dropped;
 #[cfg(feature = "std")]
 pub(crate) mod dropped_zst;
+#[cfg(feature = "std")]
+pub(crate) mod hasher;
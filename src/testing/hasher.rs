@@ -0,0 +1,25 @@
+use core::hash::{Hash, Hasher};
+
+/// A hasher which records the bytes of every `write` call.
+///
+/// All `write_*` methods of [`Hasher`] default to `write`, hence two values with equal records
+/// hash equally with any hasher which doesn't override the defaults inconsistently.
+#[derive(Default)]
+pub struct RecordingHasher(pub Vec<Vec<u8>>);
+
+impl Hasher for RecordingHasher {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.push(bytes.to_vec());
+    }
+}
+
+/// Returns the `write` calls issued by hashing `value`.
+pub fn record<T: Hash + ?Sized>(value: &T) -> Vec<Vec<u8>> {
+    let mut h = RecordingHasher::default();
+    value.hash(&mut h);
+    h.0
+}